@@ -1,29 +1,55 @@
 //! Derive ActionBuilder on a given struct
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput, Ident, Lit, LitStr, Meta};
+use syn::{
+    parse_macro_input, DeriveInput, Error, Ident, Lit, LitStr, Member, Meta, NestedMeta, Type,
+};
+
+use crate::actor::get_actor_field;
 
 /// Derive ActionBuilder on a struct that implements Component + Clone
 pub fn action_builder_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
     let label = get_label(&input);
+    let actor_field = match get_actor_field(&input) {
+        Ok(actor_field) => actor_field,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let transition = match get_transition(&input) {
+        Ok(transition) => transition,
+        Err(err) => return err.to_compile_error().into(),
+    };
 
     let component_name = input.ident;
     let generics = input.generics;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let component_string = component_name.to_string();
-    let build_method = build_method(&component_name, &ty_generics);
+    let build_method = build_method(&component_name, &ty_generics, actor_field);
     let label_method = label_method(
         label.unwrap_or_else(|| LitStr::new(&component_string, component_name.span())),
     );
 
+    let state_machine = transition
+        .map(|transition| {
+            state_machine_impl(
+                &component_name,
+                &impl_generics,
+                &ty_generics,
+                where_clause,
+                transition,
+            )
+        })
+        .unwrap_or_default();
+
     let gen = quote! {
         impl #impl_generics ::big_brain::actions::ActionBuilder for #component_name #ty_generics #where_clause {
             #build_method
             #label_method
         }
+
+        #state_machine
     };
 
     proc_macro::TokenStream::from(gen)
@@ -51,12 +77,130 @@ fn get_label(input: &DeriveInput) -> Option<LitStr> {
     label
 }
 
-fn build_method(component_name: &Ident, ty_generics: &syn::TypeGenerics) -> TokenStream {
-    let turbofish = ty_generics.as_turbofish();
+/// What `#[action(transition, ...)]` asked for.
+struct Transition {
+    param: Option<Type>,
+    on_cancel: bool,
+}
+
+/// Reads `#[action(transition)]`, along with its optional `param = "..."`
+/// and `on_cancel` settings. Returns `None` if the Action didn't opt in.
+fn get_transition(input: &DeriveInput) -> Result<Option<Transition>, Error> {
+    let mut transition = false;
+    let mut param = None;
+    let mut on_cancel = false;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("action"))
+    {
+        let Meta::List(list) = attr.parse_meta()? else {
+            return Err(Error::new_spanned(
+                attr,
+                "Expected `#[action(transition, ...)]`",
+            ));
+        };
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("transition") => {
+                    transition = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("on_cancel") => {
+                    on_cancel = true;
+                }
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident("param") =>
+                {
+                    let Lit::Str(lit_str) = &name_value.lit else {
+                        return Err(Error::new_spanned(
+                            name_value.lit,
+                            "Must specify a string for `param`",
+                        ));
+                    };
+                    param = Some(lit_str.parse()?);
+                }
+                nested => {
+                    return Err(Error::new_spanned(
+                        nested,
+                        "Unknown `action` attribute. Expected `transition`, `param` or `on_cancel`",
+                    ));
+                }
+            }
+        }
+    }
+    if !transition && (param.is_some() || on_cancel) {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`param` and `on_cancel` need `#[action(transition)]`",
+        ));
+    }
+    Ok(transition.then_some(Transition { param, on_cancel }))
+}
+
+/// Implements `ActionStateMachine` by calling the Action's own `on_execute`
+/// (and `on_cancel`, if asked for) methods.
+fn state_machine_impl(
+    component_name: &Ident,
+    impl_generics: &syn::ImplGenerics,
+    ty_generics: &syn::TypeGenerics,
+    where_clause: Option<&syn::WhereClause>,
+    transition: Transition,
+) -> TokenStream {
+    let param = transition
+        .param
+        .map(|param| quote! { #param })
+        .unwrap_or_else(|| quote! { () });
+    let cancel_method = if transition.on_cancel {
+        quote! {
+            fn on_cancel(
+                &mut self,
+                actor: ::bevy::prelude::Entity,
+                param: &mut ::bevy::ecs::system::SystemParamItem<Self::Param>,
+            ) -> ::big_brain::actions::ActionState {
+                #component_name::on_cancel(self, actor, param)
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
 
     quote! {
-        fn build(&self, cmd: &mut ::bevy::prelude::Commands, action: ::bevy::prelude::Entity, _actor: ::bevy::prelude::Entity) {
-            cmd.entity(action).insert(#component_name #turbofish::clone(self));
+        impl #impl_generics ::big_brain::actions::ActionStateMachine for #component_name #ty_generics #where_clause {
+            type Param = #param;
+
+            fn on_execute(
+                &mut self,
+                actor: ::bevy::prelude::Entity,
+                param: &mut ::bevy::ecs::system::SystemParamItem<Self::Param>,
+            ) -> ::big_brain::actions::ActionState {
+                #component_name::on_execute(self, actor, param)
+            }
+
+            #cancel_method
+        }
+    }
+}
+
+fn build_method(
+    component_name: &Ident,
+    ty_generics: &syn::TypeGenerics,
+    actor_field: Option<Member>,
+) -> TokenStream {
+    let turbofish = ty_generics.as_turbofish();
+
+    if let Some(actor_field) = actor_field {
+        quote! {
+            fn build(&self, cmd: &mut ::bevy::prelude::Commands, action: ::bevy::prelude::Entity, actor: ::bevy::prelude::Entity) {
+                let mut component = #component_name #turbofish::clone(self);
+                component.#actor_field = actor;
+                cmd.entity(action).insert(component);
+            }
+        }
+    } else {
+        quote! {
+            fn build(&self, cmd: &mut ::bevy::prelude::Commands, action: ::bevy::prelude::Entity, _actor: ::bevy::prelude::Entity) {
+                cmd.entity(action).insert(#component_name #turbofish::clone(self));
+            }
         }
     }
 }
@@ -0,0 +1,25 @@
+//! Support for the `#[actor]` field attribute shared by the derives
+use syn::{Data, DeriveInput, Error, Index, Member};
+
+/// Finds the field marked with `#[actor]`, if any.
+pub fn get_actor_field(input: &DeriveInput) -> Result<Option<Member>, Error> {
+    let Data::Struct(data) = &input.data else {
+        return Ok(None);
+    };
+    let mut actor_field = None;
+    for (index, field) in data.fields.iter().enumerate() {
+        if field.attrs.iter().any(|attr| attr.path.is_ident("actor")) {
+            if actor_field.is_some() {
+                return Err(Error::new_spanned(
+                    field,
+                    "Only one field can be marked with the `actor` attribute",
+                ));
+            }
+            actor_field = Some(match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(Index::from(index)),
+            });
+        }
+    }
+    Ok(actor_field)
+}
@@ -1,19 +1,31 @@
 //! Big Brain Derive
 //! Procedural macros to simplify the implementation of Big Brain traits
 mod action;
+mod actor;
 mod scorer;
 
 use action::action_builder_impl;
 use scorer::scorer_builder_impl;
 
 /// Derives ActionBuilder for a struct that implements Component + Clone
-#[proc_macro_derive(ActionBuilder, attributes(action_label))]
+///
+/// Mark an `Entity` field with `#[actor]` to have it filled in with the
+/// actor's entity when the action is spawned.
+///
+/// Add `#[action(transition)]` to also implement `ActionStateMachine`,
+/// calling the struct's own `on_execute` method. Use `param = "..."` to pick
+/// the system param handed to it, and `on_cancel` to call the struct's
+/// `on_cancel` method too.
+#[proc_macro_derive(ActionBuilder, attributes(action_label, action, actor))]
 pub fn action_builder_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     action_builder_impl(input)
 }
 
 /// Derives ScorerBuilder for a struct that implements Component + Clone
-#[proc_macro_derive(ScorerBuilder, attributes(scorer_label))]
+///
+/// Mark an `Entity` field with `#[actor]` to have it filled in with the
+/// actor's entity when the scorer is spawned.
+#[proc_macro_derive(ScorerBuilder, attributes(scorer_label, scorer_shared, actor))]
 pub fn scorer_builder_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     scorer_builder_impl(input)
 }
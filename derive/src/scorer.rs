@@ -1,28 +1,41 @@
 //! Derive ScorerBuilder on a given struct
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput, Ident, Lit, LitStr, Meta};
+use syn::{parse_macro_input, DeriveInput, Ident, Lit, LitStr, Member, Meta};
+
+use crate::actor::get_actor_field;
 
 /// Derive ScorerBuilder on a struct that implements Component + Clone
 pub fn scorer_builder_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
     let label = get_label(&input);
+    let actor_field = match get_actor_field(&input) {
+        Ok(actor_field) => actor_field,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let shared = is_shared(&input);
 
     let component_name = input.ident;
     let generics = input.generics;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let component_string = component_name.to_string();
-    let build_method = build_method(&component_name, &ty_generics);
+    let build_method = build_method(&component_name, &ty_generics, actor_field);
     let label_method = label_method(
         label.unwrap_or_else(|| LitStr::new(&component_string, component_name.span())),
     );
+    let shared_key_method = if shared {
+        shared_key_method()
+    } else {
+        TokenStream::new()
+    };
 
     let gen = quote! {
         impl #impl_generics ::big_brain::scorers::ScorerBuilder for #component_name #ty_generics #where_clause {
             #build_method
             #label_method
+            #shared_key_method
         }
     };
 
@@ -51,12 +64,32 @@ fn get_label(input: &DeriveInput) -> Option<LitStr> {
     label
 }
 
-fn build_method(component_name: &Ident, ty_generics: &syn::TypeGenerics) -> TokenStream {
+fn is_shared(input: &DeriveInput) -> bool {
+    input.attrs.iter().any(
+        |attr| matches!(attr.parse_meta(), Ok(Meta::Path(path)) if path.is_ident("scorer_shared")),
+    )
+}
+
+fn build_method(
+    component_name: &Ident,
+    ty_generics: &syn::TypeGenerics,
+    actor_field: Option<Member>,
+) -> TokenStream {
     let turbofish = ty_generics.as_turbofish();
 
-    quote! {
-        fn build(&self, cmd: &mut ::bevy::prelude::Commands, scorer: ::bevy::prelude::Entity, _actor: ::bevy::prelude::Entity) {
-            cmd.entity(scorer).insert(#component_name  #turbofish::clone(self));
+    if let Some(actor_field) = actor_field {
+        quote! {
+            fn build(&self, cmd: &mut ::bevy::prelude::Commands, scorer: ::bevy::prelude::Entity, actor: ::bevy::prelude::Entity) {
+                let mut component = #component_name #turbofish::clone(self);
+                component.#actor_field = actor;
+                cmd.entity(scorer).insert(component);
+            }
+        }
+    } else {
+        quote! {
+            fn build(&self, cmd: &mut ::bevy::prelude::Commands, scorer: ::bevy::prelude::Entity, _actor: ::bevy::prelude::Entity) {
+                cmd.entity(scorer).insert(#component_name  #turbofish::clone(self));
+            }
         }
     }
 }
@@ -68,3 +101,11 @@ fn label_method(label: LitStr) -> TokenStream {
         }
     }
 }
+
+fn shared_key_method() -> TokenStream {
+    quote! {
+        fn shared_key(&self) -> ::std::option::Option<::std::any::TypeId> {
+            ::std::option::Option::Some(::std::any::TypeId::of::<Self>())
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! A tiny headless benchmark for `BigBrainPlugin::pool_actions`.
+//!
+//! Spawns a bunch of actors whose Thinkers keep switching between two
+//! Actions that finish right away, then times a few hundred frames with
+//! pooling turned off and on. Run it in release mode for meaningful numbers:
+//!
+//! ```sh
+//! cargo run --release --example action_pool
+//! ```
+
+use std::time::{Duration, Instant};
+
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+const ACTORS: usize = 5_000;
+const FRAMES: usize = 300;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Blink;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Twitch;
+
+fn finish_right_away<T: Component>(mut query: Query<&mut ActionState, With<T>>) {
+    for mut state in query.iter_mut() {
+        if matches!(*state, ActionState::Requested | ActionState::Executing) {
+            *state = ActionState::Success;
+        }
+    }
+}
+
+#[derive(Clone, Component, Debug, ScorerBuilder)]
+struct Restless;
+
+/// Flips between high and low every frame, so the Thinker keeps switching
+/// between its choice and its `otherwise`.
+#[allow(clippy::manual_is_multiple_of)]
+fn restless_scorer_system(mut frame: Local<u32>, mut query: Query<&mut Score, With<Restless>>) {
+    *frame += 1;
+    let value = if *frame % 2 == 0 { 1.0 } else { 0.0 };
+    for mut score in query.iter_mut() {
+        score.set(value);
+    }
+}
+
+fn run(pool: bool) -> Duration {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        BigBrainPlugin::new(PreUpdate).pool_actions(pool),
+    ))
+    .add_systems(
+        PreUpdate,
+        (
+            restless_scorer_system.in_set(BigBrainSet::Scorers),
+            (finish_right_away::<Blink>, finish_right_away::<Twitch>).in_set(BigBrainSet::Actions),
+        ),
+    );
+    for _ in 0..ACTORS {
+        app.world_mut().spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when(Restless, Blink)
+                .otherwise(Twitch),
+        );
+    }
+    // Let everyone get their Thinkers set up before we start timing.
+    app.update();
+    app.update();
+
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        app.update();
+    }
+    start.elapsed()
+}
+
+fn main() {
+    for pool in [false, true] {
+        let elapsed = run(pool);
+        println!(
+            "pool_actions({pool}): {FRAMES} frames with {ACTORS} actors in {elapsed:?} ({:?}/frame)",
+            elapsed / FRAMES as u32
+        );
+    }
+}
@@ -51,7 +51,7 @@ fn guess_number_action(
                     *action_state = ActionState::Success;
                 }
             }
-            ActionState::Cancelled => {
+            ActionState::Cancelled | ActionState::Interrupted => {
                 // Always treat cancellations, or we might keep doing this forever!
                 // You don't need to terminate immediately, by the way, this is only a flag that
                 // the cancellation has been requested. If the actor is balancing on a tightrope,
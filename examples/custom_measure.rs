@@ -110,7 +110,7 @@ fn eat_thing_action<
                     }
                 }
                 // All Actions should make sure to handle cancellations!
-                ActionState::Cancelled => {
+                ActionState::Cancelled | ActionState::Interrupted => {
                     info!(
                         "Cancelled eating {:?}. Considering this a failure.",
                         action_marker
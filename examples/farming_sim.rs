@@ -146,7 +146,7 @@ fn sleep_action_system(
                     }
                 }
                 // All Actions should make sure to handle cancellations!
-                ActionState::Cancelled => {
+                ActionState::Cancelled | ActionState::Interrupted => {
                     debug!("Sleep was interrupted. Still tired.");
                     materials.get_mut(material).unwrap().base_color = DEFAULT_COLOR;
                     fatigue.is_sleeping = false;
@@ -234,7 +234,7 @@ fn farm_action_system(
                         *state = ActionState::Success;
                     }
                 }
-                ActionState::Cancelled => {
+                ActionState::Cancelled | ActionState::Interrupted => {
                     debug!("Farming was interrupted. Still need to work.");
                     materials.get_mut(material).unwrap().base_color = DEFAULT_COLOR;
                     *state = ActionState::Failure;
@@ -300,7 +300,7 @@ fn sell_action_system(
                     // we treat selling as instantaneous.
                     *state = ActionState::Success;
                 }
-                ActionState::Cancelled => {
+                ActionState::Cancelled | ActionState::Interrupted => {
                     debug!("Selling was interrupted. Still need to work.");
                     *state = ActionState::Failure;
                 }
@@ -410,7 +410,7 @@ pub fn move_to_nearest_system<T: Component + std::fmt::Debug + Clone>(
                     *action_state = ActionState::Success;
                 }
             }
-            ActionState::Cancelled => {
+            ActionState::Cancelled | ActionState::Interrupted => {
                 *action_state = ActionState::Failure;
             }
             _ => {}
@@ -14,7 +14,7 @@ fn one_off_action_system(mut query: Query<(&mut ActionState, &ActionSpan), With<
                 debug!("One-off action!");
                 *state = ActionState::Success;
             }
-            ActionState::Cancelled => {
+            ActionState::Cancelled | ActionState::Interrupted => {
                 debug!("One-off action was cancelled. Considering this a failure.");
                 *state = ActionState::Failure;
             }
@@ -119,7 +119,7 @@ fn move_to_water_source_action_system(
                     *action_state = ActionState::Success;
                 }
             }
-            ActionState::Cancelled => {
+            ActionState::Cancelled | ActionState::Interrupted => {
                 // Always treat cancellations, or we might keep doing this forever!
                 // You don't need to terminate immediately, by the way, this is only a flag that
                 // the cancellation has been requested. If the actor is balancing on a tightrope,
@@ -208,7 +208,7 @@ fn drink_action_system(
             }
             // All Actions should make sure to handle cancellations!
             // Drinking is not a complicated action, so we can just interrupt it immediately.
-            ActionState::Cancelled => {
+            ActionState::Cancelled | ActionState::Interrupted => {
                 *state = ActionState::Failure;
             }
             _ => {}
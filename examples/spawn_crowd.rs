@@ -0,0 +1,68 @@
+//! A tiny headless benchmark for spawning big crowds of actors.
+//!
+//! Spawns a bunch of actors with [`ThinkerBuilder::spawn_batch`] and times
+//! the frame their Thinkers get attached in, which is where every Scorer and
+//! Action builder actually runs. Run it in release mode for meaningful
+//! numbers:
+//!
+//! ```sh
+//! cargo run --release --example spawn_crowd
+//! ```
+
+use std::time::{Duration, Instant};
+
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+const ACTORS: usize = 10_000;
+const RUNS: usize = 5;
+
+#[derive(Component)]
+struct Villager;
+
+#[derive(Clone, Component, Debug, ScorerBuilder)]
+struct Hungry;
+
+#[derive(Clone, Component, Debug, ScorerBuilder)]
+struct Tired;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Eat;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Sleep;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Wander;
+
+fn run() -> Duration {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    // Get the first-frame setup out of the way.
+    app.update();
+
+    Thinker::build()
+        .picker(Highest)
+        .when(
+            SumOfScorers::build(0.5).push(Hungry).push(Tired),
+            Steps::build().step(Eat).step(Sleep),
+        )
+        .when(Tired, Sleep)
+        .otherwise(Wander)
+        .spawn_batch(&mut app.world_mut().commands(), ACTORS, || Villager);
+    app.world_mut().flush();
+
+    let start = Instant::now();
+    app.update();
+    start.elapsed()
+}
+
+fn main() {
+    let mut total = Duration::ZERO;
+    for _ in 0..RUNS {
+        let elapsed = run();
+        println!("attached {ACTORS} Thinkers in {elapsed:?}");
+        total += elapsed;
+    }
+    println!("average: {:?}", total / RUNS as u32);
+}
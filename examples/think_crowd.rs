@@ -0,0 +1,90 @@
+//! A tiny headless benchmark for the per-tick cost of big crowds of actors.
+//!
+//! Spawns a bunch of actors, lets their Thinkers settle, and then times how
+//! long each frame of picking and running Actions takes. Run it in release
+//! mode for meaningful numbers:
+//!
+//! ```sh
+//! cargo run --release --example think_crowd
+//! ```
+
+use std::time::{Duration, Instant};
+
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+const ACTORS: usize = 10_000;
+const FRAMES: u32 = 200;
+
+#[derive(Component)]
+struct Villager;
+
+#[derive(Clone, Component, Debug, ScorerBuilder)]
+struct Hungry;
+
+#[derive(Clone, Component, Debug, ScorerBuilder)]
+struct Tired;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Eat;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Sleep;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Wander;
+
+fn hungry(mut scorers: Query<&mut Score, With<Hungry>>) {
+    for mut score in scorers.iter_mut() {
+        score.set(0.4);
+    }
+}
+
+fn tired(mut scorers: Query<&mut Score, With<Tired>>) {
+    for mut score in scorers.iter_mut() {
+        score.set(0.2);
+    }
+}
+
+fn keep_going<T: Component>(mut query: Query<&mut ActionState, With<T>>) {
+    for mut state in query.iter_mut() {
+        match *state {
+            ActionState::Requested => *state = ActionState::Executing,
+            ActionState::Cancelled | ActionState::Interrupted => *state = ActionState::Failure,
+            _ => {}
+        }
+    }
+}
+
+fn main() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .add_systems(
+            PreUpdate,
+            (
+                (hungry, tired).in_set(BigBrainSet::Scorers),
+                (keep_going::<Eat>, keep_going::<Sleep>, keep_going::<Wander>)
+                    .in_set(BigBrainSet::Actions),
+            ),
+        );
+    Thinker::build()
+        .picker(Highest)
+        .when(Hungry, Eat)
+        .when(Tired, Sleep)
+        .otherwise(Wander)
+        .spawn_batch(&mut app.world_mut().commands(), ACTORS, || Villager);
+    for _ in 0..10 {
+        app.update();
+    }
+
+    let mut total = Duration::ZERO;
+    for _ in 0..FRAMES {
+        let start = Instant::now();
+        app.update();
+        total += start.elapsed();
+    }
+    println!(
+        "{ACTORS} Thinkers took {:?} per frame on average",
+        total / FRAMES
+    );
+}
@@ -74,7 +74,9 @@ fn drink_action_system(
                     debug!("Time to drink some water!");
                     *state = ActionState::Executing;
                 }
-                ActionState::Executing => {
+                // If the Thinker would rather do something else, we finish
+                // our drink first. It'll wait for us!
+                ActionState::Executing | ActionState::Interrupted => {
                     trace!("Drinking...");
                     thirst.thirst -=
                         drink.per_second * (time.delta().as_micros() as f32 / 1_000_000.0);
@@ -1,12 +1,40 @@
 //! Defines Action-related functionality. This module includes the
 //! ActionBuilder trait and some Composite Actions for utility.
-use std::sync::Arc;
+//!
+//! Composite Actions differ in when they spawn their children:
+//!
+//! * [`Steps`] only ever has the active step spawned. The first step is
+//!   spawned along with the `Steps` itself, and each following step when the
+//!   one before it succeeds.
+//! * [`Retry`] spawns its first attempt along with itself, and each retry
+//!   once the previous attempt has failed.
+//! * [`Concurrently`] spawns all of its children along with itself, unless
+//!   it's [`lazy`](ConcurrentlyBuilder::lazy), in which case they're spawned
+//!   when it's first requested.
+//! * [`RandomAction`] spawns the child it picks each time it's requested.
+//! * [`Detach`] spawns its child each time it's requested, on its own rather
+//!   than as a child, so it can outlive the `Detach`.
+use std::{
+    any::Any,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
 
-use bevy::prelude::*;
 #[cfg(feature = "trace")]
 use bevy::utils::tracing::trace;
+use bevy::{
+    ecs::system::{StaticSystemParam, SystemParam, SystemParamItem},
+    prelude::*,
+    utils::Duration,
+};
 
-use crate::thinker::{Action, ActionSpan, Actor};
+use crate::{
+    scorers::{self, Score, ScorerBuilder},
+    thinker::{Action, ActionSpan, Actor, Scorer},
+    BuildDepthGuard, MaxBuildDepth,
+};
 
 /// The current state for an Action. These states are changed by a combination
 /// of the Thinker that spawned it, and the actual Action system executing the
@@ -15,7 +43,13 @@ use crate::thinker::{Action, ActionSpan, Actor};
 /// Action system implementors should be mindful of taking appropriate action
 /// on all of these states, and be particularly careful when ignoring
 /// variants.
+///
+/// With the `serde` feature enabled, this implements `Serialize` and
+/// `Deserialize`, for saving and loading. See [`Thinker`](crate::thinker::Thinker)
+/// for how to bring a whole Thinker back from a save.
 #[derive(Debug, Clone, Component, Eq, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component, Default, PartialEq)]
 #[component(storage = "SparseSet")]
 pub enum ActionState {
     /// Initial state. No action should be performed.
@@ -30,14 +64,43 @@ pub enum ActionState {
     /// Cancelled.
     Executing,
 
-    /// An ongoing Action has been cancelled. The Thinker might set this
-    /// action for you, so for Actions that execute for longer than a single
+    /// An ongoing Action has been cancelled, and should stop as soon as
+    /// possible. The Thinker might set this action for you when it's stopped
+    /// altogether, so for Actions that execute for longer than a single
     /// tick, **you must check whether the Cancelled state was set** and
     /// change do either Success or Failure. Thinkers will wait on Cancelled
     /// actions to do any necessary cleanup work, so this can hang your AI if
     /// you don't look for it.
     Cancelled,
 
+    /// The Thinker has picked something else to do, and would like this
+    /// Action to wrap up when it's convenient. This is a softer version of
+    /// [`ActionState::Cancelled`], which Thinkers use when switching between
+    /// Actions, unless they were built with
+    /// [`ThinkerBuilder::cancel_on_switch`](crate::thinker::ThinkerBuilder::cancel_on_switch).
+    /// `Cancelled` is kept for when the Thinker itself is stopped.
+    ///
+    /// An Action that doesn't care about the difference can keep treating
+    /// this state as [`ActionState::Executing`] until it finishes on its own
+    /// (see [`ActionState::is_executing`]), or handle it exactly like
+    /// `Cancelled`. Either way, it should eventually change to either
+    /// Success or Failure. If it's still Interrupted once the Thinker's grace
+    /// period is up, the Thinker cancels it.
+    Interrupted,
+
+    /// The Action did a chunk of its work and wants to pick up where it left
+    /// off next tick, without the Thinker switching away from it in the
+    /// meantime. This is for expensive Actions that spread their work out
+    /// over several frames.
+    ///
+    /// The Thinker that's running the Action (even if it's nested inside a
+    /// Composite Action) won't pick anything else while it's yielded, and
+    /// sets it back to [`ActionState::Executing`] before the next
+    /// [`BigBrainSet::Actions`](crate::BigBrainSet::Actions) pass. Yield
+    /// again whenever you want another tick of protection. Yielding doesn't
+    /// protect an Action from being [`ActionState::Cancelled`].
+    Yielded,
+
     /// The Action was a success. This is used by Composite Actions to
     /// determine whether to continue execution.
     Success,
@@ -47,6 +110,7 @@ pub enum ActionState {
     Failure,
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for ActionState {
     fn default() -> Self {
         Self::Init
@@ -57,17 +121,524 @@ impl ActionState {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Whether the Action should keep doing its thing. This is `true` for
+    /// [`ActionState::Executing`], [`ActionState::Yielded`], and
+    /// [`ActionState::Interrupted`], so Actions that would rather finish
+    /// gracefully than stop early can use it to treat interruptions as
+    /// business as usual.
+    pub fn is_executing(&self) -> bool {
+        matches!(self, Self::Executing | Self::Yielded | Self::Interrupted)
+    }
+}
+
+/// Tracks how long an Action has been running. Every Action spawned through
+/// [`spawn_action`] gets one of these, and its clock starts the first time
+/// the Action is seen as [`ActionState::Requested`] or
+/// [`ActionState::Executing`].
+///
+/// This is handy for timeouts, ramps, and anything else that would otherwise
+/// need its own timer component.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::utils::Duration;
+/// # use big_brain::prelude::*;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct Wait;
+/// fn wait_action_system(mut query: Query<(&mut ActionState, &ActionAge), With<Wait>>) {
+///     for (mut state, age) in query.iter_mut() {
+///         if *state == ActionState::Executing && age.elapsed() > Duration::from_secs(2) {
+///             *state = ActionState::Success;
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Component, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct ActionAge {
+    started: Option<Duration>,
+    elapsed: Duration,
+}
+
+impl ActionAge {
+    /// When this Action started running, as a [`Time::elapsed`] value, if it
+    /// has started at all.
+    pub fn started(&self) -> Option<Duration> {
+        self.started
+    }
+
+    /// How long this Action has been running. Returns [`Duration::ZERO`] if
+    /// the Action hasn't been requested yet.
+    ///
+    /// This follows Bevy's [`Time`], so it stops counting while
+    /// [`Time<Virtual>`] is paused and speeds up or slows down along with it.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+/// System that starts the clock on [`ActionAge`] once its Action gets going,
+/// and keeps it ticking.
+pub fn action_age_system(time: Res<Time>, mut query: Query<(&ActionState, &mut ActionAge)>) {
+    let now = time.elapsed();
+    for (state, mut age) in query.iter_mut() {
+        match age.started {
+            Some(started) => {
+                age.elapsed = now.saturating_sub(started);
+            }
+            None if matches!(state, ActionState::Requested | ActionState::Executing) => {
+                age.started = Some(now);
+            }
+            None => {}
+        }
+    }
+}
+
+/// Despawns a finished `action`, along with its children.
+pub(crate) fn despawn_completed(cmd: &mut Commands, action: Entity) {
+    if let Some(ent) = cmd.get_entity(action) {
+        ent.despawn_recursive();
+    }
+}
+
+/// Lists every actor that's currently running an Action with component `A`,
+/// that is, one that's [`ActionState::Executing`]. Actions that were only just
+/// requested, or have already finished, don't count. Each actor is listed
+/// once, even if it's running several `A`s at the same time.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// #[derive(Clone, Component, Debug, ActionBuilder)]
+/// struct Investigate;
+///
+/// fn raise_alarm(world: &mut World) {
+///     let investigating = big_brain::actors_running::<Investigate>(world);
+///     if investigating.len() >= 3 {
+///         info!("Something's definitely up.");
+///     }
+/// }
+/// ```
+pub fn actors_running<A: Component>(world: &mut World) -> Vec<Entity> {
+    let mut actors: Vec<_> = world
+        .query_filtered::<(&Actor, &ActionState), With<A>>()
+        .iter(world)
+        .filter(|(_, state)| **state == ActionState::Executing)
+        .map(|(Actor(actor), _)| *actor)
+        .collect();
+    actors.sort_unstable();
+    actors.dedup();
+    actors
+}
+
+/// Resource that turns on Action pooling. Inserted by
+/// [`BigBrainPlugin::pool_actions`](crate::BigBrainPlugin::pool_actions).
+///
+/// With pooling on, a Thinker holds on to the entity of an Action it's done
+/// with instead of despawning it. The next time it picks the same choice (or
+/// falls back to the same `otherwise`), the new Action gets built into that
+/// entity instead of a freshly-spawned one. For Thinkers that keep cycling
+/// through a handful of short-lived Actions, this saves a lot of entity
+/// allocation and archetype moves.
+///
+/// A Thinker keeps at most one pooled entity per choice, and gets rid of them
+/// when it stops. Pooled entities keep their components, including their
+/// final [`ActionState`], until they're reused, but any children they had are
+/// despawned right away. Reused entities are cleared out before the Action is
+/// built into them again, so they start out just like a freshly-spawned one.
+#[derive(Debug, Clone, Copy, Default, Resource, Reflect)]
+pub struct ActionPooling;
+
+/// Warns when an actor has more than one live Action carrying the same
+/// Action component, like when the same `Drink` Action is used in two
+/// different choices of a [MultiThinker](crate::thinker::ThinkerBuilder::multi).
+/// Systems that look for `With<Drink>` end up processing all of them, which
+/// is usually a modeling mistake. Warns once each time it happens.
+///
+/// Only runs if enabled with
+/// [`BigBrainPlugin::warn_on_action_conflicts`](crate::BigBrainPlugin::warn_on_action_conflicts),
+/// and only checks Action types registered with
+/// [`BigBrainAppExt::register_action`](crate::registry::BigBrainAppExt::register_action).
+pub fn action_conflict_system(
+    world: &World,
+    actions: Query<(Entity, &Actor, &ActionState), Without<crate::thinker::Thinker>>,
+    mut warned: Local<bevy::utils::HashSet<(Entity, bevy::ecs::component::ComponentId)>>,
+) {
+    let Some(registry) = world.get_resource::<crate::registry::BigBrainTypeRegistry>() else {
+        return;
+    };
+    let action_components: Vec<_> = registry
+        .actions()
+        .filter_map(|builder| world.components().get_id(builder.type_id))
+        .collect();
+    let mut seen = bevy::utils::HashSet::new();
+    let mut conflicts = bevy::utils::HashSet::new();
+    for (action, Actor(actor), state) in actions.iter() {
+        if matches!(state, ActionState::Success | ActionState::Failure) {
+            continue;
+        }
+        let Ok(action) = world.get_entity(action) else {
+            continue;
+        };
+        for component in action_components.iter() {
+            if action.contains_id(*component) && !seen.insert((*actor, *component)) {
+                conflicts.insert((*actor, *component));
+            }
+        }
+    }
+    for (actor, component) in conflicts.iter() {
+        if !warned.contains(&(*actor, *component)) {
+            let name = world
+                .components()
+                .get_name(*component)
+                .unwrap_or("an Action");
+            warn!(
+                "Actor {:?} has several live Actions with a {} component, and systems querying for it will process all of them. Is the same Action used in more than one choice?",
+                actor, name
+            );
+        }
+    }
+    *warned = conflicts;
+}
+
+/// Sets [`ActionState::Yielded`] Actions back to [`ActionState::Executing`],
+/// after Thinkers have had a chance to see that they yielded. Runs every
+/// frame, whether or not their Thinker has a decision to act on, and also
+/// picks up Actions that aren't run by a Thinker at all.
+pub fn resume_yielded_system(mut states: Query<&mut ActionState, Changed<ActionState>>) {
+    for mut state in states.iter_mut() {
+        if *state == ActionState::Yielded {
+            *state = ActionState::Executing;
+        }
+    }
+}
+
+/// Event sent whenever an Action's [`ActionState`] changes. Only sent if
+/// enabled with [`BigBrainPlugin::emit_action_state_events`](crate::BigBrainPlugin::emit_action_state_events).
+///
+/// States are compared once per frame, so an Action that goes through
+/// several states within a single frame will only report the overall
+/// change.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// fn log_action_changes(mut events: EventReader<ActionStateChanged>) {
+///     for event in events.read() {
+///         info!(
+///             "{:?} went from {:?} to {:?} for {:?}",
+///             event.label, event.old, event.new, event.actor
+///         );
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Event)]
+pub struct ActionStateChanged {
+    /// The actor performing the Action.
+    pub actor: Entity,
+    /// The Action entity whose state changed.
+    pub action: Entity,
+    /// The label of the Action's [`ActionBuilder`], if any.
+    pub label: Option<String>,
+    /// The state the Action was in last time we checked.
+    pub old: ActionState,
+    /// The state the Action is in now.
+    pub new: ActionState,
+}
+
+/// The last [`ActionState`] reported through [`ActionStateChanged`].
+#[derive(Debug, Clone, Component)]
+pub(crate) struct LastActionState(ActionState);
+
+/// System that sends [`ActionStateChanged`] events.
+#[allow(clippy::type_complexity)]
+pub(crate) fn action_state_changed_system(
+    mut cmd: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &Actor,
+            &ActionState,
+            Option<&mut LastActionState>,
+            Option<&ActionSpan>,
+        ),
+        Changed<ActionState>,
+    >,
+    mut events: EventWriter<ActionStateChanged>,
+) {
+    for (action, Actor(actor), state, last, span) in query.iter_mut() {
+        let old = match last {
+            Some(mut last) => std::mem::replace(&mut last.0, state.clone()),
+            None => {
+                cmd.entity(action).insert(LastActionState(state.clone()));
+                ActionState::Init
+            }
+        };
+        if old != *state {
+            events.send(ActionStateChanged {
+                actor: *actor,
+                action,
+                label: span.and_then(|span| span.label()).map(|s| s.into()),
+                old,
+                new: state.clone(),
+            });
+        }
+    }
+}
+
+/// The query almost every Action system needs, packaged up as a
+/// [`SystemParam`]. Derefs to a [`Query`] over `(&Actor, &mut ActionState,
+/// &T, &ActionSpan)`, so it includes the [`ActionSpan`] you'll want for
+/// tracing.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// use big_brain::actions::ActionQuery;
+///
+/// #[derive(Clone, Component, Debug, ActionBuilder)]
+/// struct Wave;
+///
+/// fn wave_action_system(mut actions: ActionQuery<Wave>) {
+///     for (Actor(actor), mut state, _wave, span) in actions.iter_mut() {
+///         let _guard = span.span().enter();
+///         if *state == ActionState::Requested {
+///             info!("{actor:?} waves!");
+///             *state = ActionState::Success;
+///         }
+///     }
+/// }
+/// # bevy::ecs::system::assert_is_system(wave_action_system);
+/// ```
+#[derive(SystemParam)]
+pub struct ActionQuery<'w, 's, T: Component> {
+    query: Query<'w, 's, ActionQueryData<T>>,
+}
+
+type ActionQueryData<T> = (
+    &'static Actor,
+    &'static mut ActionState,
+    &'static T,
+    &'static ActionSpan,
+);
+
+impl<'w, 's, T: Component> std::ops::Deref for ActionQuery<'w, 's, T> {
+    type Target = Query<'w, 's, ActionQueryData<T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.query
+    }
+}
+
+impl<T: Component> std::ops::DerefMut for ActionQuery<'_, '_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.query
+    }
+}
+
+/// The usual state machine most Actions implement, so you only need to write
+/// the interesting bits. Implement this for your Action component, and add
+/// [`action_state_machine_system`] for it to [`BigBrainSet::Actions`](crate::BigBrainSet::Actions).
+/// That system takes care of the `match *state` scaffolding, calling these
+/// methods and setting the [`ActionState`] to whatever they return:
+///
+/// * `Requested` calls [`ActionStateMachine::on_start`], which starts
+///   executing by default.
+/// * `Executing` calls [`ActionStateMachine::on_execute`].
+/// * `Cancelled` calls [`ActionStateMachine::on_cancel`], which fails by
+///   default.
+/// * `Interrupted` calls [`ActionStateMachine::on_interrupt`], which does the
+///   same as `on_cancel` by default.
+///
+/// Anything the Action needs from the world goes in `Param`. It can't access
+/// the Action component itself or [`ActionState`], since the system already
+/// does.
+///
+/// This is entirely opt-in. Actions with unusual needs can keep writing
+/// their own systems.
+///
+/// Instead of implementing this by hand, you can add `#[action(transition)]`
+/// to an [`ActionBuilder`] derive. It implements this trait by calling the
+/// Action's own `on_execute` method, which takes the same arguments as
+/// [`ActionStateMachine::on_execute`]. `#[action(param = "...")]` sets
+/// `Param`, which is `()` otherwise, and `#[action(on_cancel)]` calls the
+/// Action's own `on_cancel` method as well. These must be inherent methods.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # use big_brain::actions::action_state_machine_system;
+/// # #[derive(Component, Debug)]
+/// # struct Thirst(f32);
+/// #[derive(Clone, Component, Debug, ActionBuilder)]
+/// #[action(transition, param = "Query<'static, 'static, &'static mut Thirst>")]
+/// struct Drink {
+///     per_tick: f32,
+/// }
+///
+/// impl Drink {
+///     fn on_execute(
+///         &mut self,
+///         actor: Entity,
+///         thirsts: &mut Query<&mut Thirst>,
+///     ) -> ActionState {
+///         let Ok(mut thirst) = thirsts.get_mut(actor) else {
+///             return ActionState::Failure;
+///         };
+///         thirst.0 -= self.per_tick;
+///         if thirst.0 <= 0.0 {
+///             ActionState::Success
+///         } else {
+///             ActionState::Executing
+///         }
+///     }
+/// }
+///
+/// # fn main() {
+/// App::new()
+///     .add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+///     .add_systems(
+///         PreUpdate,
+///         action_state_machine_system::<Drink>.in_set(BigBrainSet::Actions),
+///     );
+/// # }
+/// ```
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::SystemParamItem;
+/// # use big_brain::prelude::*;
+/// # use big_brain::actions::{action_state_machine_system, ActionStateMachine};
+/// #[derive(Component, Debug)]
+/// struct Thirst(f32);
+///
+/// #[derive(Clone, Component, Debug, ActionBuilder)]
+/// struct Drink {
+///     per_tick: f32,
+/// }
+///
+/// impl ActionStateMachine for Drink {
+///     type Param = Query<'static, 'static, &'static mut Thirst>;
+///
+///     fn on_execute(
+///         &mut self,
+///         actor: Entity,
+///         thirsts: &mut SystemParamItem<Self::Param>,
+///     ) -> ActionState {
+///         let Ok(mut thirst) = thirsts.get_mut(actor) else {
+///             return ActionState::Failure;
+///         };
+///         thirst.0 -= self.per_tick;
+///         if thirst.0 <= 0.0 {
+///             ActionState::Success
+///         } else {
+///             ActionState::Executing
+///         }
+///     }
+/// }
+///
+/// # fn main() {
+/// App::new()
+///     .add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+///     .add_systems(
+///         PreUpdate,
+///         action_state_machine_system::<Drink>.in_set(BigBrainSet::Actions),
+///     );
+/// # }
+/// ```
+pub trait ActionStateMachine: Component + Sized {
+    /// What the Action needs from the world, such as a [`Query`] or a
+    /// [`Res`]. Use `'static` for its lifetimes.
+    type Param: SystemParam + 'static;
+
+    /// Called when the Action is requested. Starts executing by default.
+    fn on_start(
+        &mut self,
+        _actor: Entity,
+        _param: &mut SystemParamItem<Self::Param>,
+    ) -> ActionState {
+        ActionState::Executing
+    }
+
+    /// Called every tick while the Action is executing.
+    fn on_execute(
+        &mut self,
+        actor: Entity,
+        param: &mut SystemParamItem<Self::Param>,
+    ) -> ActionState;
+
+    /// Called every tick while the Action is cancelled, until it returns
+    /// [`ActionState::Success`] or [`ActionState::Failure`]. Fails right away
+    /// by default.
+    fn on_cancel(
+        &mut self,
+        _actor: Entity,
+        _param: &mut SystemParamItem<Self::Param>,
+    ) -> ActionState {
+        ActionState::Failure
+    }
+
+    /// Called every tick while the Action is interrupted. Defaults to
+    /// [`ActionStateMachine::on_cancel`].
+    fn on_interrupt(
+        &mut self,
+        actor: Entity,
+        param: &mut SystemParamItem<Self::Param>,
+    ) -> ActionState {
+        self.on_cancel(actor, param)
+    }
+}
+
+/// System that drives any Actions of type `T` through their
+/// [`ActionStateMachine`].
+pub fn action_state_machine_system<T: ActionStateMachine>(
+    mut query: Query<(&Actor, &mut ActionState, &mut T, &ActionSpan)>,
+    mut param: StaticSystemParam<T::Param>,
+) {
+    use ActionState::*;
+    for (Actor(actor), mut state, mut action, span) in query.iter_mut() {
+        let _guard = span.span().enter();
+        let new_state = match *state {
+            Requested => action.on_start(*actor, &mut param),
+            Executing => action.on_execute(*actor, &mut param),
+            Cancelled => action.on_cancel(*actor, &mut param),
+            Interrupted => action.on_interrupt(*actor, &mut param),
+            Init | Yielded | Success | Failure => continue,
+        };
+        state.set_if_neq(new_state);
+    }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
-pub(crate) struct ActionBuilderId;
+/// Identifies one use of an [`ActionBuilder`] within a Thinker, so choices
+/// that share a builder can still be told apart. Thinkers compare these
+/// every tick, so they're plain integers rather than pointers.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub(crate) struct ActionBuilderId(u64);
+
+impl ActionBuilderId {
+    fn next() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        ActionBuilderId(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
 
 #[derive(Debug, Clone)]
-pub(crate) struct ActionBuilderWrapper(pub Arc<ActionBuilderId>, pub Arc<dyn ActionBuilder>);
+pub(crate) struct ActionBuilderWrapper(pub ActionBuilderId, pub Arc<dyn ActionBuilder>);
 
 impl ActionBuilderWrapper {
     pub fn new(builder: Arc<dyn ActionBuilder>) -> Self {
-        ActionBuilderWrapper(Arc::new(ActionBuilderId), builder)
+        ActionBuilderWrapper(ActionBuilderId::next(), builder)
     }
 }
 
@@ -101,6 +672,22 @@ pub trait ActionBuilder: std::fmt::Debug + Send + Sync {
     /// struct MyAction;
     /// ```
     ///
+    /// Mark an `Entity` field with `#[actor]` and the derived `build` will
+    /// fill it in with the actor the action is spawned for:
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// #[derive(Debug, Clone, Component, ActionBuilder)]
+    /// struct Chase {
+    ///     #[actor]
+    ///     chaser: Entity,
+    ///     speed: f32,
+    /// }
+    ///
+    /// let chase = Chase { chaser: Entity::PLACEHOLDER, speed: 2.0 };
+    /// ```
+    ///
     /// Implementing it manually:
     ///
     /// ```
@@ -125,82 +712,1231 @@ pub trait ActionBuilder: std::fmt::Debug + Send + Sync {
     fn label(&self) -> Option<&str> {
         None
     }
+
+    /// Called by [`spawn_action`] right after [`ActionBuilder::build`], once
+    /// the usual `Name`, [`ActionState`] and [`Actor`] components have been
+    /// inserted. Override it to add your own components to the Action
+    /// [`Entity`], or to replace its `Name`. Does nothing by default.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// #[derive(Debug, Component)]
+    /// struct DebugGizmo;
+    /// #[derive(Debug)]
+    /// struct Drink;
+    ///
+    /// impl ActionBuilder for Drink {
+    ///   fn build(&self, _cmd: &mut Commands, _action: Entity, _actor: Entity) {}
+    ///
+    ///   fn on_spawn(&self, cmd: &mut Commands, action: Entity) {
+    ///     cmd.entity(action).insert((Name::new("Drink"), DebugGizmo));
+    ///   }
+    /// }
+    /// ```
+    fn on_spawn(&self, _cmd: &mut Commands, _action: Entity) {}
+
+    /// Opts this builder into [`ActionBuilder::build_exclusive`]. Defaults to
+    /// `false`.
+    fn wants_exclusive_build(&self) -> bool {
+        false
+    }
+
+    /// Called with full [`World`] access right after `build()`'s commands
+    /// have been applied, but only if [`ActionBuilder::wants_exclusive_build`]
+    /// returns `true`. Use this when building your Action requires reading
+    /// other components off the actor (or anything else in the `World`),
+    /// which you can't do through `Commands`.
+    ///
+    /// Exclusive builds only happen for Actions spawned from a shared builder,
+    /// which is what Thinkers and the built-in Composite Actions do. See
+    /// [`spawn_shared_action`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// # #[derive(Debug, Component)]
+    /// # struct Position(Vec2);
+    /// #[derive(Debug, Component)]
+    /// struct WalkHome {
+    ///     from: Vec2,
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// struct WalkHomeBuilder;
+    ///
+    /// impl ActionBuilder for WalkHomeBuilder {
+    ///     fn build(&self, _cmd: &mut Commands, _action: Entity, _actor: Entity) {}
+    ///
+    ///     fn wants_exclusive_build(&self) -> bool {
+    ///         true
+    ///     }
+    ///
+    ///     fn build_exclusive(&self, world: &mut World, action: Entity, actor: Entity) {
+    ///         let from = world.get::<Position>(actor).map(|p| p.0).unwrap_or_default();
+    ///         world.entity_mut(action).insert(WalkHome { from });
+    ///     }
+    /// }
+    /// ```
+    fn build_exclusive(&self, _world: &mut World, _action: Entity, _actor: Entity) {}
+}
+
+/// Spawns a new Action Component, using the given ActionBuilder. This is
+/// useful when you're doing things like writing composite Actions.
+///
+/// This only borrows `builder`, so it can't hold on to it until the `World`
+/// is available: [`ActionBuilder::build_exclusive`] is never called for
+/// Actions spawned this way, and a warning is logged if the builder asks for
+/// it. Use [`spawn_shared_action`] for those builders instead.
+pub fn spawn_action<T: ActionBuilder + ?Sized>(
+    builder: &T,
+    cmd: &mut Commands,
+    actor: Entity,
+) -> Entity {
+    if builder.wants_exclusive_build() {
+        warn!(
+            "{:?} wants an exclusive build, which spawn_action can't do. Use spawn_shared_action instead.",
+            builder
+        );
+    }
+    let action_ent = cmd.spawn_empty().id();
+    build_action(builder, cmd, action_ent, actor);
+    action_ent
+}
+
+/// Builds an Action into `action_ent`, inserting (or replacing) every
+/// component [`spawn_action`] would give a brand new one.
+fn build_action<T: ActionBuilder + ?Sized>(
+    builder: &T,
+    cmd: &mut Commands,
+    action_ent: Entity,
+    actor: Entity,
+) {
+    let span = ActionSpan::new(action_ent, ActionBuilder::label(builder));
+    let _guard = span.span().enter();
+    debug!("New Action spawned.");
+    cmd.entity(action_ent)
+        .insert(Name::new("Action"))
+        .insert(ActionState::new())
+        .insert(ActionAge::default())
+        .insert(Actor(actor));
+    if let Some(_depth) = BuildDepthGuard::enter() {
+        builder.build(cmd, action_ent, actor);
+        builder.on_spawn(cmd, action_ent);
+    } else {
+        error!("Action nested too deeply. Is an ActionBuilder including itself? Marking it as failed instead of building it.");
+        cmd.entity(action_ent).insert(ActionState::Failure);
+    }
+    std::mem::drop(_guard);
+    cmd.entity(action_ent).insert(span);
+}
+
+/// Like [`spawn_action`], but takes a shared builder. This is the only way to
+/// spawn Actions whose builders use [`ActionBuilder::build_exclusive`], so
+/// prefer it when writing composite Actions that hold on to their children's
+/// builders.
+pub fn spawn_shared_action(
+    builder: &Arc<dyn ActionBuilder>,
+    cmd: &mut Commands,
+    actor: Entity,
+) -> Entity {
+    let action_ent = cmd.spawn_empty().id();
+    build_shared_action(builder, cmd, action_ent, actor);
+    action_ent
+}
+
+/// Like [`spawn_shared_action`], but builds the Action into an existing
+/// entity. Used to reuse pooled Action entities. See [`ActionPooling`].
+pub(crate) fn build_shared_action(
+    builder: &Arc<dyn ActionBuilder>,
+    cmd: &mut Commands,
+    action_ent: Entity,
+    actor: Entity,
+) {
+    build_action(builder.as_ref(), cmd, action_ent, actor);
+    if builder.wants_exclusive_build() {
+        let builder = builder.clone();
+        cmd.queue(move |world: &mut World| {
+            if world.get_entity(action_ent).is_ok() {
+                builder.build_exclusive(world, action_ent, actor);
+            }
+        });
+    }
+}
+
+/// Extension methods for composing [`ActionBuilder`]s fluently. This is
+/// implemented for every `ActionBuilder`, and is included in the prelude.
+pub trait ActionBuilderExt: ActionBuilder + Sized + 'static {
+    /// Runs `next` after this action succeeds. Returns a [`StepsBuilder`], so
+    /// you can keep chaining. Unlabeled [`StepsBuilder`]s on either side are
+    /// flattened into a single sequence rather than nested.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+    /// # struct Thirsty;
+    /// # #[derive(Debug, Clone, Component, ActionBuilder)]
+    /// # struct MoveToWaterSource;
+    /// # #[derive(Debug, Clone, Component, ActionBuilder)]
+    /// # struct Drink;
+    /// # #[derive(Debug, Clone, Component, ActionBuilder)]
+    /// # struct Burp;
+    /// # fn main() {
+    /// Thinker::build()
+    ///     .when(Thirsty, MoveToWaterSource.then(Drink).then(Burp))
+    /// # ;
+    /// # }
+    /// ```
+    fn then(self, next: impl ActionBuilder + 'static) -> StepsBuilder {
+        Steps::build().then(self).then(next)
+    }
+
+    /// Runs `other` at the same time as this action. Returns a
+    /// [`ConcurrentlyBuilder`] in [`ConcurrentMode::Join`] mode, so you can
+    /// keep chaining. Unlabeled `Join` [`ConcurrentlyBuilder`]s on either side
+    /// are flattened into a single [`Concurrently`] rather than nested.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+    /// # struct OnDuty;
+    /// # #[derive(Debug, Clone, Component, ActionBuilder)]
+    /// # struct Patrol;
+    /// # #[derive(Debug, Clone, Component, ActionBuilder)]
+    /// # struct ScanForEnemies;
+    /// # #[derive(Debug, Clone, Component, ActionBuilder)]
+    /// # struct Whistle;
+    /// # fn main() {
+    /// Thinker::build()
+    ///     .when(OnDuty, Patrol.and(ScanForEnemies).and(Whistle))
+    /// # ;
+    /// # }
+    /// ```
+    fn and(self, other: impl ActionBuilder + 'static) -> ConcurrentlyBuilder {
+        Concurrently::build().and(self).and(other)
+    }
+}
+
+impl<T: ActionBuilder + 'static> ActionBuilderExt for T {}
+
+/// What a [`Steps`] action does with the steps it hasn't reached yet when
+/// it gets cancelled or interrupted. Set with [`StepsBuilder::cancel_policy`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum StepsCancelPolicy {
+    /// The active step is cancelled, and the whole sequence ends along with
+    /// it. The remaining steps never run.
+    #[default]
+    Abort,
+    /// Only the active step is cancelled. Once it wraps up, the remaining
+    /// steps run as usual, and the sequence ends after the last one does. If
+    /// an interrupted sequence is then cancelled outright (for example, once
+    /// [`ThinkerBuilder::interrupt_on_switch`](crate::thinker::ThinkerBuilder::interrupt_on_switch)'s
+    /// grace period runs out), the step running at that point is cancelled
+    /// too, and the ones after it still run.
+    SkipStep,
+}
+
+/// [`ActionBuilder`] for the [`Steps`] component. Constructed through
+/// `Steps::build()`.
+#[derive(Debug, Reflect)]
+#[reflect(ActionBuilder)]
+pub struct StepsBuilder {
+    label: Option<String>,
+    steps_labels: Vec<String>,
+    #[reflect(ignore)]
+    steps: Vec<Arc<dyn ActionBuilder>>,
+    start_at: usize,
+    cancel_policy: StepsCancelPolicy,
+}
+
+impl StepsBuilder {
+    /// Sets the logging label for the Action
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Adds an action step. Order matters.
+    pub fn step(mut self, action_builder: impl ActionBuilder + 'static) -> Self {
+        if let Some(label) = action_builder.label() {
+            self.steps_labels.push(label.into());
+        } else {
+            self.steps_labels.push("Unlabeled Action".into());
+        }
+        self.steps.push(Arc::new(action_builder));
+        self
+    }
+
+    /// Starts the sequence at step `index` instead of the first one, skipping
+    /// every step before it. This is handy for resuming a sequence after
+    /// loading a saved game, along with [`Steps::active_step`].
+    ///
+    /// If `index` isn't less than the number of steps, the action fails
+    /// right away instead.
+    pub fn start_at(mut self, index: usize) -> Self {
+        self.start_at = index;
+        self
+    }
+
+    /// Sets what happens to the remaining steps when the sequence is
+    /// cancelled or interrupted. Defaults to [`StepsCancelPolicy::Abort`].
+    ///
+    /// With [`StepsCancelPolicy::SkipStep`], whatever cancelled the sequence
+    /// has to wait for all of the remaining steps to finish, so it's best
+    /// kept for short wrap-up steps like putting away a tool.
+    pub fn cancel_policy(mut self, policy: StepsCancelPolicy) -> Self {
+        self.cancel_policy = policy;
+        self
+    }
+
+    /// Adds an action step, just like [`StepsBuilder::step`]. If the action
+    /// is itself an unlabeled [`StepsBuilder`] that starts at its first step
+    /// and uses the default cancel policy, its steps are appended directly
+    /// instead of being nested as a single step.
+    pub fn then<T: ActionBuilder + 'static>(mut self, action_builder: T) -> Self {
+        let mut action_builder = Some(action_builder);
+        if let Some(steps) =
+            (&mut action_builder as &mut dyn Any).downcast_mut::<Option<StepsBuilder>>()
+        {
+            let steps = steps.take().expect("we just put it there");
+            if steps.label.is_none()
+                && steps.start_at == 0
+                && steps.cancel_policy == StepsCancelPolicy::Abort
+            {
+                self.steps.extend(steps.steps);
+                self.steps_labels.extend(steps.steps_labels);
+                return self;
+            }
+            return self.step(steps);
+        }
+        self.step(action_builder.expect("we just put it there"))
+    }
+}
+
+impl ActionBuilder for StepsBuilder {
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    fn build(&self, cmd: &mut Commands, action: Entity, actor: Entity) {
+        if self.steps.is_empty() {
+            return;
+        }
+        if self.start_at >= self.steps.len() {
+            error!(
+                "Steps can't start at step {} when there are only {} steps. Marking it as failed instead of building it.",
+                self.start_at,
+                self.steps.len()
+            );
+            cmd.entity(action).insert(ActionState::Failure);
+            return;
+        }
+        let child_action = spawn_shared_action(&self.steps[self.start_at], cmd, actor);
+        cmd.entity(action)
+            .insert(Name::new("Steps Action"))
+            .insert(Steps {
+                active_step: self.start_at,
+                active_ent: Action(child_action),
+                steps: self.steps.clone(),
+                steps_labels: self.steps_labels.clone(),
+                jump_to: None,
+                cancel_policy: self.cancel_policy,
+                skipping: None,
+            })
+            .add_children(&[child_action]);
+    }
+}
+
+/// Composite Action that executes a series of steps in sequential order, as
+/// long as each step results in a `Success`ful [`ActionState`].
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct MyScorer;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct MyAction;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct MyNextAction;
+/// # fn main() {
+/// Thinker::build()
+///     .when(
+///         MyScorer,
+///         Steps::build()
+///             .step(MyAction)
+///             .step(MyNextAction)
+///         )
+/// # ;
+/// # }
+/// ```
+#[derive(Component, Debug, Reflect)]
+pub struct Steps {
+    #[reflect(ignore)]
+    steps: Vec<Arc<dyn ActionBuilder>>,
+    steps_labels: Vec<String>,
+    active_step: usize,
+    active_ent: Action,
+    jump_to: Option<usize>,
+    cancel_policy: StepsCancelPolicy,
+    /// The state this sequence was in when it started skipping to the
+    /// remaining steps, if it has.
+    skipping: Option<ActionState>,
+}
+
+impl Steps {
+    /// Construct a new [`StepsBuilder`] to define the steps to take.
+    pub fn build() -> StepsBuilder {
+        StepsBuilder {
+            steps: Vec::new(),
+            steps_labels: Vec::new(),
+            label: None,
+            start_at: 0,
+            cancel_policy: StepsCancelPolicy::Abort,
+        }
+    }
+
+    /// Index of the step that's currently running.
+    pub fn active_step(&self) -> usize {
+        self.active_step
+    }
+
+    /// How many steps there are in total.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Whether there are no steps at all.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// The label of the step that's currently running, or `"Unlabeled
+    /// Action"` if it doesn't have one.
+    pub fn active_step_label(&self) -> &str {
+        &self.steps_labels[self.active_step]
+    }
+
+    /// The Action entity for the step that's currently running.
+    pub fn active_action(&self) -> Action {
+        self.active_ent
+    }
+
+    /// Switches to step `index`, from which the sequence carries on as usual.
+    /// The active step is cancelled first, and `index` starts once it has
+    /// wrapped up, so [`Steps::active_step`] only changes then. Jumping
+    /// doesn't count as the active step failing, even if it ends up as
+    /// [`ActionState::Failure`] after being cancelled. Jumping again before
+    /// that happens replaces the earlier jump.
+    ///
+    /// Returns `false` and does nothing if `index` isn't less than the number
+    /// of steps.
+    pub fn jump_to(&mut self, index: usize) -> bool {
+        if index >= self.steps.len() {
+            return false;
+        }
+        self.jump_to = Some(index);
+        true
+    }
+
+    /// Despawns the active step and spawns step `index` in its place.
+    fn replace_active_step(
+        &mut self,
+        cmd: &mut Commands,
+        seq_ent: Entity,
+        actor: Entity,
+        index: usize,
+    ) -> Entity {
+        despawn_completed(cmd, self.active_ent.entity());
+        self.active_step = index;
+        let step_builder = self.steps[index].clone();
+        let step_ent = spawn_shared_action(&step_builder, cmd, actor);
+        cmd.entity(seq_ent).add_children(&[step_ent]);
+        self.active_ent = Action(step_ent);
+        step_ent
+    }
+
+    /// Adds a step to the end of the sequence. This can be done at any point
+    /// while the sequence is running, and the new step will run once every
+    /// step before it has succeeded.
+    pub fn push_step(&mut self, action_builder: impl ActionBuilder + 'static) {
+        let index = self.steps.len();
+        self.insert_step(index, action_builder);
+    }
+
+    /// Inserts a step at `index`, shifting all later steps down by one.
+    ///
+    /// Returns `false` and does nothing if `index` is greater than the number
+    /// of steps, or if it's not after the currently active step. Steps that
+    /// have already run (or are running) can't be changed.
+    pub fn insert_step(
+        &mut self,
+        index: usize,
+        action_builder: impl ActionBuilder + 'static,
+    ) -> bool {
+        if index <= self.active_step || index > self.steps.len() {
+            return false;
+        }
+        let label = action_builder
+            .label()
+            .unwrap_or("Unlabeled Action")
+            .to_string();
+        self.steps_labels.insert(index, label);
+        self.steps.insert(index, Arc::new(action_builder));
+        true
+    }
+
+    /// Removes the step at `index`, shifting all later steps up by one.
+    ///
+    /// Returns `false` and does nothing if `index` is out of bounds, or if
+    /// it's not after the currently active step. Steps that have already run
+    /// (or are running) can't be changed.
+    pub fn remove_step(&mut self, index: usize) -> bool {
+        if index <= self.active_step || index >= self.steps.len() {
+            return false;
+        }
+        self.steps_labels.remove(index);
+        self.steps.remove(index);
+        true
+    }
+}
+
+/// System that takes care of executing any existing [`Steps`] Actions.
+pub fn steps_system(
+    mut cmd: Commands,
+    mut steps_q: Query<(Entity, &Actor, &mut Steps, &ActionSpan)>,
+    mut states: Query<&mut ActionState>,
+    max_depth: Option<Res<MaxBuildDepth>>,
+) {
+    let _max_depth = MaxBuildDepth::enter(max_depth.as_deref());
+    use ActionState::*;
+    for (seq_ent, Actor(actor), mut steps_action, _span) in steps_q.iter_mut() {
+        let active_ent = steps_action.active_ent.entity();
+        let current_state = states.get_mut(seq_ent).unwrap().clone();
+        #[cfg(feature = "trace")]
+        let _guard = _span.span().enter();
+        match current_state {
+            Requested => {
+                if let Some(index) = steps_action.jump_to.take() {
+                    // The active step hasn't started yet, so it can just be
+                    // swapped out. The new one gets requested next tick, once
+                    // it actually exists.
+                    #[cfg(feature = "trace")]
+                    trace!("Starting StepsAction at step {} instead.", index);
+                    steps_action.replace_active_step(&mut cmd, seq_ent, *actor, index);
+                    continue;
+                }
+                // Begin at the beginning
+                #[cfg(feature = "trace")]
+                trace!(
+                    "Initializing StepsAction and requesting first step: {:?}",
+                    active_ent
+                );
+                *states.get_mut(active_ent).unwrap() = Requested;
+                *states.get_mut(seq_ent).unwrap() = Executing;
+            }
+            Executing if steps_action.jump_to.is_some() => {
+                let mut step_state = states.get_mut(active_ent).unwrap();
+                match *step_state {
+                    Requested | Executing | Yielded => {
+                        #[cfg(feature = "trace")]
+                        trace!("Cancelling step {:?} to jump to another one.", active_ent);
+                        *step_state = Cancelled;
+                    }
+                    Cancelled | Interrupted => {
+                        // Wait for the step to wrap itself up.
+                    }
+                    Init | Success | Failure => {
+                        let index = steps_action.jump_to.take().expect("we just checked");
+                        #[cfg(feature = "trace")]
+                        trace!("Jumping to step {}.", index);
+                        steps_action.replace_active_step(&mut cmd, seq_ent, *actor, index);
+                    }
+                }
+            }
+            Executing => {
+                let mut step_state = states.get_mut(active_ent).unwrap();
+                match *step_state {
+                    Init => {
+                        // Request it! This... should not really happen? But just in case I'm missing something... :)
+                        *step_state = Requested;
+                    }
+                    Executing | Requested | Yielded => {
+                        // do nothing. Everything's running as it should.
+                    }
+                    Cancelled | Interrupted => {
+                        // Wait for the step to wrap itself up, and we'll decide what to do at that point.
+                    }
+                    Failure => {
+                        // Fail ourselves
+                        #[cfg(feature = "trace")]
+                        trace!("Step {:?} failed. Failing entire StepsAction.", active_ent);
+                        let step_state = step_state.clone();
+                        let mut seq_state = states.get_mut(seq_ent).expect("idk");
+                        *seq_state = step_state;
+                        despawn_completed(&mut cmd, steps_action.active_ent.entity());
+                    }
+                    Success if steps_action.active_step == steps_action.steps.len() - 1 => {
+                        // We're done! Let's just be successful
+                        #[cfg(feature = "trace")]
+                        trace!("StepsAction completed all steps successfully.");
+                        let step_state = step_state.clone();
+                        let mut seq_state = states.get_mut(seq_ent).expect("idk");
+                        *seq_state = step_state;
+                        despawn_completed(&mut cmd, steps_action.active_ent.entity());
+                    }
+                    Success => {
+                        #[cfg(feature = "trace")]
+                        trace!("Step succeeded, but there's more steps. Spawning next action.");
+                        // Deactivate current step and go to the next step
+                        let next = steps_action.active_step + 1;
+                        let _step_ent =
+                            steps_action.replace_active_step(&mut cmd, seq_ent, *actor, next);
+                        #[cfg(feature = "trace")]
+                        trace!("Spawned next step: {:?}", _step_ent);
+                    }
+                }
+            }
+            Cancelled | Interrupted if steps_action.skipping.as_ref() == Some(&current_state) => {
+                // The cancelled step is done, so the rest of them run as
+                // usual until the last one finishes. If we've been escalated
+                // from Interrupted to Cancelled since, we fall through to the
+                // arm below instead, which cancels whichever step is running.
+                let mut step_state = states.get_mut(active_ent).expect("oops");
+                match *step_state {
+                    Init => {
+                        *step_state = Requested;
+                    }
+                    Requested | Executing | Yielded | Cancelled | Interrupted => {}
+                    Success if steps_action.active_step < steps_action.steps.len() - 1 => {
+                        #[cfg(feature = "trace")]
+                        trace!("Step succeeded, running the next one despite the cancellation.");
+                        let next = steps_action.active_step + 1;
+                        steps_action.replace_active_step(&mut cmd, seq_ent, *actor, next);
+                    }
+                    Success | Failure => {
+                        *states.get_mut(seq_ent).unwrap() = step_state.clone();
+                    }
+                }
+            }
+            Cancelled | Interrupted => {
+                // Cancel (or interrupt) current action
+                #[cfg(feature = "trace")]
+                trace!("StepsAction has been {:?}. Passing it on to current step {:?} before finalizing.", current_state, active_ent);
+                let mut step_state = states.get_mut(active_ent).expect("oops");
+                match *step_state {
+                    Requested | Executing | Yielded | Init => {
+                        *step_state = current_state;
+                    }
+                    Interrupted if current_state == Cancelled => {
+                        *step_state = Cancelled;
+                    }
+                    Failure | Success
+                        if steps_action.cancel_policy == StepsCancelPolicy::SkipStep
+                            && steps_action.active_step < steps_action.steps.len() - 1 =>
+                    {
+                        #[cfg(feature = "trace")]
+                        trace!(
+                            "Cancelled step {:?} wrapped up. Skipping to the next one.",
+                            active_ent
+                        );
+                        steps_action.skipping = Some(current_state);
+                        let next = steps_action.active_step + 1;
+                        steps_action.replace_active_step(&mut cmd, seq_ent, *actor, next);
+                    }
+                    Failure | Success => {
+                        *states.get_mut(seq_ent).unwrap() = step_state.clone();
+                    }
+                    Cancelled | Interrupted => {}
+                }
+            }
+            Init | Yielded | Success | Failure => {
+                // Do nothing.
+            }
+        }
+    }
+}
+
+/// [`ActionBuilder`] for the [`Ticks`] component. Constructed through
+/// `Ticks::build()`.
+#[derive(Debug, Clone, Reflect)]
+pub struct TicksBuilder {
+    ticks: u32,
+    label: Option<String>,
+}
+
+impl TicksBuilder {
+    /// Sets the logging label for the Action
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+impl ActionBuilder for TicksBuilder {
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref().or(Some("Ticks"))
+    }
+
+    fn build(&self, cmd: &mut Commands, action: Entity, _actor: Entity) {
+        cmd.entity(action).insert(Ticks {
+            remaining: self.ticks,
+        });
+    }
+}
+
+/// Action that stays [`ActionState::Executing`] for a fixed number of
+/// [`BigBrainSet::Actions`](crate::BigBrainSet::Actions) passes, and then
+/// succeeds. Unlike timers based on [`Duration`], this is entirely
+/// independent of wall-clock time, which makes it handy for deterministic
+/// tests and frame-locked simulations. `Ticks::build(0)` succeeds as soon as
+/// it's requested. Fails if cancelled or interrupted.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct MyScorer;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct Attack;
+/// # fn main() {
+/// Thinker::build()
+///     .when(
+///         MyScorer,
+///         Steps::build()
+///             .step(Ticks::build(30))
+///             .step(Attack)
+///         )
+/// # ;
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct Ticks {
+    remaining: u32,
+}
+
+impl Ticks {
+    /// Construct a new [`TicksBuilder`] that will succeed after `ticks`
+    /// ticks.
+    pub fn build(ticks: u32) -> TicksBuilder {
+        TicksBuilder { ticks, label: None }
+    }
+
+    /// How many more ticks are left before this Action succeeds.
+    pub fn remaining(&self) -> u32 {
+        self.remaining
+    }
+}
+
+/// System that takes care of executing any existing [`Ticks`] Actions.
+pub fn ticks_system(mut query: Query<(&mut Ticks, &mut ActionState, &ActionSpan)>) {
+    use ActionState::*;
+    for (mut ticks, mut state, _span) in query.iter_mut() {
+        #[cfg(feature = "trace")]
+        let _guard = _span.span().enter();
+        match *state {
+            Requested if ticks.remaining == 0 => {
+                *state = Success;
+            }
+            Requested => {
+                *state = Executing;
+            }
+            Executing => {
+                ticks.remaining = ticks.remaining.saturating_sub(1);
+                #[cfg(feature = "trace")]
+                trace!("Ticks remaining: {}", ticks.remaining);
+                if ticks.remaining == 0 {
+                    *state = Success;
+                }
+            }
+            Cancelled | Interrupted => {
+                *state = Failure;
+            }
+            Init | Yielded | Success | Failure => {}
+        }
+    }
+}
+
+/// [`ActionBuilder`] for the [`WaitUntil`] component. Constructed through
+/// `WaitUntil::build()`.
+#[derive(Debug, Reflect)]
+#[reflect(ActionBuilder, from_reflect = false)]
+pub struct WaitUntilBuilder {
+    label: Option<String>,
+    threshold: f32,
+    #[reflect(ignore)]
+    scorer: Arc<dyn ScorerBuilder>,
+}
+
+impl WaitUntilBuilder {
+    /// Sets the logging label for the Action
+    pub fn label(mut self, label: impl AsRef<str>) -> Self {
+        self.label = Some(label.as_ref().into());
+        self
+    }
+}
+
+impl ActionBuilder for WaitUntilBuilder {
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref().or(Some("WaitUntil"))
+    }
+
+    fn build(&self, cmd: &mut Commands, action: Entity, actor: Entity) {
+        let scorer = scorers::spawn_scorer(&*self.scorer, cmd, actor);
+        cmd.entity(action)
+            .insert(Name::new("WaitUntil Action"))
+            .insert(WaitUntil {
+                scorer: Scorer(scorer),
+                threshold: self.threshold,
+            })
+            .add_children(&[scorer]);
+    }
+}
+
+/// Action that stays [`ActionState::Executing`] until its Scorer's [`Score`]
+/// reaches a threshold, and then succeeds. This is the Score-based sibling of
+/// [`Ticks`], for when a sequence should hold off until something's true,
+/// like standing still until it's safe to move on. Fails if cancelled or
+/// interrupted.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct MyScorer;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct Safety;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct Cross;
+/// # fn main() {
+/// Thinker::build()
+///     .when(
+///         MyScorer,
+///         Steps::build()
+///             .step(WaitUntil::build(Safety, 0.8))
+///             .step(Cross)
+///         )
+/// # ;
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct WaitUntil {
+    scorer: Scorer,
+    threshold: f32,
+}
+
+impl WaitUntil {
+    /// Construct a new [`WaitUntilBuilder`] that will succeed once `scorer`
+    /// scores at least `threshold`.
+    pub fn build(scorer: impl ScorerBuilder + 'static, threshold: f32) -> WaitUntilBuilder {
+        WaitUntilBuilder {
+            label: None,
+            threshold,
+            scorer: Arc::new(scorer),
+        }
+    }
+
+    /// The Scorer entity this Action is waiting on.
+    pub fn scorer(&self) -> Entity {
+        self.scorer.0
+    }
+}
+
+/// System that takes care of executing any existing [`WaitUntil`] Actions.
+pub fn wait_until_system(
+    mut query: Query<(&WaitUntil, &mut ActionState, &ActionSpan)>,
+    scores: Query<&Score>,
+) {
+    use ActionState::*;
+    for (wait, mut state, _span) in query.iter_mut() {
+        #[cfg(feature = "trace")]
+        let _guard = _span.span().enter();
+        match *state {
+            Requested => {
+                *state = Executing;
+            }
+            Executing => {
+                let score = scores.get(wait.scorer.0).map_or(0.0, Score::get);
+                #[cfg(feature = "trace")]
+                trace!("Waiting for score {} to reach {}", score, wait.threshold);
+                if score >= wait.threshold {
+                    *state = Success;
+                }
+            }
+            Cancelled | Interrupted => {
+                *state = Failure;
+            }
+            Init | Yielded | Success | Failure => {}
+        }
+    }
+}
+
+/// [`ActionBuilder`] for the [`Retry`] component. Constructed through
+/// `Retry::build()`.
+#[derive(Debug, Reflect)]
+#[reflect(ActionBuilder)]
+pub struct RetryBuilder {
+    label: Option<String>,
+    max_attempts: u32,
+    backoff: Duration,
+    action_label: Option<String>,
+    #[reflect(ignore)]
+    action: Option<Arc<dyn ActionBuilder>>,
+}
+
+impl RetryBuilder {
+    /// Sets the logging label for the Action
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the Action to retry.
+    pub fn action(mut self, action_builder: impl ActionBuilder + 'static) -> Self {
+        self.action_label = Some(
+            action_builder
+                .label()
+                .unwrap_or("Unlabeled Action")
+                .to_string(),
+        );
+        self.action = Some(Arc::new(action_builder));
+        self
+    }
+
+    /// Sets the Action to retry. This is the same as [`RetryBuilder::action`],
+    /// but reads better at the end of a chain, the way behavior tree
+    /// decorators are usually written:
+    /// `Retry::build(3).backoff(Duration::from_secs(1)).wrap(PathToTarget)`.
+    pub fn wrap(self, action_builder: impl ActionBuilder + 'static) -> Self {
+        self.action(action_builder)
+    }
+
+    /// Waits this long after a failed attempt before starting the next one.
+    /// Defaults to no wait at all. Like [`ActionAge`], this follows Bevy's
+    /// [`Time`], so pausing the game pauses the wait.
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+}
+
+impl ActionBuilder for RetryBuilder {
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref().or(Some("Retry"))
+    }
+
+    fn build(&self, cmd: &mut Commands, action: Entity, actor: Entity) {
+        let child_action = self
+            .action
+            .as_ref()
+            .map(|child| spawn_shared_action(child, cmd, actor));
+        cmd.entity(action)
+            .insert(Name::new("Retry Action"))
+            .insert(Retry {
+                action: self.action.clone(),
+                action_label: self.action_label.clone(),
+                max_attempts: self.max_attempts,
+                attempts: u32::from(child_action.is_some()),
+                backoff: self.backoff,
+                retry_at: None,
+                active_ent: child_action.map(Action),
+            });
+        if let Some(child_action) = child_action {
+            cmd.entity(action).add_children(&[child_action]);
+        }
+    }
+}
+
+/// Composite Action that re-runs its child Action whenever it fails, up to
+/// `max_attempts` times in total. Succeeds as soon as one attempt succeeds,
+/// and only fails once every attempt has failed. This is the opposite of
+/// repeating an Action until it stops succeeding: it's for flaky things,
+/// like pathing to a target that keeps moving. Without an Action to retry,
+/// `Retry` fails right away.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # use bevy::utils::Duration;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct MyScorer;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct PathToTarget;
+/// # fn main() {
+/// Thinker::build()
+///     .when(
+///         MyScorer,
+///         Retry::build(3)
+///             .action(PathToTarget)
+///             .backoff(Duration::from_millis(500))
+///         )
+/// # ;
+/// # }
+/// ```
+#[derive(Component, Debug, Reflect)]
+#[reflect(from_reflect = false)]
+pub struct Retry {
+    #[reflect(ignore)]
+    action: Option<Arc<dyn ActionBuilder>>,
+    action_label: Option<String>,
+    max_attempts: u32,
+    attempts: u32,
+    backoff: Duration,
+    retry_at: Option<Duration>,
+    active_ent: Option<Action>,
+}
+
+impl Retry {
+    /// Construct a new [`RetryBuilder`] that will try its Action at most
+    /// `max_attempts` times.
+    pub fn build(max_attempts: u32) -> RetryBuilder {
+        RetryBuilder {
+            label: None,
+            max_attempts,
+            backoff: Duration::ZERO,
+            action_label: None,
+            action: None,
+        }
+    }
+
+    /// How many attempts have been started so far, including the current
+    /// one.
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    fn start_next_attempt(&mut self, cmd: &mut Commands, retry_ent: Entity, actor: Entity) {
+        let Some(action) = &self.action else {
+            return;
+        };
+        self.attempts += 1;
+        let child_ent = spawn_shared_action(action, cmd, actor);
+        cmd.entity(retry_ent).add_children(&[child_ent]);
+        self.active_ent = Some(Action(child_ent));
+    }
+}
+
+/// System that takes care of executing any existing [`Retry`] Actions.
+pub fn retry_system(
+    mut cmd: Commands,
+    time: Res<Time>,
+    mut retry_q: Query<(Entity, &Actor, &mut Retry, &ActionSpan)>,
+    mut states: Query<&mut ActionState>,
+    max_depth: Option<Res<MaxBuildDepth>>,
+) {
+    let _max_depth = MaxBuildDepth::enter(max_depth.as_deref());
+    use ActionState::*;
+    for (retry_ent, Actor(actor), mut retry, _span) in retry_q.iter_mut() {
+        let current_state = states.get_mut(retry_ent).unwrap().clone();
+        #[cfg(feature = "trace")]
+        let _guard = _span.span().enter();
+        match current_state {
+            Requested => {
+                if retry.action.is_none() {
+                    *states.get_mut(retry_ent).unwrap() = Failure;
+                    continue;
+                }
+                if let Some(active_ent) = &retry.active_ent {
+                    *states.get_mut(active_ent.entity()).unwrap() = Requested;
+                }
+                *states.get_mut(retry_ent).unwrap() = Executing;
+            }
+            Executing => {
+                let Some(active_ent) = retry.active_ent.as_ref().map(|a| a.entity()) else {
+                    // Waiting out the backoff.
+                    if retry.retry_at.is_some_and(|at| time.elapsed() >= at) {
+                        #[cfg(feature = "trace")]
+                        trace!("Backoff elapsed. Starting attempt {}.", retry.attempts + 1);
+                        retry.retry_at = None;
+                        retry.start_next_attempt(&mut cmd, retry_ent, *actor);
+                    }
+                    continue;
+                };
+                let mut child_state = states.get_mut(active_ent).unwrap();
+                match *child_state {
+                    Init => {
+                        *child_state = Requested;
+                    }
+                    Requested | Executing | Yielded | Cancelled | Interrupted => {}
+                    Success => {
+                        #[cfg(feature = "trace")]
+                        trace!("Attempt {} succeeded.", retry.attempts);
+                        *states.get_mut(retry_ent).unwrap() = Success;
+                        despawn_completed(&mut cmd, active_ent);
+                        retry.active_ent = None;
+                    }
+                    Failure => {
+                        despawn_completed(&mut cmd, active_ent);
+                        retry.active_ent = None;
+                        if retry.attempts >= retry.max_attempts {
+                            #[cfg(feature = "trace")]
+                            trace!("Attempt {} failed. Out of attempts.", retry.attempts);
+                            *states.get_mut(retry_ent).unwrap() = Failure;
+                        } else if retry.backoff.is_zero() {
+                            #[cfg(feature = "trace")]
+                            trace!("Attempt {} failed. Retrying.", retry.attempts);
+                            retry.start_next_attempt(&mut cmd, retry_ent, *actor);
+                        } else {
+                            #[cfg(feature = "trace")]
+                            trace!(
+                                "Attempt {} failed. Retrying after {:?}.",
+                                retry.attempts,
+                                retry.backoff
+                            );
+                            retry.retry_at = Some(time.elapsed() + retry.backoff);
+                        }
+                    }
+                }
+            }
+            Cancelled | Interrupted => {
+                let Some(active_ent) = retry.active_ent.as_ref().map(|a| a.entity()) else {
+                    // Nothing's running, so there's nothing to wrap up.
+                    *states.get_mut(retry_ent).unwrap() = Failure;
+                    continue;
+                };
+                let mut child_state = states.get_mut(active_ent).expect("oops");
+                match *child_state {
+                    Requested | Executing | Yielded | Init => {
+                        *child_state = current_state;
+                    }
+                    Interrupted if current_state == Cancelled => {
+                        *child_state = Cancelled;
+                    }
+                    Failure | Success => {
+                        *states.get_mut(retry_ent).unwrap() = child_state.clone();
+                    }
+                    Cancelled | Interrupted => {}
+                }
+            }
+            Init | Yielded | Success | Failure => {}
+        }
+    }
+}
+
+/// Source of randomness for Composite Actions like [`RandomAction`].
+/// [`BigBrainPlugin`](crate::BigBrainPlugin) adds one with a random seed.
+/// Insert your own with [`ActionRng::seeded`] if you need the same choices
+/// every run, like for tests or replays.
+#[derive(Resource, Debug, Clone)]
+pub struct ActionRng {
+    state: u64,
+}
+
+impl ActionRng {
+    /// An `ActionRng` that always makes the same choices for the same `seed`.
+    pub fn seeded(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// A random number in `0.0..1.0`.
+    pub fn next_f32(&mut self) -> f32 {
+        // SplitMix64. Not fit for anything important, but plenty for picking
+        // attack animations.
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        (z >> 40) as f32 / (1u64 << 24) as f32
+    }
 }
 
-/// Spawns a new Action Component, using the given ActionBuilder. This is
-/// useful when you're doing things like writing composite Actions.
-pub fn spawn_action<T: ActionBuilder + ?Sized>(
-    builder: &T,
-    cmd: &mut Commands,
-    actor: Entity,
-) -> Entity {
-    let action_ent = Action(cmd.spawn_empty().id());
-    let span = ActionSpan::new(action_ent.entity(), ActionBuilder::label(builder));
-    let _guard = span.span().enter();
-    debug!("New Action spawned.");
-    cmd.entity(action_ent.entity())
-        .insert(Name::new("Action"))
-        .insert(ActionState::new())
-        .insert(Actor(actor));
-    builder.build(cmd, action_ent.entity(), actor);
-    std::mem::drop(_guard);
-    cmd.entity(action_ent.entity()).insert(span);
-    action_ent.entity()
+impl Default for ActionRng {
+    fn default() -> Self {
+        use std::hash::{BuildHasher, Hasher};
+        let seed = std::collections::hash_map::RandomState::new()
+            .build_hasher()
+            .finish();
+        Self::seeded(seed)
+    }
 }
 
-/// [`ActionBuilder`] for the [`Steps`] component. Constructed through
-/// `Steps::build()`.
+/// [`ActionBuilder`] for the [`RandomAction`] component. Constructed through
+/// `RandomAction::build()`.
 #[derive(Debug, Reflect)]
 #[reflect(ActionBuilder)]
-pub struct StepsBuilder {
+pub struct RandomActionBuilder {
     label: Option<String>,
-    steps_labels: Vec<String>,
     #[reflect(ignore)]
-    steps: Vec<Arc<dyn ActionBuilder>>,
+    actions: Vec<Arc<dyn ActionBuilder>>,
+    action_labels: Vec<String>,
+    weights: Vec<f32>,
 }
 
-impl StepsBuilder {
+impl RandomActionBuilder {
     /// Sets the logging label for the Action
     pub fn label<S: Into<String>>(mut self, label: S) -> Self {
         self.label = Some(label.into());
         self
     }
 
-    /// Adds an action step. Order matters.
-    pub fn step(mut self, action_builder: impl ActionBuilder + 'static) -> Self {
+    /// Add an action to pick from, with a weight of `1.0`.
+    pub fn push(self, action_builder: impl ActionBuilder + 'static) -> Self {
+        self.push_with_weight(action_builder, 1.0)
+    }
+
+    /// Add an action to pick from, with a weight. An action is picked with a
+    /// chance of its weight divided by the sum of all weights, so an action
+    /// with a weight of `2.0` comes up twice as often as one with `1.0`.
+    /// Actions with a weight of `0.0` or less are never picked.
+    pub fn push_with_weight(
+        mut self,
+        action_builder: impl ActionBuilder + 'static,
+        weight: f32,
+    ) -> Self {
         if let Some(label) = action_builder.label() {
-            self.steps_labels.push(label.into());
+            self.action_labels.push(label.into());
         } else {
-            self.steps_labels.push("Unlabeled Action".into());
+            self.action_labels.push("Unnamed Action".into());
         }
-        self.steps.push(Arc::new(action_builder));
+        self.actions.push(Arc::new(action_builder));
+        self.weights.push(weight);
         self
     }
 }
 
-impl ActionBuilder for StepsBuilder {
+impl ActionBuilder for RandomActionBuilder {
     fn label(&self) -> Option<&str> {
-        self.label.as_deref()
+        self.label.as_deref().or(Some("RandomAction"))
     }
 
-    fn build(&self, cmd: &mut Commands, action: Entity, actor: Entity) {
-        if let Some(step) = self.steps.first() {
-            let child_action = spawn_action(step.as_ref(), cmd, actor);
-            cmd.entity(action)
-                .insert(Name::new("Steps Action"))
-                .insert(Steps {
-                    active_step: 0,
-                    active_ent: Action(child_action),
-                    steps: self.steps.clone(),
-                    steps_labels: self.steps_labels.clone(),
-                })
-                .add_children(&[child_action]);
-        }
+    fn build(&self, cmd: &mut Commands, action: Entity, _actor: Entity) {
+        cmd.entity(action)
+            .insert(Name::new("Random Action"))
+            .insert(RandomAction {
+                actions: self.actions.clone(),
+                action_labels: self.action_labels.clone(),
+                weights: self.weights.clone(),
+                active_ent: None,
+                active_idx: 0,
+            });
     }
 }
 
-/// Composite Action that executes a series of steps in sequential order, as
-/// long as each step results in a `Success`ful [`ActionState`].
+/// Composite Action that runs one of its children, picked at random every
+/// time it's [`ActionState::Requested`], and ends up however that child
+/// does. Children can be weighted with
+/// [`RandomActionBuilder::push_with_weight`]. Picks come from the
+/// [`ActionRng`] resource.
+///
+/// If none of the children can be picked, because there aren't any or
+/// they're all weighted `0.0`, it fails right away.
 ///
 /// ### Example
 ///
@@ -208,132 +1944,304 @@ impl ActionBuilder for StepsBuilder {
 /// # use bevy::prelude::*;
 /// # use big_brain::prelude::*;
 /// # #[derive(Debug, Clone, Component, ScorerBuilder)]
-/// # struct MyScorer;
+/// # struct InRange;
 /// # #[derive(Debug, Clone, Component, ActionBuilder)]
-/// # struct MyAction;
+/// # struct Slash;
 /// # #[derive(Debug, Clone, Component, ActionBuilder)]
-/// # struct MyNextAction;
+/// # struct Stab;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct Spin;
 /// # fn main() {
 /// Thinker::build()
 ///     .when(
-///         MyScorer,
-///         Steps::build()
-///             .step(MyAction)
-///             .step(MyNextAction)
-///         )
+///         InRange,
+///         RandomAction::build()
+///             .push(Slash)
+///             .push(Stab)
+///             .push_with_weight(Spin, 0.25),
+///     )
 /// # ;
 /// # }
 /// ```
 #[derive(Component, Debug, Reflect)]
-pub struct Steps {
+#[reflect(from_reflect = false)]
+pub struct RandomAction {
     #[reflect(ignore)]
-    steps: Vec<Arc<dyn ActionBuilder>>,
-    steps_labels: Vec<String>,
-    active_step: usize,
-    active_ent: Action,
+    actions: Vec<Arc<dyn ActionBuilder>>,
+    action_labels: Vec<String>,
+    weights: Vec<f32>,
+    active_ent: Option<Action>,
+    active_idx: usize,
 }
 
-impl Steps {
-    /// Construct a new [`StepsBuilder`] to define the steps to take.
-    pub fn build() -> StepsBuilder {
-        StepsBuilder {
-            steps: Vec::new(),
-            steps_labels: Vec::new(),
+impl RandomAction {
+    /// Construct a new [`RandomActionBuilder`] to define the actions to pick
+    /// from.
+    pub fn build() -> RandomActionBuilder {
+        RandomActionBuilder {
             label: None,
+            actions: Vec::new(),
+            action_labels: Vec::new(),
+            weights: Vec::new(),
+        }
+    }
+
+    /// The child Action entity that's currently running, if any.
+    pub fn active(&self) -> Option<Entity> {
+        self.active_ent.as_ref().map(|a| a.entity())
+    }
+
+    /// The label of the child Action that's currently running, if any.
+    pub fn active_label(&self) -> Option<&str> {
+        self.active_ent.as_ref()?;
+        Some(self.action_labels[self.active_idx].as_str())
+    }
+
+    /// Picks the index of a child, weighted by `weights`.
+    fn pick(&self, rng: &mut ActionRng) -> Option<usize> {
+        let total: f32 = self.weights.iter().filter(|w| **w > 0.0).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut roll = rng.next_f32() * total;
+        let mut last = None;
+        for (idx, weight) in self.weights.iter().enumerate() {
+            if *weight <= 0.0 {
+                continue;
+            }
+            if roll < *weight {
+                return Some(idx);
+            }
+            roll -= weight;
+            last = Some(idx);
         }
+        // Only reachable through rounding errors.
+        last
     }
 }
 
-/// System that takes care of executing any existing [`Steps`] Actions.
-pub fn steps_system(
+/// System that takes care of executing any existing [`RandomAction`]s.
+pub fn random_action_system(
     mut cmd: Commands,
-    mut steps_q: Query<(Entity, &Actor, &mut Steps, &ActionSpan)>,
+    mut rng: ResMut<ActionRng>,
+    mut random_q: Query<(Entity, &Actor, &mut RandomAction, &ActionSpan)>,
     mut states: Query<&mut ActionState>,
+    max_depth: Option<Res<MaxBuildDepth>>,
 ) {
+    let _max_depth = MaxBuildDepth::enter(max_depth.as_deref());
     use ActionState::*;
-    for (seq_ent, Actor(actor), mut steps_action, _span) in steps_q.iter_mut() {
-        let active_ent = steps_action.active_ent.entity();
-        let current_state = states.get_mut(seq_ent).unwrap().clone();
+    for (random_ent, Actor(actor), mut random, _span) in random_q.iter_mut() {
+        let current_state = states.get_mut(random_ent).unwrap().clone();
         #[cfg(feature = "trace")]
         let _guard = _span.span().enter();
         match current_state {
             Requested => {
-                // Begin at the beginning
+                if let Some(Action(stale)) = random.active_ent.take() {
+                    despawn_completed(&mut cmd, stale);
+                }
+                let Some(idx) = random.pick(&mut rng) else {
+                    #[cfg(feature = "trace")]
+                    trace!("Nothing to pick from. Failing.");
+                    *states.get_mut(random_ent).unwrap() = Failure;
+                    continue;
+                };
                 #[cfg(feature = "trace")]
-                trace!(
-                    "Initializing StepsAction and requesting first step: {:?}",
-                    active_ent
-                );
-                *states.get_mut(active_ent).unwrap() = Requested;
-                *states.get_mut(seq_ent).unwrap() = Executing;
+                trace!("Picked {}.", random.action_labels[idx]);
+                let child_ent = spawn_shared_action(&random.actions[idx], &mut cmd, *actor);
+                cmd.entity(random_ent).add_children(&[child_ent]);
+                random.active_ent = Some(Action(child_ent));
+                random.active_idx = idx;
+                *states.get_mut(random_ent).unwrap() = Executing;
             }
             Executing => {
-                let mut step_state = states.get_mut(active_ent).unwrap();
-                match *step_state {
+                let Some(active_ent) = random.active() else {
+                    continue;
+                };
+                // Freshly-spawned children only show up once commands are
+                // applied.
+                let Ok(mut child_state) = states.get_mut(active_ent) else {
+                    continue;
+                };
+                match *child_state {
                     Init => {
-                        // Request it! This... should not really happen? But just in case I'm missing something... :)
-                        *step_state = Requested;
-                    }
-                    Executing | Requested => {
-                        // do nothing. Everything's running as it should.
+                        *child_state = Requested;
                     }
-                    Cancelled => {
-                        // Wait for the step to wrap itself up, and we'll decide what to do at that point.
+                    Requested | Executing | Yielded | Cancelled | Interrupted => {}
+                    Success | Failure => {
+                        let child_state = child_state.clone();
+                        *states.get_mut(random_ent).unwrap() = child_state;
+                        despawn_completed(&mut cmd, active_ent);
+                        random.active_ent = None;
                     }
-                    Failure => {
-                        // Fail ourselves
-                        #[cfg(feature = "trace")]
-                        trace!("Step {:?} failed. Failing entire StepsAction.", active_ent);
-                        let step_state = step_state.clone();
-                        let mut seq_state = states.get_mut(seq_ent).expect("idk");
-                        *seq_state = step_state;
-                        if let Some(ent) = cmd.get_entity(steps_action.active_ent.entity()) {
-                            ent.despawn_recursive();
-                        }
+                }
+            }
+            Cancelled | Interrupted => {
+                let Some(active_ent) = random.active() else {
+                    // Nothing's running, so there's nothing to wrap up.
+                    *states.get_mut(random_ent).unwrap() = Failure;
+                    continue;
+                };
+                let Ok(mut child_state) = states.get_mut(active_ent) else {
+                    *states.get_mut(random_ent).unwrap() = Failure;
+                    continue;
+                };
+                match *child_state {
+                    Requested | Executing | Yielded | Init => {
+                        *child_state = current_state;
                     }
-                    Success if steps_action.active_step == steps_action.steps.len() - 1 => {
-                        // We're done! Let's just be successful
-                        #[cfg(feature = "trace")]
-                        trace!("StepsAction completed all steps successfully.");
-                        let step_state = step_state.clone();
-                        let mut seq_state = states.get_mut(seq_ent).expect("idk");
-                        *seq_state = step_state;
-                        if let Some(ent) = cmd.get_entity(steps_action.active_ent.entity()) {
-                            ent.despawn_recursive();
-                        }
+                    Interrupted if current_state == Cancelled => {
+                        *child_state = Cancelled;
                     }
-                    Success => {
-                        #[cfg(feature = "trace")]
-                        trace!("Step succeeded, but there's more steps. Spawning next action.");
-                        // Deactivate current step and go to the next step
-                        if let Some(ent) = cmd.get_entity(steps_action.active_ent.entity()) {
-                            ent.despawn_recursive();
-                        }
-
-                        steps_action.active_step += 1;
-                        let step_builder = steps_action.steps[steps_action.active_step].clone();
-                        let step_ent = spawn_action(step_builder.as_ref(), &mut cmd, *actor);
-                        #[cfg(feature = "trace")]
-                        trace!("Spawned next step: {:?}", step_ent);
-                        cmd.entity(seq_ent).add_children(&[step_ent]);
-                        steps_action.active_ent = Action(step_ent);
+                    Failure | Success => {
+                        *states.get_mut(random_ent).unwrap() = child_state.clone();
                     }
+                    Cancelled | Interrupted => {}
                 }
             }
-            Cancelled => {
-                // Cancel current action
+            Init | Yielded | Success | Failure => {}
+        }
+    }
+}
+
+/// [`ActionBuilder`] for the [`Detach`] component. Constructed through
+/// `Detach::build()`.
+#[derive(Debug, Reflect)]
+#[reflect(ActionBuilder)]
+pub struct DetachBuilder {
+    label: Option<String>,
+    #[reflect(ignore)]
+    action: Option<Arc<dyn ActionBuilder>>,
+}
+
+impl DetachBuilder {
+    /// Sets the logging label for the Action
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the Action to run detached.
+    pub fn wrap(mut self, action_builder: impl ActionBuilder + 'static) -> Self {
+        self.action = Some(Arc::new(action_builder));
+        self
+    }
+}
+
+impl ActionBuilder for DetachBuilder {
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref().or(Some("Detach"))
+    }
+
+    fn build(&self, cmd: &mut Commands, action: Entity, _actor: Entity) {
+        cmd.entity(action)
+            .insert(Name::new("Detach Action"))
+            .insert(Detach {
+                action: self.action.clone(),
+                detached: None,
+            });
+    }
+}
+
+/// Composite Action for fire-and-forget effects that should outlive the
+/// decision that started them, like a particle emitter or a sound. When
+/// requested, it spawns its wrapped Action on its own, marked with
+/// [`Detached`], and succeeds right away so the Thinker can move on.
+///
+/// The detached Action isn't a child of anything, so it keeps running after
+/// the `Detach` is despawned, until it succeeds or fails on its own. It's
+/// cleaned up then, or along with everything else if the actor goes away.
+/// Nothing ever cancels it, so it has to end by itself. Without a wrapped
+/// Action, `Detach` fails right away.
+///
+/// Since it succeeds right away, a choice with a `Detach` is picked again the
+/// very next time the Thinker thinks, unless its Score drops. Use
+/// [`when_once`](crate::thinker::ThinkerBuilder::when_once) or a Scorer that
+/// reacts to the effect to avoid spawning a new one every frame.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct Celebrating;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct Fireworks;
+/// # fn main() {
+/// Thinker::build()
+///     .when_once(Celebrating, Detach::build().wrap(Fireworks))
+/// # ;
+/// # }
+/// ```
+#[derive(Component, Debug, Reflect)]
+#[reflect(from_reflect = false)]
+pub struct Detach {
+    #[reflect(ignore)]
+    action: Option<Arc<dyn ActionBuilder>>,
+    detached: Option<Action>,
+}
+
+impl Detach {
+    /// Construct a new [`DetachBuilder`] to define the Action to detach.
+    pub fn build() -> DetachBuilder {
+        DetachBuilder {
+            label: None,
+            action: None,
+        }
+    }
+
+    /// The last Action entity this spawned. It may have wrapped up and been
+    /// despawned since.
+    pub fn detached(&self) -> Option<Entity> {
+        self.detached.as_ref().map(|a| a.entity())
+    }
+}
+
+/// Marks an Action spawned by [`Detach`]. It runs until it succeeds or fails
+/// on its own, and is then despawned by [`detach_system`].
+#[derive(Debug, Clone, Copy, Component, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct Detached;
+
+/// System that takes care of executing any existing [`Detach`] Actions, and
+/// cleaning up the [`Detached`] Actions they spawn once they're done.
+pub fn detach_system(
+    mut cmd: Commands,
+    mut detach_q: Query<(Entity, &Actor, &mut Detach, &ActionSpan)>,
+    mut states: Query<&mut ActionState, Without<Detached>>,
+    detached_q: Query<(Entity, &ActionState), With<Detached>>,
+    max_depth: Option<Res<MaxBuildDepth>>,
+) {
+    let _max_depth = MaxBuildDepth::enter(max_depth.as_deref());
+    use ActionState::*;
+    for (detach_ent, Actor(actor), mut detach, _span) in detach_q.iter_mut() {
+        let mut state = states
+            .get_mut(detach_ent)
+            .expect("Detach without an ActionState");
+        #[cfg(feature = "trace")]
+        let _guard = _span.span().enter();
+        match *state {
+            Requested => {
+                let Some(action) = &detach.action else {
+                    *state = Failure;
+                    continue;
+                };
+                let detached = spawn_shared_action(action, &mut cmd, *actor);
+                cmd.entity(detached).insert((Detached, Requested));
                 #[cfg(feature = "trace")]
-                trace!("StepsAction has been cancelled. Cancelling current step {:?} before finalizing.", active_ent);
-                let mut step_state = states.get_mut(active_ent).expect("oops");
-                if *step_state == Requested || *step_state == Executing || *step_state == Init {
-                    *step_state = Cancelled;
-                } else if *step_state == Failure || *step_state == Success {
-                    *states.get_mut(seq_ent).unwrap() = step_state.clone();
-                }
+                trace!("Detached action {:?}.", detached);
+                detach.detached = Some(Action(detached));
+                *state = Success;
             }
-            Init | Success | Failure => {
-                // Do nothing.
+            Cancelled | Interrupted => {
+                *state = Failure;
             }
+            Init | Executing | Yielded | Success | Failure => {}
+        }
+    }
+    for (detached, state) in detached_q.iter() {
+        if matches!(state, Success | Failure) {
+            despawn_completed(&mut cmd, detached);
         }
     }
 }
@@ -352,9 +2260,12 @@ pub enum ConcurrentMode {
 #[derive(Debug, Reflect)]
 pub struct ConcurrentlyBuilder {
     mode: ConcurrentMode,
+    lazy: bool,
     #[reflect(ignore)]
     actions: Vec<Arc<dyn ActionBuilder>>,
     action_labels: Vec<String>,
+    priorities: Vec<i32>,
+    cancel_order: Vec<usize>,
     label: Option<String>,
 }
 
@@ -365,14 +2276,31 @@ impl ConcurrentlyBuilder {
         self
     }
 
-    /// Add an action to execute. Order does not matter.
-    pub fn push(mut self, action_builder: impl ActionBuilder + 'static) -> Self {
+    /// Add an action to execute, with a priority of `0`. All the actions run
+    /// at the same time, so order only matters for breaking ties in
+    /// [`ConcurrentMode::Race`] mode: see
+    /// [`ConcurrentlyBuilder::push_with_priority`].
+    pub fn push(self, action_builder: impl ActionBuilder + 'static) -> Self {
+        self.push_with_priority(action_builder, 0)
+    }
+
+    /// Add an action to execute, with a priority for [`ConcurrentMode::Race`]
+    /// mode. If several actions succeed on the same tick, the one with the
+    /// highest priority becomes the [winner](Concurrently::winner), and the
+    /// earliest-added one wins among equal priorities. Priorities don't do
+    /// anything in [`ConcurrentMode::Join`] mode.
+    pub fn push_with_priority(
+        mut self,
+        action_builder: impl ActionBuilder + 'static,
+        priority: i32,
+    ) -> Self {
         if let Some(label) = action_builder.label() {
             self.action_labels.push(label.into());
         } else {
             self.action_labels.push("Unnamed Action".into());
         }
         self.actions.push(Arc::new(action_builder));
+        self.priorities.push(priority);
         self
     }
 
@@ -381,6 +2309,61 @@ impl ConcurrentlyBuilder {
         self.mode = mode;
         self
     }
+
+    /// Don't spawn any of the actions until this action is first
+    /// [`ActionState::Requested`]. By default, they're all spawned as soon as
+    /// the [`Concurrently`] itself is, which can add up when it's buried deep
+    /// in a large tree that rarely runs. Lazy actions start a tick later,
+    /// since they need to be spawned before they can be requested.
+    pub fn lazy(mut self, lazy: bool) -> Self {
+        self.lazy = lazy;
+        self
+    }
+
+    /// Stops the actions one at a time when this action is
+    /// [`ActionState::Cancelled`] or [`ActionState::Interrupted`], in the
+    /// given order, instead of all at once. `order` holds indices into the
+    /// actions in the order they were pushed. Each one is only told to stop
+    /// once the one before it has wrapped up, so stopping "shoot" before
+    /// "aim" means listing shoot's index first. Actions left out of `order`
+    /// are stopped afterwards, in the order they were pushed, and indices
+    /// that don't match an action are ignored.
+    ///
+    /// This is best-effort: each action still finishes in its own time, and
+    /// one that never acknowledges being stopped holds up all the ones after
+    /// it. It also only applies when this action is stopped from the
+    /// outside. The other actions are still stopped all at once when a
+    /// [`ConcurrentMode::Join`] action fails or a [`ConcurrentMode::Race`]
+    /// action wins.
+    pub fn cancel_order(mut self, order: Vec<usize>) -> Self {
+        self.cancel_order = order;
+        self
+    }
+
+    /// Adds an action to execute, just like [`ConcurrentlyBuilder::push`].
+    /// If the action is itself an unlabeled [`ConcurrentlyBuilder`] with the
+    /// same [`ConcurrentMode`] and laziness, its actions are added directly
+    /// instead of being nested as a single action.
+    pub fn and<T: ActionBuilder + 'static>(mut self, action_builder: T) -> Self {
+        let mut action_builder = Some(action_builder);
+        if let Some(concurrently) =
+            (&mut action_builder as &mut dyn Any).downcast_mut::<Option<ConcurrentlyBuilder>>()
+        {
+            let concurrently = concurrently.take().expect("we just put it there");
+            if concurrently.label.is_none()
+                && concurrently.mode == self.mode
+                && concurrently.lazy == self.lazy
+                && concurrently.cancel_order.is_empty()
+            {
+                self.actions.extend(concurrently.actions);
+                self.action_labels.extend(concurrently.action_labels);
+                self.priorities.extend(concurrently.priorities);
+                return self;
+            }
+            return self.push(concurrently);
+        }
+        self.push(action_builder.expect("we just put it there"))
+    }
 }
 
 impl ActionBuilder for ConcurrentlyBuilder {
@@ -389,17 +2372,35 @@ impl ActionBuilder for ConcurrentlyBuilder {
     }
 
     fn build(&self, cmd: &mut Commands, action: Entity, actor: Entity) {
+        if self.lazy {
+            cmd.entity(action)
+                .insert(Name::new("Concurrent Action"))
+                .insert(Concurrently {
+                    actions: Vec::new(),
+                    unspawned: self.actions.clone(),
+                    action_labels: self.action_labels.clone(),
+                    priorities: self.priorities.clone(),
+                    cancel_order: self.cancel_order.clone(),
+                    winner: None,
+                    mode: self.mode,
+                });
+            return;
+        }
         let children: Vec<Entity> = self
             .actions
             .iter()
-            .map(|action| spawn_action(action.as_ref(), cmd, actor))
+            .map(|action| spawn_shared_action(action, cmd, actor))
             .collect();
         cmd.entity(action)
             .insert(Name::new("Concurrent Action"))
             .add_children(&children[..])
             .insert(Concurrently {
                 actions: children.into_iter().map(Action).collect(),
+                unspawned: Vec::new(),
                 action_labels: self.action_labels.clone(),
+                priorities: self.priorities.clone(),
+                cancel_order: self.cancel_order.clone(),
+                winner: None,
                 mode: self.mode,
             });
     }
@@ -411,6 +2412,10 @@ impl ActionBuilder for ConcurrentlyBuilder {
 /// * [`ConcurrentMode::Join`] (default) succeeds when **all** of the actions
 ///   succeed.
 /// * [`ConcurrentMode::Race`] succeeds when **any** of the actions succeed.
+///   The action that got it there is its [winner](Concurrently::winner).
+///
+/// All of the actions are spawned along with the `Concurrently` itself,
+/// unless it's built with [`ConcurrentlyBuilder::lazy`].
 ///
 /// ### Example
 ///
@@ -436,10 +2441,16 @@ impl ActionBuilder for ConcurrentlyBuilder {
 /// ```
 ///
 #[derive(Component, Debug, Reflect)]
+#[reflect(from_reflect = false)]
 pub struct Concurrently {
     mode: ConcurrentMode,
     actions: Vec<Action>,
+    #[reflect(ignore)]
+    unspawned: Vec<Arc<dyn ActionBuilder>>,
     action_labels: Vec<String>,
+    priorities: Vec<i32>,
+    cancel_order: Vec<usize>,
+    winner: Option<Action>,
 }
 
 impl Concurrently {
@@ -448,23 +2459,106 @@ impl Concurrently {
         ConcurrentlyBuilder {
             actions: Vec::new(),
             action_labels: Vec::new(),
+            priorities: Vec::new(),
+            cancel_order: Vec::new(),
             mode: ConcurrentMode::Join,
+            lazy: false,
             label: None,
         }
     }
+
+    /// The child Action entities, in the order they were added. This is
+    /// empty for a [lazy](ConcurrentlyBuilder::lazy) `Concurrently` that
+    /// hasn't been requested yet.
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// The child Action whose success made this [`ConcurrentMode::Race`]
+    /// action succeed. When several succeed on the same tick, this is the one
+    /// with the highest priority (see
+    /// [`ConcurrentlyBuilder::push_with_priority`]), and then the one added
+    /// first. Always `None` in [`ConcurrentMode::Join`] mode.
+    pub fn winner(&self) -> Option<Action> {
+        self.winner
+    }
+
+    /// Indices of the children in the order they should be stopped in. See
+    /// [`ConcurrentlyBuilder::cancel_order`].
+    fn cancel_sequence(&self) -> Vec<usize> {
+        let mut sequence = Vec::with_capacity(self.actions.len());
+        let listed = self.cancel_order.iter().copied();
+        for idx in listed.chain(0..self.actions.len()) {
+            if idx < self.actions.len() && !sequence.contains(&idx) {
+                sequence.push(idx);
+            }
+        }
+        sequence
+    }
+
+    /// Index of the child that wins among those that succeeded, if any did.
+    fn race_winner(&self, states: &Query<&mut ActionState>) -> Option<usize> {
+        self.actions
+            .iter()
+            .enumerate()
+            .filter(|(_, action)| states.get(action.entity()).ok() == Some(&ActionState::Success))
+            .min_by_key(|(idx, _)| (std::cmp::Reverse(self.priorities[*idx]), *idx))
+            .map(|(idx, _)| idx)
+    }
+
+    /// The child Actions along with their current [`ActionState`]s, for
+    /// debugging.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// fn log_concurrently(concurrents: Query<&Concurrently>, states: Query<&ActionState>) {
+    ///     for concurrently in concurrents.iter() {
+    ///         for (action, state) in concurrently.action_states(&states) {
+    ///             info!("{:?} is {:?}", action.entity(), state);
+    ///         }
+    ///     }
+    /// }
+    /// # bevy::ecs::system::assert_is_system(log_concurrently);
+    /// ```
+    pub fn action_states(&self, states: &Query<&ActionState>) -> Vec<(Action, ActionState)> {
+        self.actions
+            .iter()
+            .filter_map(|action| Some((*action, states.get(action.entity()).ok()?.clone())))
+            .collect()
+    }
 }
 
 /// System that takes care of executing any existing [`Concurrently`] Actions.
 pub fn concurrent_system(
-    concurrent_q: Query<(Entity, &Concurrently, &ActionSpan)>,
+    mut cmd: Commands,
+    mut concurrent_q: Query<(Entity, &Actor, &mut Concurrently, &ActionSpan)>,
     mut states_q: Query<&mut ActionState>,
+    max_depth: Option<Res<MaxBuildDepth>>,
 ) {
+    let _max_depth = MaxBuildDepth::enter(max_depth.as_deref());
     use ActionState::*;
-    for (seq_ent, concurrent_action, _span) in concurrent_q.iter() {
+    for (seq_ent, Actor(actor), mut concurrent_action, _span) in concurrent_q.iter_mut() {
         let current_state = states_q.get_mut(seq_ent).expect("uh oh").clone();
         #[cfg(feature = "trace")]
         let _guard = _span.span.enter();
         match current_state {
+            Requested if !concurrent_action.unspawned.is_empty() => {
+                #[cfg(feature = "trace")]
+                trace!(
+                    "Spawning {} lazy Concurrently children.",
+                    concurrent_action.unspawned.len()
+                );
+                // They'll get requested next tick, once they actually exist.
+                let children: Vec<Entity> = std::mem::take(&mut concurrent_action.unspawned)
+                    .iter()
+                    .map(|action| spawn_shared_action(action, &mut cmd, *actor))
+                    .collect();
+                cmd.entity(seq_ent).add_children(&children[..]);
+                concurrent_action.actions = children.into_iter().map(Action).collect();
+            }
             Requested => {
                 #[cfg(feature = "trace")]
                 trace!(
@@ -474,6 +2568,7 @@ pub fn concurrent_system(
                 // Begin at the beginning
                 let mut current_state = states_q.get_mut(seq_ent).expect("uh oh");
                 *current_state = Executing;
+                concurrent_action.winner = None;
                 for action in concurrent_action.actions.iter() {
                     let child_ent = action.entity();
                     let mut child_state = states_q.get_mut(child_ent).expect("uh oh");
@@ -522,32 +2617,17 @@ pub fn concurrent_system(
                     }
                 }
                 ConcurrentMode::Race => {
-                    let mut all_failure = true;
-                    let mut succeed_idx = None;
-                    for (idx, action) in concurrent_action.actions.iter().enumerate() {
-                        let child_ent = action.entity();
-                        let mut child_state = states_q.get_mut(child_ent).expect("uh oh");
-                        match *child_state {
-                            Failure => {}
-                            Success => {
-                                succeed_idx = Some(idx);
-                                all_failure = false;
-                                #[cfg(feature = "trace")]
-                                trace!("Race action has succeeded. Cancelling all other actions that haven't completed yet.");
-                            }
-                            _ => {
-                                all_failure = false;
-                                if succeed_idx.is_some() {
-                                    *child_state = Cancelled;
-                                }
-                            }
-                        }
-                    }
+                    let all_failure = concurrent_action
+                        .actions
+                        .iter()
+                        .all(|action| states_q.get(action.entity()).ok() == Some(&Failure));
                     if all_failure {
                         let mut state_var = states_q.get_mut(seq_ent).expect("uh oh");
                         *state_var = Failure;
-                    } else if let Some(idx) = succeed_idx {
-                        for action in concurrent_action.actions.iter().take(idx) {
+                    } else if let Some(idx) = concurrent_action.race_winner(&states_q) {
+                        #[cfg(feature = "trace")]
+                        trace!("Race action has succeeded. Cancelling all other actions that haven't completed yet.");
+                        for action in concurrent_action.actions.iter() {
                             let child_ent = action.entity();
                             let mut child_state = states_q.get_mut(child_ent).expect("uh oh");
                             match *child_state {
@@ -557,18 +2637,25 @@ pub fn concurrent_system(
                                 }
                             }
                         }
+                        concurrent_action.winner = Some(concurrent_action.actions[idx]);
                         let mut state_var = states_q.get_mut(seq_ent).expect("uh oh");
                         *state_var = Success;
                     }
                 }
             },
-            Cancelled => {
-                // Cancel all actions
+            Cancelled | Interrupted if !concurrent_action.unspawned.is_empty() => {
+                // Nothing's been spawned, so there's nothing to wrap up.
+                *states_q.get_mut(seq_ent).expect("uh oh") = Failure;
+            }
+            Cancelled | Interrupted => {
+                // Cancel (or interrupt) all actions, or just the next one in
+                // line if there's a cancel order.
+                let ordered = !concurrent_action.cancel_order.is_empty();
                 let mut all_done = true;
                 let mut any_failed = false;
                 let mut any_success = false;
-                for action in concurrent_action.actions.iter() {
-                    let child_ent = action.entity();
+                for idx in concurrent_action.cancel_sequence() {
+                    let child_ent = concurrent_action.actions[idx].entity();
                     let mut child_state = states_q.get_mut(child_ent).expect("uh oh");
                     match *child_state {
                         Init => {}
@@ -578,9 +2665,15 @@ pub fn concurrent_system(
                         Failure => {
                             any_failed = true;
                         }
+                        Cancelled => {
+                            all_done = false;
+                        }
+                        _ if ordered && !all_done => {
+                            // Still waiting on one earlier in the order.
+                        }
                         _ => {
                             all_done = false;
-                            *child_state = Cancelled;
+                            *child_state = current_state.clone();
                         }
                     }
                 }
@@ -592,6 +2685,9 @@ pub fn concurrent_system(
                                 #[cfg(feature = "trace")]
                                 trace!("Race action has succeeded due to succeeded children.");
                                 *state_var = Success;
+                                concurrent_action.winner = concurrent_action
+                                    .race_winner(&states_q)
+                                    .map(|idx| concurrent_action.actions[idx]);
                             } else {
                                 #[cfg(feature = "trace")]
                                 trace!("No race children has completed Successfully.");
@@ -612,7 +2708,7 @@ pub fn concurrent_system(
                     }
                 }
             }
-            Init | Success | Failure => {
+            Init | Yielded | Success | Failure => {
                 // Do nothing.
             }
         }
@@ -3,40 +3,74 @@ use std::sync::Arc;
 use bevy::prelude::*;
 
 use crate::{
-    actions::{ActionBuilder, ActionBuilderWrapper},
+    actions::{ActionBuilder, ActionBuilderWrapper, ActionState},
     scorers::{self, Score, ScorerBuilder},
     thinker::Scorer,
 };
 
 /// Contains different types of Considerations and Actions
+///
+/// Like [`ChoiceBuilder`], this can be rebuilt through [`FromReflect`], but
+/// its Action builder can't come along for the ride. See [`ChoiceBuilder`]
+/// for what you get instead.
 #[derive(Debug, Clone, Reflect)]
-#[reflect(from_reflect = false)]
 pub struct Choice {
     pub(crate) scorer: Scorer,
-    #[reflect(ignore)]
+    #[reflect(ignore, default = "unresolved_action_wrapper")]
     pub(crate) action: ActionBuilderWrapper,
     pub(crate) action_label: Option<String>,
+    pub(crate) once: bool,
+    /// Shared Scorers used anywhere in this choice's Scorer tree.
+    #[reflect(ignore)]
+    pub(crate) shared_scorers: Vec<Entity>,
+    /// Optional tag set with [`ThinkerBuilder::when_tagged`](crate::thinker::ThinkerBuilder::when_tagged).
+    /// Big Brain doesn't use this itself, but custom [`Picker`](crate::pickers::Picker)s can
+    /// read it to break ties or sort choices into categories.
+    pub tag: Option<String>,
 }
 
 impl Choice {
+    /// The current value of this choice's [`Score`]. If its Scorer entity is
+    /// gone (say, because something despawned it), this is `0.0`. Use
+    /// [`Choice::try_calculate`] to tell the two apart.
     pub fn calculate(&self, scores: &Query<&Score>) -> f32 {
-        scores
-            .get(self.scorer.0)
-            .expect("Where did the score go?")
-            .0
+        self.try_calculate(scores).unwrap_or(0.0)
+    }
+
+    /// Like [`Choice::calculate`], but returns `None` if the choice's Scorer
+    /// entity is gone. The built-in [`Picker`](crate::pickers::Picker)s skip
+    /// those choices, so one broken choice can't take the whole Thinker down
+    /// with it.
+    pub fn try_calculate(&self, scores: &Query<&Score>) -> Option<f32> {
+        scores.get(self.scorer.0).ok().map(Score::get)
+    }
+
+    /// Whether `scorer` is this choice's Scorer, or a shared Scorer used
+    /// somewhere in its tree.
+    pub(crate) fn uses_scorer(&self, scorer: Entity) -> bool {
+        self.scorer.0 == scorer || self.shared_scorers.contains(&scorer)
     }
 }
 
 /// Builds a new [`Choice`].
+///
+/// Labels, tags, and the `once` flag survive a round trip through
+/// [`FromReflect`], but the Scorer and Action builders themselves are trait
+/// objects, which can't. A rebuilt `ChoiceBuilder` gets
+/// inert stand-ins instead: a Scorer that always scores `0.0`, and an Action
+/// that fails as soon as it's built. Use the saved labels to find the real
+/// builders and put them back in [`ChoiceBuilder::when`] and
+/// [`ChoiceBuilder::then`].
 #[derive(Clone, Debug, Reflect)]
-#[reflect(from_reflect = false)]
 pub struct ChoiceBuilder {
     when_label: Option<String>,
-    #[reflect(ignore)]
+    #[reflect(ignore, default = "unresolved_scorer")]
     pub when: Arc<dyn ScorerBuilder>,
     then_label: Option<String>,
-    #[reflect(ignore)]
+    #[reflect(ignore, default = "unresolved_action")]
     pub then: Arc<dyn ActionBuilder>,
+    once: bool,
+    tag: Option<String>,
 }
 impl ChoiceBuilder {
     pub fn new(scorer: Arc<dyn ScorerBuilder>, action: Arc<dyn ActionBuilder>) -> Self {
@@ -45,16 +79,87 @@ impl ChoiceBuilder {
             when: scorer,
             then_label: action.label().map(|s| s.into()),
             then: action,
+            once: false,
+            tag: None,
         }
     }
 
+    /// The label of the Scorer this choice was built with, if it had one.
+    pub fn when_label(&self) -> Option<&str> {
+        self.when_label.as_deref()
+    }
+
+    /// The label of the Action this choice was built with, if it had one.
+    pub fn then_label(&self) -> Option<&str> {
+        self.then_label.as_deref()
+    }
+
+    /// Makes this a one-shot choice: once its action succeeds, the choice is
+    /// removed from its Thinker for good.
+    pub fn once(mut self) -> Self {
+        self.once = true;
+        self
+    }
+
+    /// Tags the built [`Choice`], so custom pickers can tell it apart.
+    pub fn tag(mut self, tag: impl AsRef<str>) -> Self {
+        self.tag = Some(tag.as_ref().to_string());
+        self
+    }
+
     pub fn build(&self, cmd: &mut Commands, actor: Entity, parent: Entity) -> Choice {
         let scorer_ent = scorers::spawn_scorer(&*self.when, cmd, actor);
-        cmd.entity(parent).add_children(&[scorer_ent]);
+        // Composites adopt the Scorers they use, so take the shared ones back:
+        // they outlive any single composite.
+        let shared_scorers = scorers::take_used_shared_scorers();
+        cmd.entity(parent)
+            .add_children(&[scorer_ent])
+            .add_children(&shared_scorers);
         Choice {
             scorer: Scorer(scorer_ent),
             action_label: self.then.label().map(|s| s.into()),
             action: ActionBuilderWrapper::new(self.then.clone()),
+            once: self.once,
+            shared_scorers,
+            tag: self.tag.clone(),
         }
     }
 }
+
+/// Stand-in for the builders a [`ChoiceBuilder`] or [`Choice`] loses when
+/// it's rebuilt through [`FromReflect`].
+#[derive(Debug)]
+struct Unresolved;
+
+impl ScorerBuilder for Unresolved {
+    fn build(&self, _cmd: &mut Commands, _scorer: Entity, _actor: Entity) {
+        warn!("Built a Scorer that was loaded without its ScorerBuilder. It will always score 0.");
+    }
+
+    fn label(&self) -> Option<&str> {
+        Some("Unresolved")
+    }
+}
+
+impl ActionBuilder for Unresolved {
+    fn build(&self, cmd: &mut Commands, action: Entity, _actor: Entity) {
+        warn!("Built an Action that was loaded without its ActionBuilder. Failing it instead.");
+        cmd.entity(action).insert(ActionState::Failure);
+    }
+
+    fn label(&self) -> Option<&str> {
+        Some("Unresolved")
+    }
+}
+
+fn unresolved_scorer() -> Arc<dyn ScorerBuilder> {
+    Arc::new(Unresolved)
+}
+
+fn unresolved_action() -> Arc<dyn ActionBuilder> {
+    Arc::new(Unresolved)
+}
+
+fn unresolved_action_wrapper() -> ActionBuilderWrapper {
+    ActionBuilderWrapper::new(unresolved_action())
+}
@@ -0,0 +1,158 @@
+//! Debugging tools for looking at what your AI is up to. Only available with
+//! the `debug` feature enabled.
+
+use std::fmt::Write;
+
+use bevy::prelude::*;
+
+use crate::{
+    scorers::Score,
+    thinker::{ActionSpan, HasThinker, ScorerSpan, Thinker},
+};
+
+#[cfg(feature = "debug_overlay")]
+pub mod overlay;
+
+/// Renders an actor's [`Thinker`] as a [Graphviz](https://graphviz.org/) DOT
+/// graph. Every choice shows up as a Scorer node (including any child
+/// Scorers of composite Scorers) with its current [`Score`], pointing at the
+/// Action it would pick. The currently-running Actions are highlighted.
+///
+/// Returns an empty graph if `actor` doesn't have a Thinker.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// fn dump_thinkers(world: &World, actors: &[Entity]) {
+///     for actor in actors {
+///         println!("{}", big_brain::debug::thinker_to_dot(world, *actor));
+///     }
+/// }
+/// ```
+pub fn thinker_to_dot(world: &World, actor: Entity) -> String {
+    let mut dot = String::from("digraph thinker {\n    node [shape=box];\n");
+    let Some(thinker_ent) = world.get::<HasThinker>(actor).map(|t| t.entity()) else {
+        dot.push_str("}\n");
+        return dot;
+    };
+    let Some(thinker) = world.get::<Thinker>(thinker_ent) else {
+        dot.push_str("}\n");
+        return dot;
+    };
+    let current = thinker.current_action.as_ref();
+
+    let thinker_label = world
+        .get::<ActionSpan>(thinker_ent)
+        .and_then(|span| span.label())
+        .unwrap_or("Thinker");
+    node(&mut dot, thinker_ent, thinker_label, None, false);
+
+    let mut current_found = false;
+    for choice in thinker.choices.iter() {
+        scorer_nodes(&mut dot, world, thinker_ent, choice.scorer.0);
+        // Shared Scorers are children of the Thinker, not of the composites
+        // using them, so they won't turn up while walking the tree.
+        for shared in choice.shared_scorers.iter() {
+            if *shared != choice.scorer.0 {
+                scorer_nodes(&mut dot, world, choice.scorer.0, *shared);
+            }
+        }
+        let is_concurrent = thinker
+            .concurrent_actions
+            .iter()
+            .any(|(_, wrapper)| wrapper.0 == choice.action.0);
+        let is_current = current.is_some_and(|(_, wrapper)| wrapper.0 == choice.action.0);
+        current_found |= is_current;
+        let is_current = is_current || is_concurrent;
+        let action_id = format!("{}_action", node_id(choice.scorer.0));
+        let label = escape(choice.action_label.as_deref().unwrap_or("Action"));
+        let _ = writeln!(
+            dot,
+            "    \"{action_id}\" [label=\"{label}\"{}];",
+            highlight(is_current)
+        );
+        let _ = writeln!(
+            dot,
+            "    \"{}\" -> \"{action_id}\";",
+            node_id(choice.scorer.0)
+        );
+    }
+
+    if let Some(otherwise) = &thinker.otherwise {
+        let is_current = current.is_some_and(|(_, wrapper)| wrapper.0 == otherwise.0);
+        current_found |= is_current;
+        let label = escape(otherwise.1.label().unwrap_or("Action"));
+        let _ = writeln!(
+            dot,
+            "    \"otherwise\" [label=\"otherwise: {label}\"{}];",
+            highlight(is_current)
+        );
+        let _ = writeln!(dot, "    \"{}\" -> \"otherwise\";", node_id(thinker_ent));
+    }
+
+    // Scheduled actions aren't part of any choice, so they get their own node.
+    if let Some((action, wrapper)) = current.filter(|_| !current_found) {
+        let label = wrapper.1.label().unwrap_or("Action");
+        node(&mut dot, action.entity(), label, None, true);
+        let _ = writeln!(
+            dot,
+            "    \"{}\" -> \"{}\" [style=dashed];",
+            node_id(thinker_ent),
+            node_id(action.entity())
+        );
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+fn scorer_nodes(dot: &mut String, world: &World, parent: Entity, scorer: Entity) {
+    let label = world
+        .get::<ScorerSpan>(scorer)
+        .and_then(|span| span.label())
+        .unwrap_or("Scorer");
+    let score = world.get::<Score>(scorer).map(|score| score.get());
+    node(dot, scorer, label, score, false);
+    let _ = writeln!(
+        dot,
+        "    \"{}\" -> \"{}\";",
+        node_id(parent),
+        node_id(scorer)
+    );
+    if let Some(children) = world.get::<Children>(scorer) {
+        for child in children.iter() {
+            if world.get::<Score>(*child).is_some() {
+                scorer_nodes(dot, world, scorer, *child);
+            }
+        }
+    }
+}
+
+fn node(dot: &mut String, ent: Entity, label: &str, score: Option<f32>, current: bool) {
+    let label = escape(label);
+    let score = score.map(|s| format!("\\n{s:.3}")).unwrap_or_default();
+    let _ = writeln!(
+        dot,
+        "    \"{}\" [label=\"{label}{score}\"{}];",
+        node_id(ent),
+        highlight(current)
+    );
+}
+
+fn node_id(ent: Entity) -> String {
+    format!("e{}", ent.to_bits())
+}
+
+fn highlight(current: bool) -> &'static str {
+    if current {
+        ", style=filled, fillcolor=palegreen"
+    } else {
+        ""
+    }
+}
+
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -0,0 +1,176 @@
+//! Draws what every actor's Thinker is up to right on top of it. Only
+//! available with the `debug_overlay` feature, and turned on with
+//! [`BigBrainPlugin::with_debug_overlay`](crate::BigBrainPlugin::with_debug_overlay).
+//!
+//! Above each actor with a [`GlobalTransform`], the overlay shows:
+//!
+//! * The label of the Action it's currently running, followed by its
+//!   highest-scoring choices and their current [`Score`]s, as [`Text2d`].
+//!   See [`DebugOverlayText`].
+//! * A sphere for the current Action, drawn with gizmos and colored after
+//!   that Action's label (see [`label_color`]). It's gray if nothing's
+//!   running.
+//! * A bar per choice, bottom to top in the order the choices were declared,
+//!   filled up to the choice's current [`Score`] and colored after the label
+//!   of the Action it picks.
+//!
+//! [`Text2d`] only shows up for 2D cameras, while the gizmos work for both.
+//! Use [`thinker_to_dot`](super::thinker_to_dot) when you need the full
+//! picture.
+
+use std::fmt::Write;
+
+use bevy::{prelude::*, utils::HashSet};
+
+use crate::{
+    scorers::Score,
+    thinker::{HasThinker, Thinker},
+};
+
+/// Where and how big the overlay draws things. Inserted by
+/// [`BigBrainPlugin::with_debug_overlay`](crate::BigBrainPlugin::with_debug_overlay),
+/// and can be changed at any time.
+#[derive(Debug, Clone, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct DebugOverlay {
+    /// Offset from the actor's position to the center of the sphere.
+    pub offset: Vec3,
+    /// Radius of the sphere. Bars are four times as wide, and sit this far
+    /// apart from each other. Use something in the order of pixels for 2D.
+    pub size: f32,
+    /// How many of the highest-scoring choices the text lists.
+    pub top_scores: usize,
+}
+
+impl Default for DebugOverlay {
+    fn default() -> Self {
+        Self {
+            offset: Vec3::Y * 2.0,
+            size: 0.1,
+            top_scores: 3,
+        }
+    }
+}
+
+/// The color the overlay uses for an Action with the given label. It's
+/// always the same for the same label, so it can be used to put together a
+/// legend.
+pub fn label_color(label: &str) -> Color {
+    // FNV-1a, so colors stay put between runs and platforms.
+    let hash = label.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+    Color::hsl((hash % 360) as f32, 0.8, 0.6)
+}
+
+/// System that draws the overlay's gizmos. Added to `PostUpdate` by
+/// [`BigBrainPlugin::with_debug_overlay`](crate::BigBrainPlugin::with_debug_overlay).
+pub fn debug_overlay_system(
+    overlay: Res<DebugOverlay>,
+    mut gizmos: Gizmos,
+    actors: Query<(&GlobalTransform, &HasThinker)>,
+    thinkers: Query<&Thinker>,
+    scores: Query<&Score>,
+) {
+    let size = overlay.size;
+    for (transform, has_thinker) in actors.iter() {
+        let Ok(thinker) = thinkers.get(has_thinker.entity()) else {
+            continue;
+        };
+        let center = transform.translation() + overlay.offset;
+        let color = thinker
+            .current_action_label()
+            .map_or(Color::srgb(0.5, 0.5, 0.5), label_color);
+        gizmos.sphere(Isometry3d::from_translation(center), size, color);
+
+        let width = size * 4.0;
+        let left = center - Vec3::X * width / 2.0;
+        for (idx, choice) in thinker.choices.iter().enumerate() {
+            let start = left + Vec3::Y * size * (idx as f32 + 2.0);
+            let end = start + Vec3::X * width;
+            let filled = start + Vec3::X * width * choice.calculate(&scores);
+            let color = choice.action.1.label().map_or(Color::WHITE, label_color);
+            gizmos.line(start, end, Color::srgb(0.2, 0.2, 0.2));
+            gizmos.line(start, filled, color);
+        }
+    }
+}
+
+/// The [`Text2d`] entity showing an actor's current Action and top scores.
+/// [`debug_overlay_text_system`] spawns one per actor, and despawns it once
+/// the actor's gone or lost its Thinker. Change its [`TextFont`] to restyle
+/// it.
+#[derive(Debug, Clone, Component)]
+pub struct DebugOverlayText {
+    /// The actor this text is about.
+    pub actor: Entity,
+}
+
+/// System that keeps the overlay's text up to date. Added to `PostUpdate` by
+/// [`BigBrainPlugin::with_debug_overlay`](crate::BigBrainPlugin::with_debug_overlay).
+pub fn debug_overlay_text_system(
+    mut cmd: Commands,
+    overlay: Res<DebugOverlay>,
+    actors: Query<(Entity, &GlobalTransform, &HasThinker)>,
+    thinkers: Query<&Thinker>,
+    scores: Query<&Score>,
+    mut texts: Query<(
+        Entity,
+        &DebugOverlayText,
+        &mut Text2d,
+        &mut TextColor,
+        &mut Transform,
+    )>,
+) {
+    let describe = |actor: Entity| {
+        let (_, transform, has_thinker) = actors.get(actor).ok()?;
+        let thinker = thinkers.get(has_thinker.entity()).ok()?;
+        let label = thinker.current_action_label();
+        let mut choices = thinker
+            .choices
+            .iter()
+            .map(|choice| {
+                let label = choice.action.1.label().unwrap_or("<unlabeled>");
+                (label, choice.calculate(&scores))
+            })
+            .collect::<Vec<_>>();
+        choices.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        let mut text = label.unwrap_or("<none>").to_string();
+        for (label, score) in choices.into_iter().take(overlay.top_scores) {
+            let _ = write!(text, "\n{label}: {score:.2}");
+        }
+        let color = label.map_or(Color::srgb(0.5, 0.5, 0.5), label_color);
+        let translation = transform.translation() + overlay.offset + Vec3::Y * overlay.size * 2.0;
+        Some((text, color, translation))
+    };
+
+    let mut labelled = HashSet::new();
+    for (text_ent, overlay_text, mut text2d, mut text_color, mut transform) in texts.iter_mut() {
+        let Some((text, color, translation)) = describe(overlay_text.actor) else {
+            cmd.entity(text_ent).despawn();
+            continue;
+        };
+        labelled.insert(overlay_text.actor);
+        if text2d.0 != text {
+            text2d.0 = text;
+        }
+        if text_color.0 != color {
+            text_color.0 = color;
+        }
+        transform.translation = translation;
+    }
+    for (actor, ..) in actors.iter() {
+        if labelled.contains(&actor) {
+            continue;
+        }
+        if let Some((text, color, translation)) = describe(actor) {
+            cmd.spawn((
+                Name::new("Big Brain Debug Overlay"),
+                DebugOverlayText { actor },
+                Text2d::new(text),
+                TextColor(color),
+                Transform::from_translation(translation),
+            ));
+        }
+    }
+}
@@ -133,7 +133,7 @@ impl SigmoidEvaluator {
             xb,
             ya,
             yb,
-            two_over_dx: (2.0 / (xb - ya)).abs(),
+            two_over_dx: (2.0 / (xb - xa)).abs(),
             x_mean: (xa + xb) / 2.0,
             y_mean: (ya + yb) / 2.0,
             dy_over_two: (yb - ya) / 2.0,
@@ -162,6 +162,98 @@ impl Default for SigmoidEvaluator {
     }
 }
 
+/**
+[`Evaluator`] with a bell-shaped, or "Gaussian", curve. Peaks at `1.0` when the
+value is exactly `center` and falls off symmetrically on either side. `width`
+is the curve's standard deviation: about 60% of the peak is left at one
+`width` away from the center, and about 14% at two.
+ */
+#[derive(Debug, Clone, Reflect)]
+pub struct GaussianEvaluator {
+    xa: f32,
+    xb: f32,
+    center: f32,
+    two_width_squared: f32,
+}
+
+impl GaussianEvaluator {
+    pub fn new(center: f32, width: f32) -> Self {
+        Self::new_ranged(center, width, f32::NEG_INFINITY, f32::INFINITY)
+    }
+
+    /// Like [`GaussianEvaluator::new`], but values are clamped to
+    /// `min..=max` before being evaluated.
+    pub fn new_ranged(center: f32, width: f32, min: f32, max: f32) -> Self {
+        Self {
+            xa: min,
+            xb: max,
+            center,
+            two_width_squared: 2.0 * width * width,
+        }
+    }
+}
+
+impl Default for GaussianEvaluator {
+    fn default() -> Self {
+        Self::new(0.5, 0.15)
+    }
+}
+
+impl Evaluator for GaussianEvaluator {
+    fn evaluate(&self, value: f32) -> f32 {
+        let cx_minus_center = clamp(value, self.xa, self.xb) - self.center;
+        if self.two_width_squared == 0.0 {
+            return if cx_minus_center == 0.0 { 1.0 } else { 0.0 };
+        }
+        (-(cx_minus_center * cx_minus_center) / self.two_width_squared).exp()
+    }
+}
+
+/**
+[`Evaluator`] that linearly interpolates between a set of `(x, y)` points.
+Values outside the range of the points are clamped to the first or last
+point's `y`. Handy for hand-authored curves, like how appealing something is
+over the course of a day.
+ */
+#[derive(Debug, Clone, Default, Reflect)]
+pub struct CurveEvaluator {
+    points: Vec<(f32, f32)>,
+}
+
+impl CurveEvaluator {
+    /// Creates a new curve from the given points. They don't need to be
+    /// sorted.
+    pub fn new(points: impl IntoIterator<Item = (f32, f32)>) -> Self {
+        let mut points: Vec<_> = points.into_iter().collect();
+        points.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        Self { points }
+    }
+}
+
+impl Evaluator for CurveEvaluator {
+    fn evaluate(&self, value: f32) -> f32 {
+        let (Some(first), Some(last)) = (self.points.first(), self.points.last()) else {
+            return 0.0;
+        };
+        if value <= first.0 {
+            return first.1;
+        }
+        if value >= last.0 {
+            return last.1;
+        }
+        for window in self.points.windows(2) {
+            let ((xa, ya), (xb, yb)) = (window[0], window[1]);
+            if value <= xb {
+                if xb == xa {
+                    return yb;
+                }
+                return ya + (yb - ya) * (value - xa) / (xb - xa);
+            }
+        }
+        last.1
+    }
+}
+
 pub(crate) fn clamp<T: PartialOrd>(val: T, min: T, max: T) -> T {
     let val = if val > max { max } else { val };
     if val < min {
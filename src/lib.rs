@@ -86,7 +86,7 @@
 //!                     thirst.thirst = 10.0;
 //!                     *state = ActionState::Success;
 //!                 }
-//!                 ActionState::Cancelled => {
+//!                 ActionState::Cancelled | ActionState::Interrupted => {
 //!                     *state = ActionState::Failure;
 //!                 }
 //!                 _ => {}
@@ -157,6 +157,23 @@
 //! should be able to get some useful display info while using things like
 //! [`bevy_inspector_egui`](https://crates.io/crates/bevy_inspector_egui).
 //!
+//! The plain data components, like `Score`, `FixedScore`, `ActionState`,
+//! and `ActionAge`, are registered by `BigBrainPlugin`, so they show up in
+//! inspectors and can be copied between worlds through reflection. So can
+//! `Actor` and the Composite Scorer components, like `AllOrNothing` and
+//! `SumOfScorers`, whose child Scorer entities get remapped through
+//! `MapEntities`. `EvaluatingScorer` and `MeasuredScorer` hold trait
+//! objects, so they can't be rebuilt from reflection. `Choice` and
+//! `ChoiceBuilder` keep their labels, tags, and `once` flags through
+//! `FromReflect`, but not their builders, which are trait objects. See
+//! `ChoiceBuilder` for the details.
+//!
+//! None of this makes a Thinker restorable from a saved scene. The `Thinker`
+//! itself, its Picker, and the spans on Scorer and Action entities aren't
+//! reflected, so a loaded scene has the data but nothing to run it. To save
+//! an actor's progress, use `ThinkerSnapshot`, and rebuild its Thinker from
+//! its `ThinkerBuilder` when loading.
+//!
 //! This implementation should **not** be considered stable, and individual
 //! fields made visible may change at **any time** and not be considered
 //! towards semver. Please use this feature **only for debugging**.
@@ -176,10 +193,17 @@ pub mod pickers;
 
 pub mod actions;
 pub mod choices;
+#[cfg(feature = "debug")]
+pub mod debug;
 pub mod measures;
+pub mod registry;
 pub mod scorers;
 pub mod thinker;
 
+pub use actions::actors_running;
+pub use scorers::{query_score, set_score_override, set_scorer_enabled};
+pub use thinker::{cancel_actor, evaluate_thinker_now, restore_thinker, snapshot_thinker};
+
 pub mod prelude {
     /*!
     Convenience module with the core types you're most likely to use when working with Big Brain. Mean to be used like `use big_brain::prelude::*;`
@@ -188,20 +212,38 @@ pub mod prelude {
 
     pub use super::BigBrainPlugin;
     pub use super::BigBrainSet;
-    pub use actions::{ActionBuilder, ActionState, ConcurrentMode, Concurrently, Steps};
+    pub use actions::{
+        ActionAge, ActionBuilder, ActionBuilderExt, ActionRng, ActionState, ActionStateChanged,
+        ConcurrentMode, Concurrently, Detach, RandomAction, Retry, Steps, StepsCancelPolicy, Ticks,
+        WaitUntil,
+    };
     pub use big_brain_derive::{ActionBuilder, ScorerBuilder};
-    pub use evaluators::{Evaluator, LinearEvaluator, PowerEvaluator, SigmoidEvaluator};
-    pub use measures::{ChebyshevDistance, Measure, WeightedProduct, WeightedSum};
-    pub use pickers::{FirstToScore, Highest, HighestToScore, Picker};
+    pub use evaluators::{
+        CurveEvaluator, Evaluator, GaussianEvaluator, LinearEvaluator, PowerEvaluator,
+        SigmoidEvaluator,
+    };
+    pub use measures::{
+        ChebyshevDistance, Measure, MinkowskiDistance, WeightedProduct, WeightedSum,
+    };
+    pub use pickers::{
+        AllToScore, FirstToScore, FirstValidPicker, Highest, HighestPerGroup, HighestToScore,
+        LazyFirstToScore, Picker, StableHighest, Sticky,
+    };
+    pub use registry::{BigBrainAppExt, BigBrainTypeRegistry};
     pub use scorers::{
-        AllOrNothing, EvaluatingScorer, FixedScore, MeasuredScorer, ProductOfScorers, Score,
-        ScorerBuilder, SumOfScorers, WinningScorer,
+        AllOrNothing, CombineScorer, ConditionScorer, DeadZoneScorer, Enabled, EvaluatingScorer,
+        FixedScore, MarginScorer, MeasuredScorer, NthScorer, OverTargets, ProductOfScorers,
+        RateLimitedScorer, RequiresComponent, ResourceScore, ScheduledScorer, Score, ScoreOverride,
+        ScorerBuilder, SumOfScorers, SustainedScorer, TargetReducer, TieBreak, WinningScorer,
     };
     pub use thinker::{
-        Action, ActionSpan, Actor, HasThinker, Scorer, ScorerSpan, Thinker, ThinkerBuilder,
+        Action, ActionCompleted, ActionSpan, Actor, HasThinker, PendingAction, Scorer, ScorerSpan,
+        Thinker, ThinkerBuilder, ThinkerFinished, ThinkerSnapshot,
     };
 }
 
+use std::{cell::Cell, sync::Arc};
+
 use bevy::{
     ecs::{intern::Interned, schedule::ScheduleLabel},
     prelude::*,
@@ -226,7 +268,33 @@ pub struct BigBrainPlugin {
     #[reflect(ignore)]
     schedule: Interned<dyn ScheduleLabel>,
     #[reflect(ignore)]
+    actions_schedule: Interned<dyn ScheduleLabel>,
+    #[reflect(ignore)]
+    scorers_schedule: Option<Interned<dyn ScheduleLabel>>,
+    #[reflect(ignore)]
     cleanup_schedule: Interned<dyn ScheduleLabel>,
+    #[reflect(ignore)]
+    attach_schedule: Option<Interned<dyn ScheduleLabel>>,
+    action_state_events: bool,
+    action_conflict_warnings: bool,
+    pool_actions: bool,
+    deterministic: bool,
+    max_build_depth: usize,
+    debug_overlay: bool,
+    #[reflect(ignore)]
+    run_conditions: Vec<SetRunCondition>,
+}
+
+type ConfigureSet = dyn Fn(&mut App, Interned<dyn ScheduleLabel>, BigBrainSet) + Send + Sync;
+
+/// Applies a user-provided run condition to one of the [`BigBrainSet`]s.
+#[derive(Clone)]
+struct SetRunCondition(Arc<ConfigureSet>);
+
+impl std::fmt::Debug for SetRunCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SetRunCondition")
+    }
 }
 
 impl BigBrainPlugin {
@@ -235,7 +303,17 @@ impl BigBrainPlugin {
     pub fn new(schedule: impl ScheduleLabel) -> Self {
         Self {
             schedule: schedule.intern(),
+            actions_schedule: schedule.intern(),
+            scorers_schedule: None,
             cleanup_schedule: Last.intern(),
+            attach_schedule: None,
+            action_state_events: false,
+            action_conflict_warnings: false,
+            pool_actions: false,
+            deterministic: false,
+            debug_overlay: false,
+            max_build_depth: DEFAULT_MAX_BUILD_DEPTH,
+            run_conditions: Vec::new(),
         }
     }
 
@@ -244,22 +322,331 @@ impl BigBrainPlugin {
         self.cleanup_schedule = cleanup_schedule.intern();
         self
     }
+
+    /// The Schedule the scorers, thinkers, and actions run in. Use this to
+    /// add your own systems to the same Schedule Big Brain is using.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use big_brain::prelude::*;
+    ///
+    /// # fn my_action_system() {}
+    /// let plugin = BigBrainPlugin::new(PreUpdate);
+    /// App::new()
+    ///     .add_systems(plugin.schedule(), my_action_system.in_set(BigBrainSet::Actions))
+    ///     .add_plugins(plugin);
+    /// ```
+    pub fn schedule(&self) -> Interned<dyn ScheduleLabel> {
+        self.schedule
+    }
+
+    /// Run [`BigBrainSet::Actions`] in a different Schedule than the scorers
+    /// and thinkers. This is useful when your actions move things around and
+    /// need to run alongside physics in `Update`, but you'd still like
+    /// scoring to happen in, say, `PreUpdate`.
+    ///
+    /// Scorers and Thinkers still run chained in the main Schedule, and
+    /// Actions still only ever see what the Thinker last decided. If the
+    /// actions Schedule runs *after* the main one in a frame (like `Update`
+    /// after `PreUpdate`), that's the same frame's decisions. If it runs
+    /// before, actions pick up decisions a frame late. Either way, any
+    /// `ActionState` changes your actions make are only seen by the Thinker
+    /// the next time it runs, which is one frame of extra latency compared
+    /// to running everything in a single Schedule.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use big_brain::prelude::*;
+    ///
+    /// # fn move_action_system() {}
+    /// let plugin = BigBrainPlugin::new(PreUpdate).set_actions_schedule(Update);
+    /// App::new()
+    ///     .add_systems(
+    ///         plugin.actions_schedule(),
+    ///         move_action_system.in_set(BigBrainSet::Actions),
+    ///     )
+    ///     .add_plugins(plugin);
+    /// ```
+    pub fn set_actions_schedule(mut self, actions_schedule: impl ScheduleLabel) -> Self {
+        self.actions_schedule = actions_schedule.intern();
+        self
+    }
+
+    /// The Schedule actions run in. Same as [`BigBrainPlugin::schedule`]
+    /// unless overridden with [`BigBrainPlugin::set_actions_schedule`].
+    pub fn actions_schedule(&self) -> Interned<dyn ScheduleLabel> {
+        self.actions_schedule
+    }
+
+    /// Run [`BigBrainSet::Scorers`] in a different Schedule than the
+    /// thinkers and actions, usually `FixedUpdate` or `FixedPreUpdate`. This
+    /// is useful when scoring is expensive and doesn't need to keep up with
+    /// the frame rate, but actions should still run every frame.
+    ///
+    /// Scores stay put between fixed steps, so Thinkers keep deciding every
+    /// frame based on whatever the scorers last reported. That means a
+    /// Thinker can react up to one fixed timestep (plus a frame) late to a
+    /// change in the world, and on frames where the fixed Schedule runs
+    /// several times, only the last scores are ever seen. If you'd rather
+    /// have the Thinkers on the fixed timestep as well, use that as the main
+    /// Schedule and move only the actions out with
+    /// [`BigBrainPlugin::set_actions_schedule`] instead.
+    ///
+    /// Your own scorer systems need to go in this Schedule too.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use big_brain::prelude::*;
+    ///
+    /// # fn thirsty_scorer_system() {}
+    /// let plugin = BigBrainPlugin::new(PreUpdate).set_scorers_schedule(FixedPreUpdate);
+    /// App::new()
+    ///     .add_systems(
+    ///         plugin.scorers_schedule(),
+    ///         thirsty_scorer_system.in_set(BigBrainSet::Scorers),
+    ///     )
+    ///     .add_plugins(plugin);
+    /// ```
+    pub fn set_scorers_schedule(mut self, scorers_schedule: impl ScheduleLabel) -> Self {
+        self.scorers_schedule = Some(scorers_schedule.intern());
+        self
+    }
+
+    /// The Schedule scorers run in. Same as [`BigBrainPlugin::schedule`]
+    /// unless overridden with [`BigBrainPlugin::set_scorers_schedule`].
+    pub fn scorers_schedule(&self) -> Interned<dyn ScheduleLabel> {
+        self.scorers_schedule.unwrap_or(self.schedule)
+    }
+
+    /// The Schedule cleanup tasks run in. Defaults to `Last`.
+    pub fn cleanup_schedule(&self) -> Interned<dyn ScheduleLabel> {
+        self.cleanup_schedule
+    }
+
+    /// Overwrite the Schedule that spawns Thinkers for newly-added
+    /// [`ThinkerBuilder`](thinker::ThinkerBuilder)s. By default, this is part
+    /// of cleanup, so a new actor's Thinker only starts running the frame
+    /// after it's spawned.
+    ///
+    /// If this is the same Schedule as [`BigBrainPlugin::schedule`], Thinkers
+    /// are spawned right before [`BigBrainSet::Scorers`] instead (or
+    /// [`BigBrainSet::Thinkers`], if scorers run in their own Schedule), so
+    /// actors spawned before that point start thinking the same frame.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use big_brain::prelude::*;
+    ///
+    /// App::new().add_plugins(BigBrainPlugin::new(PreUpdate).set_attach_schedule(PreUpdate));
+    /// ```
+    pub fn set_attach_schedule(mut self, attach_schedule: impl ScheduleLabel) -> Self {
+        self.attach_schedule = Some(attach_schedule.intern());
+        self
+    }
+
+    /// The Schedule Thinkers are spawned in. Same as
+    /// [`BigBrainPlugin::cleanup_schedule`] unless overridden with
+    /// [`BigBrainPlugin::set_attach_schedule`].
+    pub fn attach_schedule(&self) -> Interned<dyn ScheduleLabel> {
+        self.attach_schedule.unwrap_or(self.cleanup_schedule)
+    }
+
+    /// Send an [`ActionStateChanged`](actions::ActionStateChanged) event
+    /// every time an Action's state changes. Off by default.
+    pub fn emit_action_state_events(mut self, emit: bool) -> Self {
+        self.action_state_events = emit;
+        self
+    }
+
+    /// Warn when an actor has several live Actions with the same Action
+    /// component. Off by default. Only Action types registered with
+    /// [`BigBrainAppExt::register_action`](registry::BigBrainAppExt::register_action)
+    /// are checked. See
+    /// [`action_conflict_system`](actions::action_conflict_system) for the
+    /// details.
+    pub fn warn_on_action_conflicts(mut self, warn: bool) -> Self {
+        self.action_conflict_warnings = warn;
+        self
+    }
+
+    /// Reuse a Thinker's finished Action entities instead of despawning them
+    /// and spawning new ones. Off by default. See
+    /// [`ActionPooling`](actions::ActionPooling) for the details.
+    pub fn pool_actions(mut self, pool: bool) -> Self {
+        self.pool_actions = pool;
+        self
+    }
+
+    /// Evaluate every Thinker every frame, instead of leaving some for the
+    /// next frame once Big Brain has used up its per-frame CPU budget. Off
+    /// by default. Turn this on if you need reproducible behavior, like for
+    /// lockstep multiplayer or replays, and can live with the occasional
+    /// long frame. See
+    /// [`DeterministicThinking`](thinker::DeterministicThinking) for the
+    /// details.
+    pub fn deterministic(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+
+    /// Show what every actor's Thinker is up to on top of it: the label of
+    /// its current Action and its top Scores as text, plus some gizmos. Off
+    /// by default. Needs the `debug_overlay` feature, and Bevy's
+    /// `GizmoPlugin` and `TextPlugin`, which `DefaultPlugins` includes. See
+    /// [`debug::overlay`] for what gets drawn.
+    #[cfg(feature = "debug_overlay")]
+    pub fn with_debug_overlay(mut self) -> Self {
+        self.debug_overlay = true;
+        self
+    }
+
+    /// Sets how deeply Scorers and Actions can be nested while they're being
+    /// built. Composites nested deeper than this (usually because a builder
+    /// ends up including itself) are logged as errors and left unbuilt, rather
+    /// than overflowing the stack. Actions that weren't built are marked as
+    /// [`ActionState::Failure`](actions::ActionState::Failure). Defaults to
+    /// 128.
+    ///
+    /// Each App keeps its own limit. It applies to everything Big Brain builds
+    /// from its own systems. Builders you run yourself, through
+    /// [`spawn_action`](actions::spawn_action) or
+    /// [`ThinkerBuilder::attach_to`](thinker::ThinkerBuilder::attach_to), always
+    /// use the default.
+    pub fn max_build_depth(mut self, depth: usize) -> Self {
+        self.max_build_depth = depth;
+        self
+    }
+
+    /// Only run Big Brain (all of its [`BigBrainSet`]s, cleanup included)
+    /// when `condition` is true. Can be called multiple times, in which case
+    /// all conditions must be true.
+    pub fn run_if<M>(
+        mut self,
+        condition: impl Condition<M> + Clone + Send + Sync + 'static,
+    ) -> Self {
+        self.run_conditions.push(SetRunCondition(Arc::new(
+            move |app: &mut App, schedule: Interned<dyn ScheduleLabel>, set: BigBrainSet| {
+                app.configure_sets(schedule, set.run_if(condition.clone()));
+            },
+        )));
+        self
+    }
+
+    /// Only run Big Brain while the app is in the given [`State`]. Handy for
+    /// pausing all AI while in menus. Needs the `states` feature.
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use big_brain::prelude::*;
+    ///
+    /// #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    /// enum GameState {
+    ///     #[default]
+    ///     Menu,
+    ///     Playing,
+    /// }
+    ///
+    /// App::new()
+    ///     .add_plugins(DefaultPlugins)
+    ///     .init_state::<GameState>()
+    ///     .add_plugins(BigBrainPlugin::new(PreUpdate).run_in_state(GameState::Playing))
+    ///     .run();
+    /// ```
+    #[cfg(feature = "states")]
+    pub fn run_in_state<S: States>(self, state: S) -> Self {
+        self.run_if(in_state(state))
+    }
 }
 
 impl Plugin for BigBrainPlugin {
     fn build(&self, app: &mut App) {
+        app.insert_resource(MaxBuildDepth(self.max_build_depth));
+        let scorers_schedule = self.scorers_schedule();
+        match (
+            scorers_schedule == self.schedule,
+            self.actions_schedule == self.schedule,
+        ) {
+            (true, true) => {
+                app.configure_sets(
+                    self.schedule.intern(),
+                    (
+                        BigBrainSet::Scorers,
+                        BigBrainSet::Thinkers,
+                        BigBrainSet::Actions,
+                    )
+                        .chain(),
+                );
+            }
+            (true, false) => {
+                app.configure_sets(
+                    self.schedule.intern(),
+                    (BigBrainSet::Scorers, BigBrainSet::Thinkers).chain(),
+                )
+                .configure_sets(self.actions_schedule.intern(), BigBrainSet::Actions);
+            }
+            (false, true) => {
+                app.configure_sets(scorers_schedule, BigBrainSet::Scorers)
+                    .configure_sets(
+                        self.schedule.intern(),
+                        (BigBrainSet::Thinkers, BigBrainSet::Actions).chain(),
+                    );
+            }
+            (false, false) => {
+                app.configure_sets(scorers_schedule, BigBrainSet::Scorers)
+                    .configure_sets(self.schedule.intern(), BigBrainSet::Thinkers)
+                    .configure_sets(self.actions_schedule.intern(), BigBrainSet::Actions);
+            }
+        }
         app.configure_sets(
             self.schedule.intern(),
+            BigBrainSet::Decisions.in_set(BigBrainSet::Thinkers),
+        )
+        .configure_sets(self.cleanup_schedule.intern(), BigBrainSet::Cleanup);
+        let attach_schedule = self.attach_schedule();
+        if attach_schedule == scorers_schedule {
+            app.configure_sets(
+                attach_schedule,
+                BigBrainSet::Cleanup.before(BigBrainSet::Scorers),
+            );
+        } else if attach_schedule == self.schedule {
+            app.configure_sets(
+                attach_schedule,
+                BigBrainSet::Cleanup.before(BigBrainSet::Thinkers),
+            );
+        } else if attach_schedule != self.cleanup_schedule {
+            app.configure_sets(attach_schedule, BigBrainSet::Cleanup);
+        }
+        for SetRunCondition(configure) in self.run_conditions.iter() {
+            configure(app, scorers_schedule, BigBrainSet::Scorers);
+            configure(app, self.schedule, BigBrainSet::Thinkers);
+            configure(app, self.actions_schedule, BigBrainSet::Actions);
+            configure(app, self.cleanup_schedule, BigBrainSet::Cleanup);
+            if attach_schedule != self.cleanup_schedule {
+                configure(app, attach_schedule, BigBrainSet::Cleanup);
+            }
+        }
+        scorers::add_score_now_systems(app);
+        app.add_systems(
+            scorers_schedule,
             (
-                BigBrainSet::Scorers,
-                BigBrainSet::Thinkers,
-                BigBrainSet::Actions,
+                scorers::enabled_scorer_system,
+                scorers::score_override_system,
             )
-                .chain(),
+                .chain()
+                .in_set(BigBrainSet::Scorers),
         )
-        .configure_sets(self.cleanup_schedule.intern(), BigBrainSet::Cleanup)
         .add_systems(
-            self.schedule.intern(),
+            scorers_schedule,
             (
                 scorers::fixed_score_system,
                 scorers::measured_scorers_system,
@@ -267,27 +654,119 @@ impl Plugin for BigBrainPlugin {
                 scorers::sum_of_scorers_system,
                 scorers::product_of_scorers_system,
                 scorers::winning_scorer_system,
+                scorers::nth_scorer_system,
                 scorers::evaluating_scorer_system,
+                scorers::margin_scorer_system,
+                scorers::sustained_scorer_system,
+                scorers::dead_zone_scorer_system,
+                scorers::combine_scorer_system,
+                scorers::rate_limited_scorer_system,
+                scorers::condition_scorer_system,
             )
-                .in_set(BigBrainSet::Scorers),
+                .in_set(BigBrainSet::Scorers)
+                .after(scorers::score_override_system),
         )
         .add_systems(
             self.schedule.intern(),
-            thinker::thinker_system.in_set(BigBrainSet::Thinkers),
+            (
+                thinker::thinker_system.before(BigBrainSet::Decisions),
+                thinker::lazy_scoring_system
+                    .after(thinker::thinker_system)
+                    .before(BigBrainSet::Decisions),
+                thinker::pending_action_system.after(BigBrainSet::Decisions),
+                actions::action_age_system.after(thinker::pending_action_system),
+                actions::resume_yielded_system.after(thinker::pending_action_system),
+            )
+                .in_set(BigBrainSet::Thinkers),
         )
         .add_systems(
-            self.schedule.intern(),
-            (actions::steps_system, actions::concurrent_system).in_set(BigBrainSet::Actions),
+            self.actions_schedule.intern(),
+            (
+                actions::steps_system,
+                actions::concurrent_system,
+                actions::ticks_system,
+                actions::retry_system,
+                actions::wait_until_system,
+                actions::random_action_system,
+                actions::detach_system,
+            )
+                .in_set(BigBrainSet::Actions),
+        )
+        .add_systems(
+            attach_schedule,
+            thinker::thinker_component_attach_system.in_set(BigBrainSet::Cleanup),
         )
         .add_systems(
             self.cleanup_schedule.intern(),
             (
-                thinker::thinker_component_attach_system,
                 thinker::thinker_component_detach_system,
                 thinker::actor_gone_cleanup,
             )
                 .in_set(BigBrainSet::Cleanup),
         );
+
+        app.register_type::<scorers::Score>()
+            .register_type::<scorers::FixedScore>()
+            .register_type::<scorers::Enabled>()
+            .register_type::<scorers::StandaloneScorer>()
+            .register_type::<scorers::ScoreOverride>()
+            .register_type::<scorers::SkipScoring>()
+            .register_type::<scorers::AllOrNothing>()
+            .register_type::<scorers::SumOfScorers>()
+            .register_type::<scorers::ProductOfScorers>()
+            .register_type::<scorers::WinningScorer>()
+            .register_type::<scorers::NthScorer>()
+            .register_type::<scorers::MarginScorer>()
+            .register_type::<scorers::SustainedScorer>()
+            .register_type::<scorers::DeadZoneScorer>()
+            .register_type::<scorers::RateLimitedScorer>()
+            .register_type::<thinker::Actor>()
+            .register_type::<thinker::Unwinding>()
+            .register_type::<actions::ActionState>()
+            .register_type::<actions::ActionAge>()
+            .register_type::<actions::Detached>()
+            .register_type::<choices::Choice>()
+            .register_type::<choices::ChoiceBuilder>();
+
+        app.init_resource::<actions::ActionRng>()
+            .init_resource::<registry::BigBrainTypeRegistry>();
+
+        if self.pool_actions {
+            app.insert_resource(actions::ActionPooling);
+        }
+
+        if self.deterministic {
+            app.insert_resource(thinker::DeterministicThinking);
+        }
+
+        #[cfg(feature = "debug_overlay")]
+        if self.debug_overlay {
+            app.init_resource::<debug::overlay::DebugOverlay>()
+                .register_type::<debug::overlay::DebugOverlay>()
+                .add_systems(
+                    PostUpdate,
+                    (
+                        debug::overlay::debug_overlay_text_system
+                            .before(bevy::transform::TransformSystem::TransformPropagate),
+                        debug::overlay::debug_overlay_system
+                            .after(bevy::transform::TransformSystem::TransformPropagate),
+                    ),
+                );
+        }
+
+        if self.action_conflict_warnings {
+            app.add_systems(
+                self.cleanup_schedule.intern(),
+                actions::action_conflict_system.in_set(BigBrainSet::Cleanup),
+            );
+        }
+
+        if self.action_state_events {
+            app.add_event::<actions::ActionStateChanged>().add_systems(
+                self.cleanup_schedule.intern(),
+                actions::action_state_changed_system.in_set(BigBrainSet::Cleanup),
+            );
+        }
     }
 }
 
@@ -301,6 +780,74 @@ pub enum BigBrainSet {
     Actions,
     /// Thinkers run their logic in this set.
     Thinkers,
-    /// Various internal cleanup items run in this final set.
+    /// Runs inside [`BigBrainSet::Thinkers`], after Thinkers have decided
+    /// what to do but before that decision is acted upon. Systems in here
+    /// can inspect or override each Thinker's
+    /// [`PendingAction`](thinker::PendingAction).
+    Decisions,
+    /// Various internal cleanup items run in this final set. If
+    /// [`BigBrainPlugin::set_attach_schedule`] is used, spawning Thinkers
+    /// runs in this set in that Schedule as well.
     Cleanup,
 }
+
+const DEFAULT_MAX_BUILD_DEPTH: usize = 128;
+
+/// The [`BigBrainPlugin::max_build_depth`] of the App it's in. Inserted by
+/// [`BigBrainPlugin`].
+#[derive(Clone, Copy, Debug, Resource)]
+pub struct MaxBuildDepth(usize);
+
+impl MaxBuildDepth {
+    /// How deeply Scorers and Actions can be nested while they're being built.
+    pub fn get(&self) -> usize {
+        self.0
+    }
+
+    /// Uses this App's limit for anything built on the current thread until
+    /// the returned guard is dropped. Without one, builders get the default.
+    pub(crate) fn enter(max_depth: Option<&Self>) -> MaxBuildDepthScope {
+        let max_depth = max_depth.map_or(DEFAULT_MAX_BUILD_DEPTH, |max_depth| max_depth.0);
+        MaxBuildDepthScope(MAX_BUILD_DEPTH.with(|max| max.replace(max_depth)))
+    }
+}
+
+/// Restores the previous maximum build depth when dropped. See
+/// [`MaxBuildDepth::enter`].
+pub(crate) struct MaxBuildDepthScope(usize);
+
+impl Drop for MaxBuildDepthScope {
+    fn drop(&mut self) {
+        MAX_BUILD_DEPTH.with(|max| max.set(self.0));
+    }
+}
+
+thread_local! {
+    static BUILD_DEPTH: Cell<usize> = const { Cell::new(0) };
+    static MAX_BUILD_DEPTH: Cell<usize> = const { Cell::new(DEFAULT_MAX_BUILD_DEPTH) };
+}
+
+/// Keeps track of how deeply nested the Scorer or Action currently being
+/// built is. See [`BigBrainPlugin::max_build_depth`].
+pub(crate) struct BuildDepthGuard;
+
+impl BuildDepthGuard {
+    /// Enters one level deeper. Returns `None` if that would go past the
+    /// configured maximum depth.
+    pub(crate) fn enter() -> Option<Self> {
+        BUILD_DEPTH.with(|depth| {
+            if depth.get() >= MAX_BUILD_DEPTH.with(Cell::get) {
+                None
+            } else {
+                depth.set(depth.get() + 1);
+                Some(Self)
+            }
+        })
+    }
+}
+
+impl Drop for BuildDepthGuard {
+    fn drop(&mut self) {
+        BUILD_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
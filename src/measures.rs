@@ -22,7 +22,7 @@ impl Measure for WeightedSum {
     fn calculate(&self, scores: Vec<(&Score, f32)>) -> f32 {
         scores
             .iter()
-            .fold(0f32, |acc, (score, weight)| acc + score.0 * weight)
+            .fold(0f32, |acc, (score, weight)| acc + score.get() * weight)
     }
 }
 
@@ -34,7 +34,7 @@ impl Measure for WeightedProduct {
     fn calculate(&self, scores: Vec<(&Score, f32)>) -> f32 {
         scores
             .iter()
-            .fold(0f32, |acc, (score, weight)| acc * score.0 * weight)
+            .fold(0f32, |acc, (score, weight)| acc * score.get() * weight)
     }
 }
 
@@ -46,9 +46,35 @@ pub struct ChebyshevDistance;
 
 impl Measure for ChebyshevDistance {
     fn calculate(&self, scores: Vec<(&Score, f32)>) -> f32 {
+        scores.iter().fold(0f32, |best, (score, weight)| {
+            (score.get() * weight).max(best)
+        })
+    }
+}
+
+/// A measure that combines the weighted child scores using the
+/// [Minkowski distance](https://en.wikipedia.org/wiki/Minkowski_distance)
+/// of order `p`, `(sum((score * weight)^p))^(1/p)`.
+///
+/// `p` is a single knob between additive and max-like combination: `p = 1`
+/// is the same as [`WeightedSum`], `p = 2` is the Euclidean distance, and the
+/// larger `p` gets, the closer it comes to [`ChebyshevDistance`], which is
+/// what an infinite `p` gives you. `p` should be at least `1.0`.
+#[derive(Debug, Clone, Reflect)]
+pub struct MinkowskiDistance {
+    pub p: f32,
+}
+
+impl Measure for MinkowskiDistance {
+    fn calculate(&self, scores: Vec<(&Score, f32)>) -> f32 {
+        if self.p.is_infinite() {
+            return ChebyshevDistance.calculate(scores);
+        }
         scores
             .iter()
-            .fold(0f32, |best, (score, weight)| (score.0 * weight).max(best))
+            .map(|(score, weight)| (score.get() * weight).powf(self.p))
+            .sum::<f32>()
+            .powf(1.0 / self.p)
     }
 }
 
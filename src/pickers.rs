@@ -1,5 +1,7 @@
 //! Pickers are used by Thinkers to determine which of its Scorers will "win".
 
+use std::sync::Arc;
+
 use bevy::prelude::*;
 
 use crate::{choices::Choice, scorers::Score};
@@ -7,11 +9,52 @@ use crate::{choices::Choice, scorers::Score};
 /// Required trait for Pickers. A Picker is given a slice of choices and a
 /// query that can be passed into `Choice::calculate`.
 ///
+/// The choices are always in the order they were declared on the
+/// [`ThinkerBuilder`](crate::thinker::ThinkerBuilder), so Pickers are free to
+/// give earlier choices priority.
+///
 /// Implementations of `pick` must return `Some(Choice)` for the `Choice` that
 /// was picked, or `None`.
 #[reflect_trait]
 pub trait Picker: std::fmt::Debug + Sync + Send {
     fn pick<'a>(&self, choices: &'a [Choice], scores: &Query<&Score>) -> Option<&'a Choice>;
+
+    /// Like `pick`, but also told which of the `choices`, if any, the Thinker
+    /// is currently running. This is what Thinkers actually call, so Pickers
+    /// that care about the current choice (like [`StableHighest`]) should
+    /// override this.
+    ///
+    /// Defaults to ignoring `current` and calling `pick`.
+    fn pick_with_current<'a>(
+        &self,
+        choices: &'a [Choice],
+        scores: &Query<&Score>,
+        current: Option<&'a Choice>,
+    ) -> Option<&'a Choice> {
+        let _ = current;
+        self.pick(choices, scores)
+    }
+
+    /// Picks any number of `Choice`s to run at the same time. This is what
+    /// Thinkers in MultiThinker mode (see
+    /// [`ThinkerBuilder::multi`](crate::thinker::ThinkerBuilder::multi)) use
+    /// instead of `pick`.
+    ///
+    /// Defaults to whatever `pick` picks, if anything.
+    fn pick_many<'a>(&self, choices: &'a [Choice], scores: &Query<&Score>) -> Vec<&'a Choice> {
+        self.pick(choices, scores).into_iter().collect()
+    }
+
+    /// Whether the Scorers of the choices after the one this Picker picked
+    /// can go unscored, because it would have picked the same choice no
+    /// matter what they scored. Thinkers mark those Scorers with
+    /// [`SkipScoring`](crate::scorers::SkipScoring) until they're needed
+    /// again.
+    ///
+    /// Defaults to `false`.
+    fn is_lazy(&self) -> bool {
+        false
+    }
 }
 
 /// Picker that chooses the first `Choice` with a [`Score`] higher than its
@@ -41,13 +84,56 @@ impl FirstToScore {
 
 impl Picker for FirstToScore {
     fn pick<'a>(&self, choices: &'a [Choice], scores: &Query<&Score>) -> Option<&'a Choice> {
-        for choice in choices {
-            let value = choice.calculate(scores);
-            if value >= self.threshold {
-                return Some(choice);
-            }
-        }
-        None
+        choices.iter().find(|choice| {
+            choice
+                .try_calculate(scores)
+                .is_some_and(|value| value >= self.threshold)
+        })
+    }
+}
+
+/// Like [`FirstToScore`], but doesn't bother scoring the choices after the
+/// one that got picked. Their Scorers get marked with
+/// [`SkipScoring`](crate::scorers::SkipScoring), which the built-in Scorer
+/// systems and ones using [`ScorerQuery`](crate::scorers::ScorerQuery) skip
+/// automatically, so expensive low-priority Scorers only run while every
+/// choice before them is below the threshold. Your own Scorer systems that
+/// don't use `ScorerQuery` need a `Without<SkipScoring>` filter to be skipped.
+///
+/// Skipped Scores are [unset](Score::unset), so they're never picked based on
+/// an old value. The flip side is that once the picked choice drops below the
+/// threshold, the ones after it only get a chance a frame later, once they've
+/// been scored again.
+///
+/// ### Example
+///
+/// ```
+/// # use big_brain::prelude::*;
+/// # fn main() {
+/// Thinker::build()
+///     .picker(LazyFirstToScore::new(0.8))
+///     // .when(...)
+/// # ;
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LazyFirstToScore {
+    pub threshold: f32,
+}
+
+impl LazyFirstToScore {
+    pub fn new(threshold: f32) -> Self {
+        Self { threshold }
+    }
+}
+
+impl Picker for LazyFirstToScore {
+    fn pick<'a>(&self, choices: &'a [Choice], scores: &Query<&Score>) -> Option<&'a Choice> {
+        FirstToScore::new(self.threshold).pick(choices, scores)
+    }
+
+    fn is_lazy(&self) -> bool {
+        true
     }
 }
 
@@ -125,3 +211,330 @@ impl Picker for HighestToScore {
         })
     }
 }
+
+/// Picker that chooses every `Choice` with a [`Score`] higher than its
+/// configured `threshold`. Meant for Thinkers in MultiThinker mode (see
+/// [`ThinkerBuilder::multi`](crate::thinker::ThinkerBuilder::multi)). With a
+/// regular Thinker, it behaves just like [`FirstToScore`].
+///
+/// ### Example
+///
+/// ```
+/// # use big_brain::prelude::*;
+/// # fn main() {
+/// Thinker::build()
+///     .multi(true)
+///     .picker(AllToScore::new(0.5))
+///     // .when(...)
+/// # ;
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AllToScore {
+    pub threshold: f32,
+}
+
+impl AllToScore {
+    pub fn new(threshold: f32) -> Self {
+        Self { threshold }
+    }
+}
+
+impl Picker for AllToScore {
+    fn pick<'a>(&self, choices: &'a [Choice], scores: &Query<&Score>) -> Option<&'a Choice> {
+        FirstToScore::new(self.threshold).pick(choices, scores)
+    }
+
+    fn pick_many<'a>(&self, choices: &'a [Choice], scores: &Query<&Score>) -> Vec<&'a Choice> {
+        choices
+            .iter()
+            .filter(|choice| {
+                choice
+                    .try_calculate(scores)
+                    .is_some_and(|score| score >= self.threshold)
+            })
+            .collect()
+    }
+}
+
+/// Picker that behaves like [`Highest`], but only switches away from the
+/// choice the Thinker is currently running if another one beats its current
+/// [`Score`] by more than `min_gap`. This keeps Thinkers from flip-flopping
+/// between choices whose Scores are close and jittery.
+///
+/// If nothing is running yet, or the current choice's Score drops to `0.0`,
+/// it picks just like [`Highest`].
+///
+/// ### Example
+///
+/// ```
+/// # use big_brain::prelude::*;
+/// # fn main() {
+/// Thinker::build()
+///     .picker(StableHighest::new(0.1))
+///     // .when(...)
+/// # ;
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StableHighest {
+    pub min_gap: f32,
+}
+
+impl StableHighest {
+    pub fn new(min_gap: f32) -> Self {
+        Self { min_gap }
+    }
+}
+
+impl Picker for StableHighest {
+    fn pick<'a>(&self, choices: &'a [Choice], scores: &Query<&Score>) -> Option<&'a Choice> {
+        Highest.pick(choices, scores)
+    }
+
+    fn pick_with_current<'a>(
+        &self,
+        choices: &'a [Choice],
+        scores: &Query<&Score>,
+        current: Option<&'a Choice>,
+    ) -> Option<&'a Choice> {
+        let best = Highest.pick(choices, scores)?;
+        let Some(current) = current else {
+            return Some(best);
+        };
+        let current_score = current.calculate(scores);
+        if current_score <= 0.0 || best.calculate(scores) > current_score + self.min_gap {
+            Some(best)
+        } else {
+            Some(current)
+        }
+    }
+}
+
+/// Picker that wraps another Picker and gives whatever choice the Thinker is
+/// currently running a head start of `stickiness`. If the inner Picker picks
+/// a different choice, the Thinker only switches to it if its [`Score`] beats
+/// the current choice's by more than `stickiness`. Unlike [`StableHighest`],
+/// this works with any Picker.
+///
+/// The head start only counts against other choices the inner Picker picks.
+/// If it doesn't pick anything, neither does `Sticky`, so it won't keep a
+/// choice that's dropped below the inner Picker's threshold. Choices whose
+/// Score drops to `0.0` don't get a head start either.
+///
+/// ### Example
+///
+/// ```
+/// # use big_brain::prelude::*;
+/// # fn main() {
+/// Thinker::build()
+///     .picker(Sticky::new(HighestToScore::new(0.5), 0.1))
+///     // .when(...)
+/// # ;
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Sticky<P: Picker> {
+    pub inner: P,
+    pub stickiness: f32,
+}
+
+impl<P: Picker> Sticky<P> {
+    pub fn new(inner: P, stickiness: f32) -> Self {
+        Self { inner, stickiness }
+    }
+}
+
+impl<P: Picker> Picker for Sticky<P> {
+    fn pick<'a>(&self, choices: &'a [Choice], scores: &Query<&Score>) -> Option<&'a Choice> {
+        self.inner.pick(choices, scores)
+    }
+
+    fn pick_with_current<'a>(
+        &self,
+        choices: &'a [Choice],
+        scores: &Query<&Score>,
+        current: Option<&'a Choice>,
+    ) -> Option<&'a Choice> {
+        let picked = self.inner.pick_with_current(choices, scores, current)?;
+        let Some(current) = current else {
+            return Some(picked);
+        };
+        let current_score = current.calculate(scores);
+        if std::ptr::eq(picked, current)
+            || current_score <= 0.0
+            || picked.calculate(scores) > current_score + self.stickiness
+        {
+            Some(picked)
+        } else {
+            Some(current)
+        }
+    }
+
+    fn pick_many<'a>(&self, choices: &'a [Choice], scores: &Query<&Score>) -> Vec<&'a Choice> {
+        self.inner.pick_many(choices, scores)
+    }
+}
+
+/// Picker that tries a list of Pickers in order, and goes with whatever the
+/// first one to pick something picks. This makes for tiered strategies, like
+/// going for anything really urgent first, and settling for anything
+/// reasonable otherwise. In MultiThinker mode, it goes with the first
+/// non-empty [`Picker::pick_many`].
+///
+/// ### Example
+///
+/// ```
+/// # use big_brain::prelude::*;
+/// # fn main() {
+/// Thinker::build()
+///     .picker(
+///         FirstValidPicker::new()
+///             .push(HighestToScore::new(0.8))
+///             .push(FirstToScore::new(0.3)),
+///     )
+///     // .when(...)
+/// # ;
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FirstValidPicker {
+    pub pickers: Vec<Arc<dyn Picker>>,
+}
+
+impl FirstValidPicker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a Picker to try after the ones already added.
+    pub fn push(mut self, picker: impl Picker + 'static) -> Self {
+        self.pickers.push(Arc::new(picker));
+        self
+    }
+}
+
+impl Picker for FirstValidPicker {
+    fn pick<'a>(&self, choices: &'a [Choice], scores: &Query<&Score>) -> Option<&'a Choice> {
+        self.pickers
+            .iter()
+            .find_map(|picker| picker.pick(choices, scores))
+    }
+
+    fn pick_with_current<'a>(
+        &self,
+        choices: &'a [Choice],
+        scores: &Query<&Score>,
+        current: Option<&'a Choice>,
+    ) -> Option<&'a Choice> {
+        self.pickers
+            .iter()
+            .find_map(|picker| picker.pick_with_current(choices, scores, current))
+    }
+
+    fn pick_many<'a>(&self, choices: &'a [Choice], scores: &Query<&Score>) -> Vec<&'a Choice> {
+        self.pickers
+            .iter()
+            .map(|picker| picker.pick_many(choices, scores))
+            .find(|picked| !picked.is_empty())
+            .unwrap_or_default()
+    }
+}
+
+/// Picker for running one choice per group at the same time, using choice
+/// tags as group names (see
+/// [`ThinkerBuilder::when_tagged`](crate::thinker::ThinkerBuilder::when_tagged)).
+/// Meant for Thinkers in MultiThinker mode (see
+/// [`ThinkerBuilder::multi`](crate::thinker::ThinkerBuilder::multi)), where it
+/// picks the highest-scoring choice of each group, as long as it scores at
+/// least `threshold`. Ties go to the earlier choice, and untagged choices all
+/// count as one group. This lets a single Thinker juggle independent concerns,
+/// like moving and fighting, while making sure it never tries to do two
+/// things of the same kind at once.
+///
+/// With a regular Thinker, it picks the highest-scoring choice overall, just
+/// like [`HighestToScore`].
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct FarFromHome;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct Threatened;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct EnemyInRange;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct GoHome;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct Flee;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct Shoot;
+/// # fn main() {
+/// // Moves and shoots at the same time, but never goes home and flees at once.
+/// Thinker::build()
+///     .multi(true)
+///     .picker(HighestPerGroup::new(0.5))
+///     .when_tagged(FarFromHome, GoHome, "movement")
+///     .when_tagged(Threatened, Flee, "movement")
+///     .when_tagged(EnemyInRange, Shoot, "combat")
+/// # ;
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HighestPerGroup {
+    pub threshold: f32,
+}
+
+impl HighestPerGroup {
+    pub fn new(threshold: f32) -> Self {
+        Self { threshold }
+    }
+
+    /// The highest-scoring choice out of `choices`, if it makes the
+    /// threshold.
+    fn best<'a>(
+        &self,
+        choices: impl Iterator<Item = &'a Choice>,
+        scores: &Query<&Score>,
+    ) -> Option<&'a Choice> {
+        let mut best: Option<(&'a Choice, f32)> = None;
+        for choice in choices {
+            let Some(score) = choice.try_calculate(scores) else {
+                continue;
+            };
+            if score >= self.threshold && best.is_none_or(|(_, best)| score > best) {
+                best = Some((choice, score));
+            }
+        }
+        best.map(|(choice, _)| choice)
+    }
+}
+
+impl Picker for HighestPerGroup {
+    fn pick<'a>(&self, choices: &'a [Choice], scores: &Query<&Score>) -> Option<&'a Choice> {
+        self.best(choices.iter(), scores)
+    }
+
+    fn pick_many<'a>(&self, choices: &'a [Choice], scores: &Query<&Score>) -> Vec<&'a Choice> {
+        let mut groups: Vec<Option<&str>> = Vec::new();
+        for choice in choices {
+            if !groups.contains(&choice.tag.as_deref()) {
+                groups.push(choice.tag.as_deref());
+            }
+        }
+        groups
+            .into_iter()
+            .filter_map(|group| {
+                self.best(
+                    choices
+                        .iter()
+                        .filter(|choice| choice.tag.as_deref() == group),
+                    scores,
+                )
+            })
+            .collect()
+    }
+}
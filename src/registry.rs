@@ -0,0 +1,183 @@
+//! A catalog of the Scorer and Action types an app uses, for tools like
+//! visual AI editors that need to list them at runtime.
+
+use std::any::TypeId;
+
+use bevy::{
+    prelude::*,
+    reflect::{GetTypeRegistration, TypeInfo, Typed},
+};
+
+use crate::{actions::ActionBuilder, scorers::ScorerBuilder};
+
+/// Whether a [`RegisteredBuilder`] is for a Scorer or an Action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum BuilderKind {
+    Scorer,
+    Action,
+}
+
+/// One field of a [`RegisteredBuilder`], as seen through [`Reflect`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuilderField {
+    /// The field's name. Fields of tuple structs are named by their index,
+    /// like `"0"`.
+    pub name: String,
+    /// The full type path of the field's type, like `"f32"` or
+    /// `"alloc::string::String"`.
+    pub type_path: &'static str,
+}
+
+/// Everything [`BigBrainTypeRegistry`] knows about a Scorer or Action type.
+#[derive(Debug, Clone)]
+pub struct RegisteredBuilder {
+    /// Whether this is a Scorer or an Action.
+    pub kind: BuilderKind,
+    /// The type's [`TypeId`].
+    pub type_id: TypeId,
+    /// The full type path, like `"my_game::ai::Thirsty"`.
+    pub type_path: &'static str,
+    /// Just the type's name, like `"Thirsty"`.
+    pub short_name: &'static str,
+    /// The label a default-constructed builder of this type reports.
+    pub default_label: Option<String>,
+    /// The type's fields, in declaration order. Empty for unit structs and
+    /// enums.
+    pub fields: Vec<BuilderField>,
+}
+
+/// Lists every Scorer and Action type registered with
+/// [`BigBrainAppExt::register_scorer`] and
+/// [`BigBrainAppExt::register_action`], in the order they were registered.
+/// The types are also added to Bevy's own [`AppTypeRegistry`], so their
+/// values can be inspected and edited through [`Reflect`] as usual.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// #[derive(Debug, Clone, Default, Component, Reflect, ScorerBuilder)]
+/// struct Thirsty {
+///     threshold: f32,
+/// }
+///
+/// #[derive(Debug, Clone, Default, Component, Reflect, ActionBuilder)]
+/// struct Drink;
+///
+/// let mut app = App::new();
+/// app.register_scorer::<Thirsty>().register_action::<Drink>();
+///
+/// let registry = app.world().resource::<BigBrainTypeRegistry>();
+/// let thirsty = registry.scorers().next().unwrap();
+/// assert_eq!(thirsty.short_name, "Thirsty");
+/// assert_eq!(thirsty.fields[0].name, "threshold");
+/// assert_eq!(registry.actions().count(), 1);
+/// ```
+#[derive(Debug, Default, Resource)]
+pub struct BigBrainTypeRegistry {
+    builders: Vec<RegisteredBuilder>,
+}
+
+impl BigBrainTypeRegistry {
+    /// Every registered Scorer and Action type.
+    pub fn iter(&self) -> impl Iterator<Item = &RegisteredBuilder> {
+        self.builders.iter()
+    }
+
+    /// Every registered Scorer type.
+    pub fn scorers(&self) -> impl Iterator<Item = &RegisteredBuilder> {
+        self.iter()
+            .filter(|builder| builder.kind == BuilderKind::Scorer)
+    }
+
+    /// Every registered Action type.
+    pub fn actions(&self) -> impl Iterator<Item = &RegisteredBuilder> {
+        self.iter()
+            .filter(|builder| builder.kind == BuilderKind::Action)
+    }
+
+    /// Looks up a registered type by its full type path.
+    pub fn get(&self, type_path: &str) -> Option<&RegisteredBuilder> {
+        self.iter().find(|builder| builder.type_path == type_path)
+    }
+
+    /// Looks up a registered type by its [`TypeId`].
+    pub fn get_by_id(&self, type_id: TypeId) -> Option<&RegisteredBuilder> {
+        self.iter().find(|builder| builder.type_id == type_id)
+    }
+
+    fn add<T: Typed>(&mut self, kind: BuilderKind, default_label: Option<String>) {
+        if self.get_by_id(TypeId::of::<T>()).is_some() {
+            return;
+        }
+        let fields = match T::type_info() {
+            TypeInfo::Struct(info) => info
+                .iter()
+                .map(|field| BuilderField {
+                    name: field.name().to_string(),
+                    type_path: field.type_path(),
+                })
+                .collect(),
+            TypeInfo::TupleStruct(info) => info
+                .iter()
+                .map(|field| BuilderField {
+                    name: field.index().to_string(),
+                    type_path: field.type_path(),
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        let table = T::type_info().type_path_table();
+        self.builders.push(RegisteredBuilder {
+            kind,
+            type_id: TypeId::of::<T>(),
+            type_path: table.path(),
+            short_name: table.short_path(),
+            default_label,
+            fields,
+        });
+    }
+}
+
+/// Extension methods on [`App`] for filling in the [`BigBrainTypeRegistry`].
+/// Registering the same type twice is harmless.
+pub trait BigBrainAppExt {
+    /// Registers a Scorer type. It's also registered with Bevy's
+    /// [`AppTypeRegistry`].
+    fn register_scorer<T>(&mut self) -> &mut Self
+    where
+        T: ScorerBuilder + Default + GetTypeRegistration + Typed;
+
+    /// Registers an Action type. It's also registered with Bevy's
+    /// [`AppTypeRegistry`].
+    fn register_action<T>(&mut self) -> &mut Self
+    where
+        T: ActionBuilder + Default + GetTypeRegistration + Typed;
+}
+
+impl BigBrainAppExt for App {
+    fn register_scorer<T>(&mut self) -> &mut Self
+    where
+        T: ScorerBuilder + Default + GetTypeRegistration + Typed,
+    {
+        let label = T::default().label().map(String::from);
+        self.register_type::<T>()
+            .world_mut()
+            .get_resource_or_init::<BigBrainTypeRegistry>()
+            .add::<T>(BuilderKind::Scorer, label);
+        self
+    }
+
+    fn register_action<T>(&mut self) -> &mut Self
+    where
+        T: ActionBuilder + Default + GetTypeRegistration + Typed,
+    {
+        let label = T::default().label().map(String::from);
+        self.register_type::<T>()
+            .world_mut()
+            .get_resource_or_init::<BigBrainTypeRegistry>()
+            .add::<T>(BuilderKind::Action, label);
+        self
+    }
+}
@@ -2,26 +2,75 @@
 //! range of 0.0..=1.0. This module includes the ScorerBuilder trait and some
 //! built-in Composite Scorers.
 
-use std::{cmp::Ordering, sync::Arc};
+use std::{
+    any::TypeId,
+    cell::{Cell, RefCell},
+    cmp::Ordering,
+    marker::PhantomData,
+    ops::Deref,
+    sync::Arc,
+};
 
-use bevy::prelude::*;
 #[cfg(feature = "trace")]
 use bevy::utils::tracing::trace;
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        query::{QueryFilter, ROQueryItem, ReadOnlyQueryData},
+        reflect::ReflectMapEntities,
+        schedule::{BoxedCondition, ScheduleLabel},
+        system::SystemParam,
+    },
+    prelude::*,
+    utils::{Duration, HashMap},
+};
 
 use crate::{
     evaluators::Evaluator,
     measures::{Measure, WeightedMeasure},
-    thinker::{Actor, Scorer, ScorerSpan},
+    thinker::{Actor, HasThinker, Scorer, ScorerSpan, Thinker},
+    BuildDepthGuard,
 };
 
 /// Score value between `0.0..=1.0` associated with a Scorer.
+///
+/// A Score can also be [unset](Score::unset), meaning its Scorer has no
+/// opinion right now (for example, because it has no data yet). Unset Scores
+/// read as `0.0`, but Composite Scorers like [`SumOfScorers`] and
+/// [`MeasuredScorer`] leave them out entirely instead of letting them drag
+/// the result down.
+///
+/// All of its fields are reflected, so inspectors like `bevy_inspector_egui`
+/// show the raw `value` along with whether the Scorer is `disabled`, `unset`
+/// or `overridden`. [`Score::get`] combines them into the value Pickers see.
 #[derive(Clone, Component, Debug, Default, Reflect)]
-pub struct Score(pub(crate) f32);
+#[reflect(Component, Default)]
+pub struct Score {
+    value: f32,
+    disabled: bool,
+    unset: bool,
+    overridden: Option<f32>,
+}
 
 impl Score {
-    /// Returns the `Score`'s current value.
+    pub(crate) fn new(value: f32) -> Self {
+        Self {
+            value,
+            disabled: false,
+            unset: false,
+            overridden: None,
+        }
+    }
+
+    /// Returns the `Score`'s current value. This is always `0.0` while the
+    /// Scorer is disabled with [`Enabled`], and the override value while it
+    /// has a [`ScoreOverride`].
     pub fn get(&self) -> f32 {
-        self.0
+        if self.disabled {
+            0.0
+        } else {
+            self.overridden.unwrap_or(self.value)
+        }
     }
 
     /// Set the `Score`'s value.
@@ -33,7 +82,8 @@ impl Score {
         if !(0.0..=1.0).contains(&value) {
             panic!("Score value must be between 0.0 and 1.0");
         }
-        self.0 = value;
+        self.value = value;
+        self.unset = false;
     }
 
     /// Set the `Score`'s value. Allows values outside the range `0.0..=1.0`
@@ -41,7 +91,22 @@ impl Score {
     /// isn't a set scale. Avoid using unless it's not feasible to rescale
     /// and use `set` instead.
     pub fn set_unchecked(&mut self, value: f32) {
-        self.0 = value;
+        self.value = value;
+        self.unset = false;
+    }
+
+    /// Marks the `Score` as having no opinion. It reads as `0.0` until the
+    /// next call to [`Score::set`], but Composite Scorers that support it
+    /// will skip it instead of treating it as `0.0`.
+    pub fn unset(&mut self) {
+        self.value = 0.0;
+        self.unset = true;
+    }
+
+    /// Returns `true` if the `Score` was [unset](Score::unset) and hasn't
+    /// been set since.
+    pub fn is_unset(&self) -> bool {
+        self.unset && self.overridden.is_none()
     }
 }
 
@@ -97,6 +162,102 @@ pub trait ScorerBuilder: std::fmt::Debug + Sync + Send {
     fn label(&self) -> Option<&str> {
         None
     }
+
+    /// Called by [`spawn_scorer`] right after [`ScorerBuilder::build`], once
+    /// the usual `Name`, [`Score`] and [`Actor`] components have been
+    /// inserted. Override it to add your own components to the Scorer
+    /// [`Entity`], or to replace its `Name`. Does nothing by default.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// #[derive(Debug, Component)]
+    /// struct DebugGizmo;
+    /// #[derive(Debug)]
+    /// struct Thirsty;
+    ///
+    /// impl ScorerBuilder for Thirsty {
+    ///   fn build(&self, _cmd: &mut Commands, _scorer: Entity, _actor: Entity) {}
+    ///
+    ///   fn on_spawn(&self, cmd: &mut Commands, scorer: Entity) {
+    ///     cmd.entity(scorer).insert((Name::new("Thirsty"), DebugGizmo));
+    ///   }
+    /// }
+    /// ```
+    fn on_spawn(&self, _cmd: &mut Commands, _scorer: Entity) {}
+
+    /// Opts this Scorer into being shared. If this returns a key, every
+    /// Scorer with the same key for the same actor within a single
+    /// [`Thinker`](crate::thinker::Thinker) uses one Scorer entity, so its
+    /// system only has to compute the value once. Useful for expensive
+    /// Scorers used in several choices.
+    ///
+    /// Only the first builder with a given key is actually built, so shared
+    /// Scorers should be stateless: any two builders with the same key must
+    /// produce the same Score.
+    ///
+    /// Shared Scorers are children of their Thinker, not of any composite
+    /// Scorer that uses them, so despawning one composite leaves them alone.
+    /// They live until no choice uses them anymore, or until their Thinker
+    /// goes away.
+    ///
+    /// With the derive macro, add `#[scorer_shared]` to share by type:
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// #[derive(Debug, Clone, Component, ScorerBuilder)]
+    /// #[scorer_shared]
+    /// struct EnemyDistance;
+    /// ```
+    fn shared_key(&self) -> Option<TypeId> {
+        None
+    }
+}
+
+thread_local! {
+    static SHARED_SCORERS: RefCell<Option<SharedScorers>> = const { RefCell::new(None) };
+    static SPAWNING_STANDALONE: Cell<bool> = const { Cell::new(false) };
+}
+
+#[derive(Default)]
+pub(crate) struct SharedScorers {
+    by_key: HashMap<(Entity, TypeId), Entity>,
+    used: Vec<Entity>,
+}
+
+/// Keeps track of shared Scorers (see [`ScorerBuilder::shared_key`]) while a
+/// Thinker is being built. Restores whatever was being tracked before when
+/// dropped.
+pub(crate) struct SharedScorersScope(Option<SharedScorers>);
+
+impl SharedScorersScope {
+    pub(crate) fn enter() -> Self {
+        Self(SHARED_SCORERS.with(|shared| shared.replace(Some(SharedScorers::default()))))
+    }
+}
+
+impl Drop for SharedScorersScope {
+    fn drop(&mut self) {
+        SHARED_SCORERS.with(|shared| *shared.borrow_mut() = self.0.take());
+    }
+}
+
+/// Returns the shared Scorers used since the last call, wherever they were
+/// used in the Scorer tree, and forgets about them.
+pub(crate) fn take_used_shared_scorers() -> Vec<Entity> {
+    SHARED_SCORERS.with(|shared| {
+        shared
+            .borrow_mut()
+            .as_mut()
+            .map(|shared| {
+                let mut used = std::mem::take(&mut shared.used);
+                used.sort();
+                used.dedup();
+                used
+            })
+            .unwrap_or_default()
+    })
 }
 
 pub fn spawn_scorer<T: ScorerBuilder + ?Sized>(
@@ -104,7 +265,28 @@ pub fn spawn_scorer<T: ScorerBuilder + ?Sized>(
     cmd: &mut Commands,
     actor: Entity,
 ) -> Entity {
+    let shared_key = builder.shared_key().map(|key| (actor, key));
+    if let Some(key) = shared_key {
+        let existing = SHARED_SCORERS.with(|shared| {
+            let mut shared = shared.borrow_mut();
+            let shared = shared.as_mut()?;
+            let existing = shared.by_key.get(&key).copied()?;
+            shared.used.push(existing);
+            Some(existing)
+        });
+        if let Some(existing) = existing {
+            return existing;
+        }
+    }
     let scorer_ent = cmd.spawn_empty().id();
+    if let Some(key) = shared_key {
+        SHARED_SCORERS.with(|shared| {
+            if let Some(shared) = shared.borrow_mut().as_mut() {
+                shared.by_key.insert(key, scorer_ent);
+                shared.used.push(scorer_ent);
+            }
+        });
+    }
     let span = ScorerSpan::new(scorer_ent, ScorerBuilder::label(builder));
     let _guard = span.span().enter();
     debug!("New Scorer spawned.");
@@ -112,15 +294,487 @@ pub fn spawn_scorer<T: ScorerBuilder + ?Sized>(
         .insert(Name::new("Scorer"))
         .insert(Score::default())
         .insert(Actor(actor));
-    builder.build(cmd, scorer_ent, actor);
+    if SPAWNING_STANDALONE.with(Cell::get) {
+        cmd.entity(scorer_ent).insert(StandaloneScorer);
+    }
+    if let Some(_depth) = BuildDepthGuard::enter() {
+        builder.build(cmd, scorer_ent, actor);
+        builder.on_spawn(cmd, scorer_ent);
+    } else {
+        error!(
+            "Scorer nested too deeply. Is a ScorerBuilder including itself? Leaving it unbuilt."
+        );
+    }
     std::mem::drop(_guard);
     cmd.entity(scorer_ent).insert(span);
     scorer_ent
 }
 
+/// Marks a Scorer spawned with [`spawn_standalone_scorer`], along with any
+/// child Scorers it spawned. Standalone Scorers don't need their actor to
+/// have a Thinker, and only get cleaned up once the actor itself is
+/// despawned.
+#[derive(Clone, Copy, Component, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct StandaloneScorer;
+
+/// Spawns a Scorer for `actor` that isn't part of any Thinker, and returns its
+/// entity. Useful when you want a [`Score`] for your own UI or game logic
+/// without it driving any decisions.
+///
+/// Scorer systems, built-in and custom alike, work on any entity with their
+/// Scorer component, [`Actor`] and [`Score`], no matter how it was spawned, so
+/// a standalone Scorer keeps its `Score` up to date just like one inside a
+/// Thinker does, as long as its system is registered. Composite Scorers work
+/// too, and bring their children along.
+///
+/// The actor doesn't need a Thinker. The Scorer (and its children) are
+/// despawned along with the actor, or you can
+/// [`despawn_recursive`](DespawnRecursiveExt::despawn_recursive) it yourself
+/// whenever you're done with it.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # use big_brain::scorers::spawn_standalone_scorer;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct Hungry;
+/// # #[derive(Component)]
+/// # struct Villager;
+/// #[derive(Component)]
+/// struct HungerBar(Entity);
+///
+/// fn add_hunger_bar(mut cmd: Commands, villagers: Query<Entity, Added<Villager>>) {
+///     for actor in villagers.iter() {
+///         let scorer = spawn_standalone_scorer(&mut cmd, actor, &Hungry);
+///         cmd.entity(actor).insert(HungerBar(scorer));
+///     }
+/// }
+///
+/// fn show_hunger_bar(bars: Query<&HungerBar>, scores: Query<&Score>) {
+///     for HungerBar(scorer) in bars.iter() {
+///         if let Ok(score) = scores.get(*scorer) {
+///             println!("Hunger: {}", score.get());
+///         }
+///     }
+/// }
+/// ```
+pub fn spawn_standalone_scorer<T: ScorerBuilder + ?Sized>(
+    cmd: &mut Commands,
+    actor: Entity,
+    builder: &T,
+) -> Entity {
+    let was_standalone = SPAWNING_STANDALONE.with(|standalone| standalone.replace(true));
+    let scorer = spawn_scorer(builder, cmd, actor);
+    SPAWNING_STANDALONE.with(|standalone| standalone.set(was_standalone));
+    scorer
+}
+
+/// Composite Scorers with no children end up with a constant Score, which is
+/// almost certainly a mistake, so make some noise about it when they're built.
+fn warn_if_childless(builder: &dyn ScorerBuilder, children: usize) {
+    if children == 0 {
+        warn!(
+            "{} has no child Scorers, so its Score will never change. Did you forget to push() some?",
+            builder.label().unwrap_or("Composite Scorer")
+        );
+    }
+}
+
+/// Clamps a value from a user closure into a valid Score. `Score::set` panics
+/// on NaN, which `clamp` happily lets through, so that counts as 0.0.
+fn closure_score(value: f32) -> f32 {
+    if value.is_nan() {
+        0.0
+    } else {
+        crate::evaluators::clamp(value, 0.0, 1.0)
+    }
+}
+
+/// Looks up the current value of one of `actor`'s Scorers by its label (see
+/// [`ScorerBuilder::label`]). Handy for debug UIs that want to show why an
+/// actor is doing what it's doing. Only the Scorers of the actor's [`Thinker`]
+/// are searched, so standalone Scorers aren't found. If several of them share
+/// a label, the value of an arbitrary one of them is returned.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// fn show_curiosity(world: &World, actor: Entity) {
+///     if let Some(score) = big_brain::query_score(world, actor, "Curious") {
+///         info!("curiosity: {score:.1}");
+///     }
+/// }
+/// ```
+pub fn query_score(world: &World, actor: Entity, label: &str) -> Option<f32> {
+    let thinker = world.get::<Thinker>(world.get::<HasThinker>(actor)?.entity())?;
+    let mut pending: Vec<Entity> = thinker
+        .choices
+        .iter()
+        .rev()
+        .flat_map(|choice| choice.shared_scorers.iter().chain([&choice.scorer.0]))
+        .copied()
+        .collect();
+    while let Some(scorer) = pending.pop() {
+        let Ok(scorer) = world.get_entity(scorer) else {
+            continue;
+        };
+        if scorer.get::<ScorerSpan>().and_then(|span| span.label()) == Some(label) {
+            if let Some(score) = scorer.get::<Score>() {
+                return Some(score.get());
+            }
+        }
+        if let Some(children) = scorer.get::<Children>() {
+            pending.extend(children.iter().rev());
+        }
+    }
+    None
+}
+
+/// Turns a Scorer on or off without rebuilding its Thinker. Scorers are
+/// enabled by default.
+///
+/// While a Scorer is disabled, its [`Score`] reads as `0.0` no matter what its
+/// system sets it to, so Pickers skip its choice and Composite Scorers treat
+/// it as `0.0`. The value its system keeps setting is still kept, and shows
+/// up again as soon as it's re-enabled.
+///
+/// Scorers don't get one of these unless you insert it, and a Scorer without
+/// one is enabled. Removing it re-enables the Scorer. To toggle one of an
+/// actor's Scorers by label, see [`set_scorer_enabled`].
+#[derive(Clone, Copy, Component, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default)]
+pub struct Enabled(pub bool);
+
+impl Default for Enabled {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+/// Marks a Scorer whose [`Score`] isn't needed right now, because a lazy
+/// Picker (see [`LazyFirstToScore`](crate::pickers::LazyFirstToScore))
+/// already went with an earlier choice. Its Score is [unset](Score::unset)
+/// for as long as it's marked, and the mark is removed as soon as the Score
+/// is needed again.
+///
+/// The built-in Scorer systems and [`ScorerQuery`] leave these out, so Scorer
+/// systems built on it skip them for free. Other Scorer systems can filter
+/// them out with `Without<SkipScoring>`, or ignore the mark altogether.
+#[derive(Debug, Clone, Copy, Component, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct SkipScoring;
+
+/// Marks the Scorers [`evaluate_thinker_now`](crate::evaluate_thinker_now) is
+/// scoring, so that the [`ScoreNow`] schedule leaves every other actor's
+/// Scorers alone.
+#[derive(Component)]
+struct ScoringNow;
+
+type ScoringNowFilter = (With<ScoringNow>, Without<SkipScoring>);
+
+/// Schedule of built-in Scorer systems that only score the Scorers marked
+/// with [`ScoringNow`]. See [`score_actor_now`].
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+struct ScoreNow;
+
+/// Adds the [`ScoreNow`] schedule's systems. It leaves out the Scorers that
+/// keep track of time, like [`SustainedScorer`] and [`RateLimitedScorer`],
+/// which would count the extra run as time passing.
+pub(crate) fn add_score_now_systems(app: &mut App) {
+    app.add_systems(
+        ScoreNow,
+        (
+            (enabled_scorer_system, score_override_system).chain(),
+            (
+                fixed_score_system_filtered::<ScoringNowFilter>,
+                condition_scorer_system_filtered::<ScoringNowFilter>,
+            ),
+            (
+                measured_scorers_system_filtered::<ScoringNowFilter>,
+                all_or_nothing_system_filtered::<ScoringNowFilter>,
+                sum_of_scorers_system_filtered::<ScoringNowFilter>,
+                product_of_scorers_system_filtered::<ScoringNowFilter>,
+                winning_scorer_system_filtered::<ScoringNowFilter>,
+                nth_scorer_system_filtered::<ScoringNowFilter>,
+                evaluating_scorer_system_filtered::<ScoringNowFilter>,
+                margin_scorer_system_filtered::<ScoringNowFilter>,
+                dead_zone_scorer_system_filtered::<ScoringNowFilter>,
+                combine_scorer_system_filtered::<ScoringNowFilter>,
+            ),
+        )
+            .chain(),
+    );
+}
+
+/// Runs the built-in Scorer systems on `actor`'s Scorers, and only those.
+/// Worlds without [`BigBrainPlugin`](crate::BigBrainPlugin) don't have the
+/// systems, and keep the Scores as they are.
+pub(crate) fn score_actor_now(world: &mut World, actor: Entity) {
+    let scorers: Vec<Entity> = world
+        .query_filtered::<(Entity, &Actor), With<Score>>()
+        .iter(world)
+        .filter(|(_, Actor(owner))| *owner == actor)
+        .map(|(scorer, _)| scorer)
+        .collect();
+    for &scorer in &scorers {
+        world.entity_mut(scorer).insert(ScoringNow);
+    }
+    let _ = world.try_run_schedule(ScoreNow);
+    for scorer in scorers {
+        world.entity_mut(scorer).remove::<ScoringNow>();
+    }
+}
+
+/// Keeps [`Score`]s in sync with their Scorer's [`Enabled`] component. Runs
+/// in [`BigBrainSet::Scorers`](crate::BigBrainSet::Scorers) before the
+/// built-in Scorer systems, so Composite Scorers and Pickers see the change
+/// the same frame.
+pub fn enabled_scorer_system(
+    mut changed: Query<(&Enabled, &mut Score), Changed<Enabled>>,
+    mut removed: RemovedComponents<Enabled>,
+    mut scores: Query<&mut Score, Without<Enabled>>,
+) {
+    for (Enabled(enabled), mut score) in changed.iter_mut() {
+        score.disabled = !enabled;
+    }
+    for scorer in removed.read() {
+        if let Ok(mut score) = scores.get_mut(scorer) {
+            score.disabled = false;
+        }
+    }
+}
+
+/// Forces a Scorer's [`Score`] to a fixed value, no matter what its system
+/// computes. While this is `Some`, [`Score::get`] returns the override
+/// (clamped to `0.0..=1.0`) to Pickers and Composite Scorers alike. Set it
+/// back to `None` (or remove the component) to hand control back to the
+/// Scorer's system. A disabled Scorer (see [`Enabled`]) still reads as `0.0`.
+///
+/// Overrides aren't one-shot: one stays in effect, frame after frame, until
+/// it's cleared. Scorers don't get one of these unless you insert it. To
+/// override one of an actor's Scorers by label, see [`set_score_override`].
+#[derive(Clone, Copy, Component, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default)]
+pub struct ScoreOverride(pub Option<f32>);
+
+/// Applies [`ScoreOverride`]s to their Scorer's [`Score`]. Runs in
+/// [`BigBrainSet::Scorers`](crate::BigBrainSet::Scorers) before the built-in
+/// Scorer systems, so Composite Scorers and Pickers see the change the same
+/// frame.
+pub fn score_override_system(
+    mut changed: Query<(&ScoreOverride, &mut Score), Changed<ScoreOverride>>,
+    mut removed: RemovedComponents<ScoreOverride>,
+    mut scores: Query<&mut Score, Without<ScoreOverride>>,
+) {
+    for (ScoreOverride(value), mut score) in changed.iter_mut() {
+        score.overridden = value.map(|value| value.clamp(0.0, 1.0));
+    }
+    for scorer in removed.read() {
+        if let Ok(mut score) = scores.get_mut(scorer) {
+            score.overridden = None;
+        }
+    }
+}
+
+/// Sets or clears the [`ScoreOverride`] of every one of `actor`'s Scorers
+/// with the given label, taking effect right away. Returns whether any
+/// Scorers matched.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// #[derive(Event)]
+/// struct Cutscene(Entity);
+///
+/// fn force_panic(mut cmd: Commands, mut cutscenes: EventReader<Cutscene>) {
+///     for Cutscene(actor) in cutscenes.read() {
+///         let actor = *actor;
+///         cmd.queue(move |world: &mut World| {
+///             big_brain::set_score_override(world, actor, "Scared", Some(1.0));
+///         });
+///     }
+/// }
+/// ```
+pub fn set_score_override(
+    world: &mut World,
+    actor: Entity,
+    label: &str,
+    value: Option<f32>,
+) -> bool {
+    let matched = labeled_scorers(world, actor, label);
+    for scorer in matched.iter() {
+        let mut scorer = world.entity_mut(*scorer);
+        scorer.insert(ScoreOverride(value));
+        if let Some(mut score) = scorer.get_mut::<Score>() {
+            score.overridden = value.map(|value| value.clamp(0.0, 1.0));
+        }
+    }
+    !matched.is_empty()
+}
+
+/// Every one of `actor`'s Scorers with the given label.
+fn labeled_scorers(world: &mut World, actor: Entity, label: &str) -> Vec<Entity> {
+    world
+        .query::<(Entity, &Actor, &ScorerSpan)>()
+        .iter(world)
+        .filter(|(_, Actor(owner), span)| *owner == actor && span.label() == Some(label))
+        .map(|(scorer, _, _)| scorer)
+        .collect()
+}
+
+/// Enables or disables every one of `actor`'s Scorers with the given label
+/// (see [`ScorerBuilder::label`]), taking effect right away. Returns whether
+/// any Scorers matched. See [`Enabled`].
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// #[derive(Component)]
+/// struct Cornered;
+///
+/// fn no_fleeing_when_cornered(mut cmd: Commands, cornered: Query<Entity, Added<Cornered>>) {
+///     for actor in cornered.iter() {
+///         cmd.queue(move |world: &mut World| {
+///             big_brain::set_scorer_enabled(world, actor, "Scared", false);
+///         });
+///     }
+/// }
+/// ```
+pub fn set_scorer_enabled(world: &mut World, actor: Entity, label: &str, enabled: bool) -> bool {
+    let matched = labeled_scorers(world, actor, label);
+    for scorer in matched.iter() {
+        let mut scorer = world.entity_mut(*scorer);
+        scorer.insert(Enabled(enabled));
+        if let Some(mut score) = scorer.get_mut::<Score>() {
+            score.disabled = !enabled;
+        }
+    }
+    !matched.is_empty()
+}
+
+/// The query almost every Scorer system needs, packaged up as a
+/// [`SystemParam`]. Derefs to a [`Query`] over `(&Actor, &mut Score,
+/// &ScorerSpan)`, filtered to Scorers with a `T` component that aren't marked
+/// with [`SkipScoring`].
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// use big_brain::scorers::ScorerQuery;
+///
+/// #[derive(Component, Debug)]
+/// struct Thirst(f32);
+///
+/// #[derive(Clone, Component, Debug, ScorerBuilder)]
+/// struct Thirsty;
+///
+/// fn thirsty_scorer_system(thirsts: Query<&Thirst>, mut scorers: ScorerQuery<Thirsty>) {
+///     for (Actor(actor), mut score, span) in scorers.iter_mut() {
+///         let _guard = span.span().enter();
+///         if let Ok(thirst) = thirsts.get(*actor) {
+///             score.set(thirst.0);
+///         }
+///     }
+/// }
+/// # bevy::ecs::system::assert_is_system(thirsty_scorer_system);
+/// ```
+#[derive(SystemParam)]
+pub struct ScorerQuery<'w, 's, T: Component> {
+    query: Query<'w, 's, ScorerQueryData, ScorerQueryFilter<T>>,
+}
+
+type ScorerQueryData = (&'static Actor, &'static mut Score, &'static ScorerSpan);
+
+type ScorerQueryFilter<T> = (With<T>, Without<SkipScoring>);
+
+impl<'w, 's, T: Component> std::ops::Deref for ScorerQuery<'w, 's, T> {
+    type Target = Query<'w, 's, ScorerQueryData, ScorerQueryFilter<T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.query
+    }
+}
+
+impl<T: Component> std::ops::DerefMut for ScorerQuery<'_, '_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.query
+    }
+}
+
+/// Lets a Scorer system skip actors whose inputs haven't changed, for Scorers
+/// that are expensive to compute but depend on slowly-changing state. `F` is
+/// a query filter over the actor that says which of its components the
+/// Scorer depends on, like `Changed<Thirst>` or `Or<(Changed<Thirst>,
+/// Changed<Transform>)>`.
+///
+/// A skipped Scorer keeps the last value it [set](Score::set), so
+/// [`ScorerTrigger::should_score`] only needs to be checked before doing the
+/// expensive bit. Change detection is relative to the last time the system
+/// ran, and removing a component from the actor doesn't count as a change.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// use big_brain::scorers::{ScorerQuery, ScorerTrigger};
+///
+/// #[derive(Component, Debug)]
+/// struct Thirst(f32);
+///
+/// #[derive(Clone, Component, Debug, ScorerBuilder)]
+/// struct Thirsty;
+///
+/// fn thirsty_scorer_system(
+///     thirsts: Query<&Thirst>,
+///     mut scorers: ScorerQuery<Thirsty>,
+///     trigger: ScorerTrigger<Changed<Thirst>>,
+/// ) {
+///     for (Actor(actor), mut score, _span) in scorers.iter_mut() {
+///         if !trigger.should_score(*actor, &score) {
+///             continue;
+///         }
+///         if let Ok(thirst) = thirsts.get(*actor) {
+///             score.set(thirst.0);
+///         }
+///     }
+/// }
+/// # bevy::ecs::system::assert_is_system(thirsty_scorer_system);
+/// ```
+#[derive(SystemParam)]
+pub struct ScorerTrigger<'w, 's, F: QueryFilter + 'static> {
+    changed: Query<'w, 's, (), F>,
+}
+
+impl<F: QueryFilter + 'static> ScorerTrigger<'_, '_, F> {
+    /// Whether the Scorer with this `score` needs to be recomputed for
+    /// `actor`: either it's new since the system last ran, it's been
+    /// [unset](Score::unset) (say, by a lazy [`Picker`](crate::pickers::Picker)
+    /// that skipped it for a while), or the actor matches `F`.
+    pub fn should_score(
+        &self,
+        actor: Entity,
+        score: &(impl DetectChanges + Deref<Target = Score>),
+    ) -> bool {
+        score.is_added() || score.is_unset() || self.changed.contains(actor)
+    }
+}
+
 /// Scorer that always returns the same, fixed score. Good for combining with
 /// things creatively!
 #[derive(Clone, Component, Debug, Reflect)]
+#[reflect(Component)]
 pub struct FixedScore(pub f32);
 
 impl FixedScore {
@@ -129,7 +783,16 @@ impl FixedScore {
     }
 }
 
-pub fn fixed_score_system(mut query: Query<(&FixedScore, &mut Score, &ScorerSpan)>) {
+pub fn fixed_score_system(
+    query: Query<(&FixedScore, &mut Score, &ScorerSpan), Without<SkipScoring>>,
+) {
+    fixed_score_system_filtered(query);
+}
+
+/// Does the work of [`fixed_score_system`] for the Scorers matching `F`.
+fn fixed_score_system_filtered<F: QueryFilter + 'static>(
+    mut query: Query<(&FixedScore, &mut Score, &ScorerSpan), F>,
+) {
     for (FixedScore(fixed), mut score, _span) in query.iter_mut() {
         #[cfg(feature = "trace")]
         {
@@ -163,6 +826,121 @@ impl ScorerBuilder for FixedScorerBuilder {
     }
 }
 
+/// Scorer that computes its score from a [`Resource`], for world-level
+/// influences (alarm levels, time of day, weather...) that every actor
+/// should react to. The closure is run once per Scorer, every frame, and
+/// **must** return a value within `0.0..=1.0`, or the Scorer will panic.
+///
+/// Since the system for this Scorer is generic over the resource, you have
+/// to register [`resource_score_system`] for each resource type you use.
+/// If the resource doesn't exist, the Score is left as-is.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # use big_brain::scorers::resource_score_system;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct Hide;
+/// #[derive(Resource)]
+/// struct AlarmLevel(f32);
+///
+/// fn setup(mut cmd: Commands) {
+///     cmd.spawn(
+///         Thinker::build()
+///             .picker(FirstToScore::new(0.5))
+///             .when(ResourceScore::build(|alarm: &AlarmLevel| alarm.0), Hide),
+///     );
+/// }
+///
+/// # fn main() {
+/// App::new()
+///     .add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+///     .insert_resource(AlarmLevel(0.2))
+///     .add_systems(Startup, setup)
+///     .add_systems(
+///         PreUpdate,
+///         resource_score_system::<AlarmLevel>.in_set(BigBrainSet::Scorers),
+///     )
+/// # ;
+/// # }
+/// ```
+#[derive(Component)]
+pub struct ResourceScore<R: Resource> {
+    score_fn: Arc<dyn Fn(&R) -> f32 + Send + Sync>,
+}
+
+impl<R: Resource> std::fmt::Debug for ResourceScore<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResourceScore")
+            .field("resource", &std::any::type_name::<R>())
+            .finish()
+    }
+}
+
+impl<R: Resource> ResourceScore<R> {
+    pub fn build(score_fn: impl Fn(&R) -> f32 + Send + Sync + 'static) -> ResourceScoreBuilder<R> {
+        ResourceScoreBuilder {
+            score_fn: Arc::new(score_fn),
+            label: None,
+        }
+    }
+}
+
+/// System that updates any [`ResourceScore`]s for the resource `R`.
+pub fn resource_score_system<R: Resource>(
+    resource: Option<Res<R>>,
+    mut query: Query<(&ResourceScore<R>, &mut Score, &ScorerSpan), Without<SkipScoring>>,
+) {
+    let Some(resource) = resource else {
+        return;
+    };
+    for (ResourceScore { score_fn }, mut score, _span) in query.iter_mut() {
+        let value = score_fn(&resource);
+        #[cfg(feature = "trace")]
+        {
+            let _guard = _span.span().enter();
+            trace!("ResourceScore: {}", value);
+        }
+        score.set(value);
+    }
+}
+
+pub struct ResourceScoreBuilder<R: Resource> {
+    score_fn: Arc<dyn Fn(&R) -> f32 + Send + Sync>,
+    label: Option<String>,
+}
+
+impl<R: Resource> std::fmt::Debug for ResourceScoreBuilder<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResourceScoreBuilder")
+            .field("resource", &std::any::type_name::<R>())
+            .field("label", &self.label)
+            .finish()
+    }
+}
+
+impl<R: Resource> ResourceScoreBuilder<R> {
+    /// Set a label for this ScorerBuilder.
+    pub fn label(mut self, label: impl AsRef<str>) -> Self {
+        self.label = Some(label.as_ref().into());
+        self
+    }
+}
+
+impl<R: Resource> ScorerBuilder for ResourceScoreBuilder<R> {
+    fn build(&self, cmd: &mut Commands, scorer: Entity, _actor: Entity) {
+        cmd.entity(scorer).insert(ResourceScore {
+            score_fn: self.score_fn.clone(),
+        });
+    }
+
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref().or(Some("ResourceScore"))
+    }
+}
+
 /// Composite Scorer that takes any number of other Scorers and returns the
 /// sum of their [`Score`] values if each _individual_ [`Score`] is at or
 /// above the configured `threshold`.
@@ -189,11 +967,20 @@ impl ScorerBuilder for FixedScorerBuilder {
 /// # }
 /// ```
 #[derive(Component, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
 pub struct AllOrNothing {
     threshold: f32,
     scorers: Vec<Scorer>,
 }
 
+impl MapEntities for AllOrNothing {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        for scorer in self.scorers.iter_mut() {
+            scorer.map_entities(entity_mapper);
+        }
+    }
+}
+
 impl AllOrNothing {
     pub fn build(threshold: f32) -> AllOrNothingBuilder {
         AllOrNothingBuilder {
@@ -206,7 +993,15 @@ impl AllOrNothing {
 }
 
 pub fn all_or_nothing_system(
-    query: Query<(Entity, &AllOrNothing, &ScorerSpan)>,
+    query: Query<(Entity, &AllOrNothing, &ScorerSpan), Without<SkipScoring>>,
+    scores: Query<&mut Score>,
+) {
+    all_or_nothing_system_filtered(query, scores);
+}
+
+/// Does the work of [`all_or_nothing_system`] for the Scorers matching `F`.
+fn all_or_nothing_system_filtered<F: QueryFilter + 'static>(
+    query: Query<(Entity, &AllOrNothing, &ScorerSpan), F>,
     mut scores: Query<&mut Score>,
 ) {
     for (
@@ -221,11 +1016,11 @@ pub fn all_or_nothing_system(
         let mut sum = 0.0;
         for Scorer(child) in children.iter() {
             let score = scores.get_mut(*child).expect("where is it?");
-            if score.0 < *threshold {
+            if score.get() < *threshold {
                 sum = 0.0;
                 break;
             } else {
-                sum += score.0;
+                sum += score.get();
             }
         }
         let mut score = scores.get_mut(aon_ent).expect("where did it go?");
@@ -272,6 +1067,7 @@ impl ScorerBuilder for AllOrNothingBuilder {
     }
 
     fn build(&self, cmd: &mut Commands, scorer: Entity, actor: Entity) {
+        warn_if_childless(self, self.scorers.len());
         let scorers: Vec<_> = self
             .scorers
             .iter()
@@ -290,6 +1086,9 @@ impl ScorerBuilder for AllOrNothingBuilder {
 
 /// Composite Scorer that takes any number of other Scorers and returns the sum of their [`Score`] values if the _total_ summed [`Score`] is at or above the configured `threshold`.
 ///
+/// [Unset](Score::unset) child Scores are left out, including when averaging
+/// with `normalized`. If every child is unset, this Scorer is unset too.
+///
 /// ### Example
 ///
 /// ```
@@ -312,16 +1111,27 @@ impl ScorerBuilder for AllOrNothingBuilder {
 /// # }
 /// ```
 #[derive(Component, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
 pub struct SumOfScorers {
     threshold: f32,
+    normalized: bool,
     scorers: Vec<Scorer>,
     scorer_labels: Vec<String>,
 }
 
+impl MapEntities for SumOfScorers {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        for scorer in self.scorers.iter_mut() {
+            scorer.map_entities(entity_mapper);
+        }
+    }
+}
+
 impl SumOfScorers {
     pub fn build(threshold: f32) -> SumOfScorersBuilder {
         SumOfScorersBuilder {
             threshold,
+            normalized: false,
             scorers: Vec::new(),
             scorer_labels: Vec::new(),
             label: None,
@@ -330,13 +1140,22 @@ impl SumOfScorers {
 }
 
 pub fn sum_of_scorers_system(
-    query: Query<(Entity, &SumOfScorers, &ScorerSpan)>,
-    mut scores: Query<&mut Score>,
+    query: Query<(Entity, &SumOfScorers, &ScorerSpan), Without<SkipScoring>>,
+    scores: Query<&mut Score>,
 ) {
-    for (
+    sum_of_scorers_system_filtered(query, scores);
+}
+
+/// Does the work of [`sum_of_scorers_system`] for the Scorers matching `F`.
+fn sum_of_scorers_system_filtered<F: QueryFilter + 'static>(
+    query: Query<(Entity, &SumOfScorers, &ScorerSpan), F>,
+    mut scores: Query<&mut Score>,
+) {
+    for (
         sos_ent,
         SumOfScorers {
             threshold,
+            normalized,
             scorers: children,
             ..
         },
@@ -344,14 +1163,25 @@ pub fn sum_of_scorers_system(
     ) in query.iter()
     {
         let mut sum = 0.0;
+        let mut counted = 0;
         for Scorer(child) in children.iter() {
             let score = scores.get_mut(*child).expect("where is it?");
-            sum += score.0;
+            if !score.is_unset() {
+                sum += score.get();
+                counted += 1;
+            }
+        }
+        let mut score = scores.get_mut(sos_ent).expect("where did it go?");
+        if counted == 0 && !children.is_empty() {
+            score.unset();
+            continue;
+        }
+        if *normalized && counted > 0 {
+            sum /= counted as f32;
         }
         if sum < *threshold {
             sum = 0.0;
         }
-        let mut score = scores.get_mut(sos_ent).expect("where did it go?");
         score.set(crate::evaluators::clamp(sum, 0.0, 1.0));
         #[cfg(feature = "trace")]
         {
@@ -368,6 +1198,7 @@ pub fn sum_of_scorers_system(
 #[derive(Debug, Clone, Reflect)]
 pub struct SumOfScorersBuilder {
     threshold: f32,
+    normalized: bool,
     #[reflect(ignore)]
     scorers: Vec<Arc<dyn ScorerBuilder>>,
     scorer_labels: Vec<String>,
@@ -375,6 +1206,15 @@ pub struct SumOfScorersBuilder {
 }
 
 impl SumOfScorersBuilder {
+    /// Divide the sum by the number of child Scorers (that is, average them)
+    /// before comparing it to the threshold, instead of clamping the raw sum
+    /// to `1.0`. Without this, a few saturated children are enough to max
+    /// out the score, and you lose any resolution beyond that.
+    pub fn normalized(mut self, normalized: bool) -> Self {
+        self.normalized = normalized;
+        self
+    }
+
     /// Add a new Scorer to this [`SumOfScorersBuilder`].
     pub fn push(mut self, scorer: impl ScorerBuilder + 'static) -> Self {
         if let Some(label) = scorer.label() {
@@ -400,6 +1240,7 @@ impl ScorerBuilder for SumOfScorersBuilder {
 
     #[allow(clippy::needless_collect)]
     fn build(&self, cmd: &mut Commands, scorer: Entity, actor: Entity) {
+        warn_if_childless(self, self.scorers.len());
         let scorers: Vec<_> = self
             .scorers
             .iter()
@@ -409,6 +1250,7 @@ impl ScorerBuilder for SumOfScorersBuilder {
             .add_children(&scorers[..])
             .insert(SumOfScorers {
                 threshold: self.threshold,
+                normalized: self.normalized,
                 scorers: scorers.into_iter().map(Scorer).collect(),
                 scorer_labels: self.scorer_labels.clone(),
             });
@@ -447,6 +1289,7 @@ impl ScorerBuilder for SumOfScorersBuilder {
 /// ```
 
 #[derive(Component, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
 pub struct ProductOfScorers {
     threshold: f32,
     use_compensation: bool,
@@ -454,6 +1297,14 @@ pub struct ProductOfScorers {
     scorer_labels: Vec<String>,
 }
 
+impl MapEntities for ProductOfScorers {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        for scorer in self.scorers.iter_mut() {
+            scorer.map_entities(entity_mapper);
+        }
+    }
+}
+
 impl ProductOfScorers {
     pub fn build(threshold: f32) -> ProductOfScorersBuilder {
         ProductOfScorersBuilder {
@@ -467,7 +1318,15 @@ impl ProductOfScorers {
 }
 
 pub fn product_of_scorers_system(
-    query: Query<(Entity, &ProductOfScorers, &ScorerSpan)>,
+    query: Query<(Entity, &ProductOfScorers, &ScorerSpan), Without<SkipScoring>>,
+    scores: Query<&mut Score>,
+) {
+    product_of_scorers_system_filtered(query, scores);
+}
+
+/// Does the work of [`product_of_scorers_system`] for the Scorers matching `F`.
+fn product_of_scorers_system_filtered<F: QueryFilter + 'static>(
+    query: Query<(Entity, &ProductOfScorers, &ScorerSpan), F>,
     mut scores: Query<&mut Score>,
 ) {
     for (
@@ -486,7 +1345,7 @@ pub fn product_of_scorers_system(
 
         for Scorer(child) in children.iter() {
             let score = scores.get_mut(*child).expect("where is it?");
-            product *= score.0;
+            product *= score.get();
             num_scorers += 1;
         }
 
@@ -559,6 +1418,7 @@ impl ScorerBuilder for ProductOfScorersBuilder {
 
     #[allow(clippy::needless_collect)]
     fn build(&self, cmd: &mut Commands, scorer: Entity, actor: Entity) {
+        warn_if_childless(self, self.scorers.len());
         let scorers: Vec<_> = self
             .scorers
             .iter()
@@ -602,10 +1462,22 @@ impl ScorerBuilder for ProductOfScorersBuilder {
 /// ```
 
 #[derive(Component, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
 pub struct WinningScorer {
     threshold: f32,
     scorers: Vec<Scorer>,
     scorer_labels: Vec<String>,
+    tie_break: TieBreak,
+    winner: Option<Entity>,
+}
+
+impl MapEntities for WinningScorer {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        for scorer in self.scorers.iter_mut() {
+            scorer.map_entities(entity_mapper);
+        }
+        self.winner = self.winner.map(|winner| entity_mapper.map_entity(winner));
+    }
 }
 
 impl WinningScorer {
@@ -614,33 +1486,62 @@ impl WinningScorer {
             threshold,
             scorers: Vec::new(),
             scorer_labels: Vec::new(),
+            tie_break: TieBreak::default(),
             label: None,
         }
     }
+
+    /// The child Scorer entity that won the last time this Scorer was
+    /// evaluated, or `None` if none of them reached the threshold.
+    pub fn winner(&self) -> Option<Entity> {
+        self.winner
+    }
+}
+
+/// Decides which Scorer wins a [`WinningScorer`] when several of them share
+/// the highest [`Score`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+pub enum TieBreak {
+    /// The Scorer that was pushed first wins.
+    FirstPushed,
+    /// The Scorer that was pushed last wins.
+    #[default]
+    LastPushed,
 }
 
 pub fn winning_scorer_system(
-    mut query: Query<(Entity, &mut WinningScorer, &ScorerSpan)>,
+    query: Query<(Entity, &mut WinningScorer, &ScorerSpan), Without<SkipScoring>>,
+    scores: Query<&mut Score>,
+) {
+    winning_scorer_system_filtered(query, scores);
+}
+
+/// Does the work of [`winning_scorer_system`] for the Scorers matching `F`.
+fn winning_scorer_system_filtered<F: QueryFilter + 'static>(
+    mut query: Query<(Entity, &mut WinningScorer, &ScorerSpan), F>,
     mut scores: Query<&mut Score>,
 ) {
     for (sos_ent, mut winning_scorer, _span) in query.iter_mut() {
-        let (threshold, children) = (winning_scorer.threshold, &mut winning_scorer.scorers);
-        let mut all_scores = children
-            .iter()
-            .map(|Scorer(e)| scores.get(*e).expect("where is it?"))
-            .collect::<Vec<&Score>>();
-
-        all_scores.sort_by(|a, b| a.get().partial_cmp(&b.get()).unwrap_or(Ordering::Equal));
-        let winning_score_or_zero = match all_scores.last() {
-            Some(s) => {
-                if s.get() < threshold {
-                    0.0
-                } else {
-                    s.get()
-                }
+        let threshold = winning_scorer.threshold;
+        let tie_break = winning_scorer.tie_break;
+        let mut winner: Option<(Entity, f32)> = None;
+        for Scorer(child) in winning_scorer.scorers.iter() {
+            let child_score = scores.get(*child).expect("where is it?").get();
+            let wins = match winner {
+                None => true,
+                Some((_, best)) => match child_score.partial_cmp(&best) {
+                    Some(Ordering::Greater) => true,
+                    Some(Ordering::Equal) => tie_break == TieBreak::LastPushed,
+                    _ => false,
+                },
+            };
+            if wins {
+                winner = Some((*child, child_score));
             }
-            None => 0.0,
-        };
+        }
+        let winner = winner.filter(|(_, s)| *s >= threshold);
+        winning_scorer.winner = winner.map(|(ent, _)| ent);
+        let winning_score_or_zero = winner.map(|(_, s)| s).unwrap_or(0.0);
         let mut score = scores.get_mut(sos_ent).expect("where did it go?");
         score.set(crate::evaluators::clamp(winning_score_or_zero, 0.0, 1.0));
         #[cfg(feature = "trace")]
@@ -649,7 +1550,7 @@ pub fn winning_scorer_system(
             trace!(
                 "WinningScorer score: {}, from {} scores",
                 score.get(),
-                children.len()
+                winning_scorer.scorers.len()
             );
         }
     }
@@ -661,6 +1562,7 @@ pub struct WinningScorerBuilder {
     #[reflect(ignore)]
     scorers: Vec<Arc<dyn ScorerBuilder>>,
     scorer_labels: Vec<String>,
+    tie_break: TieBreak,
     label: Option<String>,
 }
 
@@ -676,6 +1578,13 @@ impl WinningScorerBuilder {
         self
     }
 
+    /// Choose which Scorer wins when several of them tie for the highest
+    /// [`Score`]. Defaults to [`TieBreak::LastPushed`].
+    pub fn tie_break(mut self, tie_break: TieBreak) -> Self {
+        self.tie_break = tie_break;
+        self
+    }
+
     /// Set a label for this Action.
     pub fn label(mut self, label: impl AsRef<str>) -> Self {
         self.label = Some(label.as_ref().into());
@@ -690,6 +1599,7 @@ impl ScorerBuilder for WinningScorerBuilder {
 
     #[allow(clippy::needless_collect)]
     fn build(&self, cmd: &mut Commands, scorer: Entity, actor: Entity) {
+        warn_if_childless(self, self.scorers.len());
         let scorers: Vec<_> = self
             .scorers
             .iter()
@@ -701,6 +1611,163 @@ impl ScorerBuilder for WinningScorerBuilder {
                 threshold: self.threshold,
                 scorers: scorers.into_iter().map(Scorer).collect(),
                 scorer_labels: self.scorer_labels.clone(),
+                tie_break: self.tie_break,
+                winner: None,
+            });
+    }
+}
+
+/// Composite Scorer that takes any number of other Scorers and returns the
+/// `n`-th highest [`Score`] among them (1-indexed, so `n = 1` is the same as
+/// a [`WinningScorer`]), if it's at or above the configured `threshold`.
+/// Returns 0 if that Score is below the threshold, or if there are fewer than
+/// `n` child Scorers.
+///
+/// Useful for picking a runner-up, like the second-most-threatening target,
+/// when the top choice is already being taken care of elsewhere.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct ThreatA;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct ThreatB;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct ThreatC;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct Engage;
+/// # fn main() {
+/// Thinker::build()
+///     .when(
+///         NthScorer::build(0.5, 2)
+///           .push(ThreatA)
+///           .push(ThreatB)
+///           .push(ThreatC),
+///         Engage)
+/// # ;
+/// # }
+/// ```
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct NthScorer {
+    threshold: f32,
+    n: usize,
+    scorers: Vec<Scorer>,
+    scorer_labels: Vec<String>,
+}
+
+impl MapEntities for NthScorer {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        for scorer in self.scorers.iter_mut() {
+            scorer.map_entities(entity_mapper);
+        }
+    }
+}
+
+impl NthScorer {
+    /// Builds an [`NthScorer`] returning the `n`-th highest child [`Score`].
+    /// `n` is 1-indexed, and an `n` of 0 is treated as 1.
+    pub fn build(threshold: f32, n: usize) -> NthScorerBuilder {
+        NthScorerBuilder {
+            threshold,
+            n: n.max(1),
+            scorers: Vec::new(),
+            scorer_labels: Vec::new(),
+            label: None,
+        }
+    }
+}
+
+pub fn nth_scorer_system(
+    query: Query<(Entity, &NthScorer, &ScorerSpan), Without<SkipScoring>>,
+    scores: Query<&mut Score>,
+) {
+    nth_scorer_system_filtered(query, scores);
+}
+
+/// Does the work of [`nth_scorer_system`] for the Scorers matching `F`.
+fn nth_scorer_system_filtered<F: QueryFilter + 'static>(
+    mut query: Query<(Entity, &NthScorer, &ScorerSpan), F>,
+    mut scores: Query<&mut Score>,
+) {
+    for (nth_ent, nth_scorer, _span) in query.iter_mut() {
+        let mut child_scores: Vec<f32> = nth_scorer
+            .scorers
+            .iter()
+            .map(|Scorer(child)| scores.get(*child).expect("where is it?").get())
+            .collect();
+        child_scores.sort_by(|a, b| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+        let nth_score_or_zero = child_scores
+            .get(nth_scorer.n - 1)
+            .copied()
+            .filter(|s| *s >= nth_scorer.threshold)
+            .unwrap_or(0.0);
+        let mut score = scores.get_mut(nth_ent).expect("where did it go?");
+        score.set(crate::evaluators::clamp(nth_score_or_zero, 0.0, 1.0));
+        #[cfg(feature = "trace")]
+        {
+            let _guard = _span.span().enter();
+            trace!(
+                "NthScorer score: {}, n: {}, from {} scores",
+                score.get(),
+                nth_scorer.n,
+                nth_scorer.scorers.len()
+            );
+        }
+    }
+}
+
+#[derive(Debug, Clone, Reflect)]
+pub struct NthScorerBuilder {
+    threshold: f32,
+    n: usize,
+    #[reflect(ignore)]
+    scorers: Vec<Arc<dyn ScorerBuilder>>,
+    scorer_labels: Vec<String>,
+    label: Option<String>,
+}
+
+impl NthScorerBuilder {
+    /// Add another Scorer to this [`NthScorerBuilder`].
+    pub fn push(mut self, scorer: impl ScorerBuilder + 'static) -> Self {
+        if let Some(label) = scorer.label() {
+            self.scorer_labels.push(label.into());
+        } else {
+            self.scorer_labels.push("Unnamed Scorer".into())
+        }
+        self.scorers.push(Arc::new(scorer));
+        self
+    }
+
+    /// Set a label for this Scorer.
+    pub fn label(mut self, label: impl AsRef<str>) -> Self {
+        self.label = Some(label.as_ref().into());
+        self
+    }
+}
+
+impl ScorerBuilder for NthScorerBuilder {
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref().or(Some("NthScorer"))
+    }
+
+    fn build(&self, cmd: &mut Commands, scorer: Entity, actor: Entity) {
+        warn_if_childless(self, self.scorers.len());
+        let scorers: Vec<_> = self
+            .scorers
+            .iter()
+            .map(|scorer| spawn_scorer(&**scorer, cmd, actor))
+            .collect();
+        cmd.entity(scorer)
+            .add_children(&scorers[..])
+            .insert(NthScorer {
+                threshold: self.threshold,
+                n: self.n,
+                scorers: scorers.into_iter().map(Scorer).collect(),
+                scorer_labels: self.scorer_labels.clone(),
             });
     }
 }
@@ -757,7 +1824,15 @@ impl EvaluatingScorer {
 }
 
 pub fn evaluating_scorer_system(
-    query: Query<(Entity, &EvaluatingScorer, &ScorerSpan)>,
+    query: Query<(Entity, &EvaluatingScorer, &ScorerSpan), Without<SkipScoring>>,
+    scores: Query<&mut Score>,
+) {
+    evaluating_scorer_system_filtered(query, scores);
+}
+
+/// Does the work of [`evaluating_scorer_system`] for the Scorers matching `F`.
+fn evaluating_scorer_system_filtered<F: QueryFilter + 'static>(
+    query: Query<(Entity, &EvaluatingScorer, &ScorerSpan), F>,
     mut scores: Query<&mut Score>,
 ) {
     for (sos_ent, eval_scorer, _span) in query.iter() {
@@ -814,114 +1889,84 @@ impl ScorerBuilder for EvaluatingScorerBuilder {
     }
 }
 
-/// Composite Scorer that allows more fine-grained control of how the scores
-/// are combined. The default is to apply a weighting
+/// Composite Scorer that reports how far its child's [`Score`] is above
+/// `threshold`, rather than the raw value. The score is `0.0` at (or below)
+/// the threshold, ramping up linearly to `1.0` when the child's score is
+/// `1.0`. Useful for gauging how urgently something needs doing, on top of
+/// whether it needs doing at all.
 ///
 /// ### Example
 ///
-/// Using the default measure ([`WeightedMeasure`]):
-///
-/// ```
-/// # use bevy::prelude::*;
-/// # use big_brain::prelude::*;
-/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
-/// # struct MyScorer;
-/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
-/// # struct MyOtherScorer;
-/// # #[derive(Debug, Clone, Component, ActionBuilder)]
-/// # struct MyAction;
-/// # fn main() {
-/// Thinker::build()
-///     .when(
-///         MeasuredScorer::build(0.5)
-///           .push(MyScorer, 0.9)
-///           .push(MyOtherScorer, 0.4),
-///         MyAction)
-/// # ;
-/// # }
-/// ```
-///
-/// Customising the measure:
-///
 /// ```
 /// # use bevy::prelude::*;
 /// # use big_brain::prelude::*;
 /// # #[derive(Debug, Clone, Component, ScorerBuilder)]
-/// # struct MyScorer;
-/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
-/// # struct MyOtherScorer;
+/// # struct Hungry;
 /// # #[derive(Debug, Clone, Component, ActionBuilder)]
-/// # struct MyAction;
+/// # struct Eat;
 /// # fn main() {
 /// Thinker::build()
-///     .when(
-///         MeasuredScorer::build(0.5)
-///           .measure(ChebyshevDistance)
-///           .push(MyScorer, 0.8)
-///           .push(MyOtherScorer, 0.2),
-///         MyAction)
+///     .picker(Highest)
+///     .when(MarginScorer::build(0.6, Hungry), Eat)
 /// # ;
 /// # }
 /// ```
-
 #[derive(Component, Debug, Reflect)]
-#[reflect(from_reflect = false)]
-pub struct MeasuredScorer {
+#[reflect(Component, MapEntities)]
+pub struct MarginScorer {
     threshold: f32,
-    #[reflect(ignore)]
-    measure: Arc<dyn Measure>,
-    measure_string: String,
-    scorers: Vec<(Scorer, f32)>,
+    scorer: Scorer,
 }
 
-impl MeasuredScorer {
-    pub fn build(threshold: f32) -> MeasuredScorerBuilder {
-        MeasuredScorerBuilder {
+impl MapEntities for MarginScorer {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.scorer.map_entities(entity_mapper);
+    }
+}
+
+impl MarginScorer {
+    pub fn build(threshold: f32, scorer: impl ScorerBuilder + 'static) -> MarginScorerBuilder {
+        MarginScorerBuilder {
             threshold,
-            measure: Arc::new(WeightedMeasure),
-            measure_string: format!("{WeightedMeasure:#?}"),
-            scorers: Vec::new(),
-            scorer_labels: Vec::new(),
+            scorer_label: scorer.label().map(|s| s.into()),
+            scorer: Arc::new(scorer),
             label: None,
         }
     }
 }
 
-pub fn measured_scorers_system(
-    query: Query<(Entity, &MeasuredScorer, &ScorerSpan)>,
-    mut scores: Query<&mut Score>,
+pub fn margin_scorer_system(
+    query: Query<(Entity, &MarginScorer, &ScorerSpan), Without<SkipScoring>>,
+    scores: Query<&mut Score>,
 ) {
-    for (
-        sos_ent,
-        MeasuredScorer {
-            threshold,
-            measure,
-            scorers: children,
-            ..
-        },
-        _span,
-    ) in query.iter()
-    {
-        let measured_score = measure.calculate(
-            children
-                .iter()
-                .map(|(scorer, weight)| (scores.get(scorer.0).expect("where is it?"), *weight))
-                .collect::<Vec<_>>(),
-        );
-        let mut score = scores.get_mut(sos_ent).expect("where did it go?");
+    margin_scorer_system_filtered(query, scores);
+}
 
-        if measured_score < *threshold {
-            score.set(0.0);
+/// Does the work of [`margin_scorer_system`] for the Scorers matching `F`.
+fn margin_scorer_system_filtered<F: QueryFilter + 'static>(
+    query: Query<(Entity, &MarginScorer, &ScorerSpan), F>,
+    mut scores: Query<&mut Score>,
+) {
+    for (margin_ent, MarginScorer { threshold, scorer }, _span) in query.iter() {
+        let inner_score = scores.get(scorer.0).expect("where did it go?").get();
+        let margin = if *threshold >= 1.0 {
+            if inner_score >= 1.0 {
+                1.0
+            } else {
+                0.0
+            }
         } else {
-            score.set(measured_score.clamp(0.0, 1.0));
-        }
+            (inner_score - threshold) / (1.0 - threshold)
+        };
+        let mut score = scores.get_mut(margin_ent).expect("where did it go?");
+        score.set(crate::evaluators::clamp(margin, 0.0, 1.0));
         #[cfg(feature = "trace")]
         {
             let _guard = _span.span().enter();
             trace!(
-                "MeasuredScorer score: {}, from {} scores",
+                "MarginScorer score: {}, from score: {}",
                 score.get(),
-                children.len()
+                inner_score
             );
         }
     }
@@ -929,35 +1974,15 @@ pub fn measured_scorers_system(
 
 #[derive(Debug, Reflect)]
 #[reflect(from_reflect = false)]
-pub struct MeasuredScorerBuilder {
+pub struct MarginScorerBuilder {
     threshold: f32,
     #[reflect(ignore)]
-    measure: Arc<dyn Measure>,
-    measure_string: String,
-    #[reflect(ignore)]
-    scorers: Vec<(Arc<dyn ScorerBuilder>, f32)>,
-    scorer_labels: Vec<String>,
+    scorer: Arc<dyn ScorerBuilder>,
+    scorer_label: Option<String>,
     label: Option<String>,
 }
 
-impl MeasuredScorerBuilder {
-    /// Sets the measure to be used to combine the child scorers
-    pub fn measure(mut self, measure: impl Measure + 'static) -> Self {
-        self.measure_string = format!("{measure:#?}");
-        self.measure = Arc::new(measure);
-        self
-    }
-
-    pub fn push(mut self, scorer: impl ScorerBuilder + 'static, weight: f32) -> Self {
-        if let Some(label) = scorer.label() {
-            self.scorer_labels.push(label.into());
-        } else {
-            self.scorer_labels.push("Unnamed Scorer".into());
-        }
-        self.scorers.push((Arc::new(scorer), weight));
-        self
-    }
-
+impl MarginScorerBuilder {
     /// Set a label for this ScorerBuilder.
     pub fn label(mut self, label: impl AsRef<str>) -> Self {
         self.label = Some(label.as_ref().into());
@@ -965,15 +1990,1189 @@ impl MeasuredScorerBuilder {
     }
 }
 
-impl ScorerBuilder for MeasuredScorerBuilder {
+impl ScorerBuilder for MarginScorerBuilder {
     fn label(&self) -> Option<&str> {
-        self.label.as_deref().or(Some("MeasuredScorer"))
+        self.label.as_deref().or(Some("MarginScorer"))
     }
 
-    #[allow(clippy::needless_collect)]
     fn build(&self, cmd: &mut Commands, scorer: Entity, actor: Entity) {
-        let scorers: Vec<_> = self
-            .scorers
+        let inner_scorer = spawn_scorer(&*self.scorer, cmd, actor);
+        cmd.entity(scorer)
+            .add_children(&[inner_scorer])
+            .insert(MarginScorer {
+                threshold: self.threshold,
+                scorer: Scorer(inner_scorer),
+            });
+    }
+}
+
+/// Composite Scorer that only scores once its child's [`Score`] has stayed at
+/// or above `threshold` for a while, so momentary spikes don't cause knee-jerk
+/// reactions. While the child is below the threshold, this scores `0.0`.
+///
+/// By default, the score ramps up from `0.0` to the child's score over
+/// `duration`. With [`SustainedScorerBuilder::ramp`] turned off, it stays at
+/// `0.0` until `duration` has passed, and then jumps straight to the child's
+/// score. Like [`ActionAge`](crate::actions::ActionAge), this follows Bevy's
+/// [`Time`], so pausing the game pauses the clock.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::utils::Duration;
+/// # use big_brain::prelude::*;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct Bored;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct Wander;
+/// # fn main() {
+/// Thinker::build()
+///     .picker(FirstToScore::new(0.5))
+///     .when(
+///         SustainedScorer::build(0.7, Duration::from_secs(3), Bored),
+///         Wander,
+///     )
+/// # ;
+/// # }
+/// ```
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct SustainedScorer {
+    threshold: f32,
+    duration: Duration,
+    ramp: bool,
+    scorer: Scorer,
+    sustained_since: Option<Duration>,
+}
+
+impl MapEntities for SustainedScorer {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.scorer.map_entities(entity_mapper);
+    }
+}
+
+impl SustainedScorer {
+    pub fn build(
+        threshold: f32,
+        duration: Duration,
+        scorer: impl ScorerBuilder + 'static,
+    ) -> SustainedScorerBuilder {
+        SustainedScorerBuilder {
+            threshold,
+            duration,
+            ramp: true,
+            scorer_label: scorer.label().map(|s| s.into()),
+            scorer: Arc::new(scorer),
+            label: None,
+        }
+    }
+
+    /// How long the child's score has been at or above the threshold, if it
+    /// currently is.
+    pub fn sustained_for(&self, time: &Time) -> Option<Duration> {
+        self.sustained_since
+            .map(|since| time.elapsed().saturating_sub(since))
+    }
+}
+
+pub fn sustained_scorer_system(
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut SustainedScorer, &ScorerSpan), Without<SkipScoring>>,
+    mut scores: Query<&mut Score>,
+) {
+    let now = time.elapsed();
+    for (sustained_ent, mut sustained, _span) in query.iter_mut() {
+        let inner_score = scores
+            .get(sustained.scorer.0)
+            .expect("where did it go?")
+            .get();
+        let value = if inner_score >= sustained.threshold {
+            let since = *sustained.sustained_since.get_or_insert(now);
+            let elapsed = now.saturating_sub(since);
+            if elapsed >= sustained.duration {
+                inner_score
+            } else if sustained.ramp {
+                inner_score * elapsed.as_secs_f32() / sustained.duration.as_secs_f32()
+            } else {
+                0.0
+            }
+        } else {
+            sustained.sustained_since = None;
+            0.0
+        };
+        let mut score = scores.get_mut(sustained_ent).expect("where did it go?");
+        score.set(crate::evaluators::clamp(value, 0.0, 1.0));
+        #[cfg(feature = "trace")]
+        {
+            let _guard = _span.span().enter();
+            trace!(
+                "SustainedScorer score: {}, from score: {}",
+                score.get(),
+                inner_score
+            );
+        }
+    }
+}
+
+#[derive(Debug, Reflect)]
+#[reflect(from_reflect = false)]
+pub struct SustainedScorerBuilder {
+    threshold: f32,
+    duration: Duration,
+    ramp: bool,
+    #[reflect(ignore)]
+    scorer: Arc<dyn ScorerBuilder>,
+    scorer_label: Option<String>,
+    label: Option<String>,
+}
+
+impl SustainedScorerBuilder {
+    /// Whether to ramp the score up over the required duration, rather than
+    /// jumping to the child's score once it's been met. Defaults to `true`.
+    pub fn ramp(mut self, ramp: bool) -> Self {
+        self.ramp = ramp;
+        self
+    }
+
+    /// Set a label for this ScorerBuilder.
+    pub fn label(mut self, label: impl AsRef<str>) -> Self {
+        self.label = Some(label.as_ref().into());
+        self
+    }
+}
+
+impl ScorerBuilder for SustainedScorerBuilder {
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref().or(Some("SustainedScorer"))
+    }
+
+    fn build(&self, cmd: &mut Commands, scorer: Entity, actor: Entity) {
+        let inner_scorer = spawn_scorer(&*self.scorer, cmd, actor);
+        cmd.entity(scorer)
+            .add_children(&[inner_scorer])
+            .insert(SustainedScorer {
+                threshold: self.threshold,
+                duration: self.duration,
+                ramp: self.ramp,
+                scorer: Scorer(inner_scorer),
+                sustained_since: None,
+            });
+    }
+}
+
+/// Composite Scorer that eases toward its child's [`Score`] at no more than
+/// `max_delta_per_sec` per second, instead of jumping straight to it. This
+/// makes a behavior's appeal rise (and fall) smoothly at the same pace no
+/// matter the frame rate, even when the child jumps around. It starts out at
+/// `0.0`.
+///
+/// Unlike exponential smoothing, the rate is fixed: a score going from `0.0`
+/// to `1.0` with a `max_delta_per_sec` of `0.5` takes exactly two seconds.
+///
+/// ### Panics
+///
+/// Panics if `max_delta_per_sec` is negative or NaN.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct Curious;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct Investigate;
+/// # fn main() {
+/// Thinker::build()
+///     .picker(FirstToScore::new(0.5))
+///     .when(RateLimitedScorer::build(0.25, Curious), Investigate)
+/// # ;
+/// # }
+/// ```
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct RateLimitedScorer {
+    max_delta_per_sec: f32,
+    scorer: Scorer,
+    value: f32,
+}
+
+impl MapEntities for RateLimitedScorer {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.scorer.map_entities(entity_mapper);
+    }
+}
+
+impl RateLimitedScorer {
+    pub fn build(
+        max_delta_per_sec: f32,
+        scorer: impl ScorerBuilder + 'static,
+    ) -> RateLimitedScorerBuilder {
+        if max_delta_per_sec.is_nan() || max_delta_per_sec < 0.0 {
+            panic!(
+                "RateLimitedScorer's max_delta_per_sec must be zero or more, but was {}",
+                max_delta_per_sec
+            );
+        }
+        RateLimitedScorerBuilder {
+            max_delta_per_sec,
+            scorer_label: scorer.label().map(|s| s.into()),
+            scorer: Arc::new(scorer),
+            label: None,
+        }
+    }
+}
+
+pub fn rate_limited_scorer_system(
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut RateLimitedScorer, &ScorerSpan), Without<SkipScoring>>,
+    mut scores: Query<&mut Score>,
+) {
+    for (limited_ent, mut limited, _span) in query.iter_mut() {
+        let inner_score = scores
+            .get(limited.scorer.0)
+            .expect("where did it go?")
+            .get();
+        let max_step = limited.max_delta_per_sec * time.delta_secs();
+        limited.value += (inner_score - limited.value).clamp(-max_step, max_step);
+        let mut score = scores.get_mut(limited_ent).expect("where did it go?");
+        score.set(crate::evaluators::clamp(limited.value, 0.0, 1.0));
+        #[cfg(feature = "trace")]
+        {
+            let _guard = _span.span().enter();
+            trace!(
+                "RateLimitedScorer score: {}, from score: {}",
+                score.get(),
+                inner_score
+            );
+        }
+    }
+}
+
+#[derive(Debug, Reflect)]
+#[reflect(from_reflect = false)]
+pub struct RateLimitedScorerBuilder {
+    max_delta_per_sec: f32,
+    #[reflect(ignore)]
+    scorer: Arc<dyn ScorerBuilder>,
+    scorer_label: Option<String>,
+    label: Option<String>,
+}
+
+impl RateLimitedScorerBuilder {
+    /// Set a label for this ScorerBuilder.
+    pub fn label(mut self, label: impl AsRef<str>) -> Self {
+        self.label = Some(label.as_ref().into());
+        self
+    }
+}
+
+impl ScorerBuilder for RateLimitedScorerBuilder {
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref().or(Some("RateLimitedScorer"))
+    }
+
+    fn build(&self, cmd: &mut Commands, scorer: Entity, actor: Entity) {
+        let inner_scorer = spawn_scorer(&*self.scorer, cmd, actor);
+        cmd.entity(scorer)
+            .add_children(&[inner_scorer])
+            .insert(RateLimitedScorer {
+                max_delta_per_sec: self.max_delta_per_sec,
+                scorer: Scorer(inner_scorer),
+                value: 0.0,
+            });
+    }
+}
+
+/// Composite Scorer that adds hysteresis to its child's [`Score`], like a
+/// Schmitt trigger. It switches on once the child's score rises above `high`,
+/// and only switches back off once it drops below `low`. While on, it scores
+/// whatever the child scores, and while off, it scores `0.0`. In between
+/// `low` and `high`, it keeps doing whatever it was doing, so a score that
+/// hovers right around a Picker's threshold doesn't make the Thinker flip
+/// back and forth. It starts out off.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct Tired;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct Sleep;
+/// # fn main() {
+/// Thinker::build()
+///     .picker(FirstToScore::new(0.5))
+///     .when(DeadZoneScorer::build(0.4, 0.6, Tired), Sleep)
+/// # ;
+/// # }
+/// ```
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct DeadZoneScorer {
+    low: f32,
+    high: f32,
+    scorer: Scorer,
+    on: bool,
+}
+
+impl MapEntities for DeadZoneScorer {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.scorer.map_entities(entity_mapper);
+    }
+}
+
+impl DeadZoneScorer {
+    pub fn build(
+        low: f32,
+        high: f32,
+        scorer: impl ScorerBuilder + 'static,
+    ) -> DeadZoneScorerBuilder {
+        DeadZoneScorerBuilder {
+            low,
+            high,
+            scorer_label: scorer.label().map(|s| s.into()),
+            scorer: Arc::new(scorer),
+            label: None,
+        }
+    }
+
+    /// Whether the child's score last crossed above `high` (rather than below
+    /// `low`), meaning this is passing it through.
+    pub fn is_on(&self) -> bool {
+        self.on
+    }
+}
+
+pub fn dead_zone_scorer_system(
+    query: Query<(Entity, &mut DeadZoneScorer, &ScorerSpan), Without<SkipScoring>>,
+    scores: Query<&mut Score>,
+) {
+    dead_zone_scorer_system_filtered(query, scores);
+}
+
+/// Does the work of [`dead_zone_scorer_system`] for the Scorers matching `F`.
+fn dead_zone_scorer_system_filtered<F: QueryFilter + 'static>(
+    mut query: Query<(Entity, &mut DeadZoneScorer, &ScorerSpan), F>,
+    mut scores: Query<&mut Score>,
+) {
+    for (dead_zone_ent, mut dead_zone, _span) in query.iter_mut() {
+        let inner_score = scores
+            .get(dead_zone.scorer.0)
+            .expect("where did it go?")
+            .get();
+        if inner_score > dead_zone.high {
+            dead_zone.on = true;
+        } else if inner_score < dead_zone.low {
+            dead_zone.on = false;
+        }
+        let mut score = scores.get_mut(dead_zone_ent).expect("where did it go?");
+        score.set(if dead_zone.on {
+            crate::evaluators::clamp(inner_score, 0.0, 1.0)
+        } else {
+            0.0
+        });
+        #[cfg(feature = "trace")]
+        {
+            let _guard = _span.span().enter();
+            trace!(
+                "DeadZoneScorer score: {}, from score: {}",
+                score.get(),
+                inner_score
+            );
+        }
+    }
+}
+
+#[derive(Debug, Reflect)]
+#[reflect(from_reflect = false)]
+pub struct DeadZoneScorerBuilder {
+    low: f32,
+    high: f32,
+    #[reflect(ignore)]
+    scorer: Arc<dyn ScorerBuilder>,
+    scorer_label: Option<String>,
+    label: Option<String>,
+}
+
+impl DeadZoneScorerBuilder {
+    /// Set a label for this ScorerBuilder.
+    pub fn label(mut self, label: impl AsRef<str>) -> Self {
+        self.label = Some(label.as_ref().into());
+        self
+    }
+}
+
+impl ScorerBuilder for DeadZoneScorerBuilder {
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref().or(Some("DeadZoneScorer"))
+    }
+
+    fn build(&self, cmd: &mut Commands, scorer: Entity, actor: Entity) {
+        let inner_scorer = spawn_scorer(&*self.scorer, cmd, actor);
+        cmd.entity(scorer)
+            .add_children(&[inner_scorer])
+            .insert(DeadZoneScorer {
+                low: self.low,
+                high: self.high,
+                scorer: Scorer(inner_scorer),
+                on: false,
+            });
+    }
+}
+
+/// Composite Scorer that scales its child's [`Score`] by a curve over time,
+/// where "time" is whatever your clock resource `R` says it is. This is
+/// useful for behaviors that should be more or less appealing depending on
+/// the time of day, a season, or any other global value that changes over
+/// time.
+///
+/// The final score is the child's score multiplied by `curve.evaluate(time)`,
+/// clamped to `0.0..=1.0`. [`CurveEvaluator`](crate::evaluators::CurveEvaluator)
+/// is a good fit for the curve, but any [`Evaluator`] works.
+///
+/// Just like [`ResourceScore`], you have to register
+/// [`scheduled_scorer_system`] for each clock resource you use.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # use big_brain::scorers::scheduled_scorer_system;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct Tired;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct Sleep;
+/// #[derive(Resource)]
+/// struct Clock {
+///     hour: f32,
+/// }
+///
+/// fn setup(mut cmd: Commands) {
+///     // Sleeping is much more appealing at night.
+///     let night = CurveEvaluator::new([
+///         (6.0, 1.0),
+///         (8.0, 0.2),
+///         (20.0, 0.2),
+///         (22.0, 1.0),
+///     ]);
+///     cmd.spawn(
+///         Thinker::build().picker(Highest).when(
+///             ScheduledScorer::build(Tired, |clock: &Clock| clock.hour, night),
+///             Sleep,
+///         ),
+///     );
+/// }
+///
+/// # fn main() {
+/// App::new()
+///     .add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+///     .insert_resource(Clock { hour: 12.0 })
+///     .add_systems(Startup, setup)
+///     .add_systems(
+///         PreUpdate,
+///         scheduled_scorer_system::<Clock>.in_set(BigBrainSet::Scorers),
+///     )
+/// # ;
+/// # }
+/// ```
+#[derive(Component)]
+pub struct ScheduledScorer<R: Resource> {
+    scorer: Scorer,
+    time_fn: Arc<dyn Fn(&R) -> f32 + Send + Sync>,
+    curve: Arc<dyn Evaluator>,
+}
+
+impl<R: Resource> std::fmt::Debug for ScheduledScorer<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScheduledScorer")
+            .field("scorer", &self.scorer)
+            .field("clock", &std::any::type_name::<R>())
+            .field("curve", &self.curve)
+            .finish()
+    }
+}
+
+impl<R: Resource> ScheduledScorer<R> {
+    pub fn build(
+        scorer: impl ScorerBuilder + 'static,
+        time_fn: impl Fn(&R) -> f32 + Send + Sync + 'static,
+        curve: impl Evaluator + 'static,
+    ) -> ScheduledScorerBuilder<R> {
+        ScheduledScorerBuilder {
+            scorer: Arc::new(scorer),
+            time_fn: Arc::new(time_fn),
+            curve: Arc::new(curve),
+            label: None,
+        }
+    }
+}
+
+/// System that updates any [`ScheduledScorer`]s using the clock resource `R`.
+pub fn scheduled_scorer_system<R: Resource>(
+    clock: Option<Res<R>>,
+    query: Query<(Entity, &ScheduledScorer<R>, &ScorerSpan), Without<SkipScoring>>,
+    mut scores: Query<&mut Score>,
+) {
+    let Some(clock) = clock else {
+        return;
+    };
+    for (sched_ent, scheduled, _span) in query.iter() {
+        let inner_score = scores
+            .get(scheduled.scorer.0)
+            .expect("where did it go?")
+            .get();
+        let time = (scheduled.time_fn)(&clock);
+        let mut score = scores.get_mut(sched_ent).expect("where did it go?");
+        score.set(crate::evaluators::clamp(
+            inner_score * scheduled.curve.evaluate(time),
+            0.0,
+            1.0,
+        ));
+        #[cfg(feature = "trace")]
+        {
+            let _guard = _span.span().enter();
+            trace!(
+                "ScheduledScorer score: {}, from score: {} at time: {}",
+                score.get(),
+                inner_score,
+                time
+            );
+        }
+    }
+}
+
+pub struct ScheduledScorerBuilder<R: Resource> {
+    scorer: Arc<dyn ScorerBuilder>,
+    time_fn: Arc<dyn Fn(&R) -> f32 + Send + Sync>,
+    curve: Arc<dyn Evaluator>,
+    label: Option<String>,
+}
+
+impl<R: Resource> std::fmt::Debug for ScheduledScorerBuilder<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScheduledScorerBuilder")
+            .field("scorer", &self.scorer)
+            .field("clock", &std::any::type_name::<R>())
+            .field("curve", &self.curve)
+            .field("label", &self.label)
+            .finish()
+    }
+}
+
+impl<R: Resource> ScheduledScorerBuilder<R> {
+    /// Set a label for this ScorerBuilder.
+    pub fn label(mut self, label: impl AsRef<str>) -> Self {
+        self.label = Some(label.as_ref().into());
+        self
+    }
+}
+
+impl<R: Resource> ScorerBuilder for ScheduledScorerBuilder<R> {
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref().or(Some("ScheduledScorer"))
+    }
+
+    fn build(&self, cmd: &mut Commands, scorer: Entity, actor: Entity) {
+        let inner_scorer = spawn_scorer(&*self.scorer, cmd, actor);
+        cmd.entity(scorer)
+            .add_children(&[inner_scorer])
+            .insert(ScheduledScorer {
+                scorer: Scorer(inner_scorer),
+                time_fn: self.time_fn.clone(),
+                curve: self.curve.clone(),
+            });
+    }
+}
+
+/// Composite Scorer that passes its child's [`Score`] through only while the
+/// actor has a `C` component, and scores `0.0` otherwise. Saves custom
+/// Scorers from having to check for things like `HasWeapon` themselves.
+///
+/// Just like [`ResourceScore`], you have to register
+/// [`requires_component_system`] for each component type you use.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # use big_brain::scorers::requires_component_system;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct EnemyInRange;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct Shoot;
+/// #[derive(Component)]
+/// struct HasWeapon;
+///
+/// fn setup(mut cmd: Commands) {
+///     cmd.spawn(
+///         Thinker::build()
+///             .picker(FirstToScore::new(0.5))
+///             .when(RequiresComponent::<HasWeapon>::build(EnemyInRange), Shoot),
+///     );
+/// }
+///
+/// # fn main() {
+/// App::new()
+///     .add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+///     .add_systems(Startup, setup)
+///     .add_systems(
+///         PreUpdate,
+///         requires_component_system::<HasWeapon>.in_set(BigBrainSet::Scorers),
+///     )
+/// # ;
+/// # }
+/// ```
+#[derive(Component)]
+pub struct RequiresComponent<C: Component> {
+    scorer: Scorer,
+    component: PhantomData<fn() -> C>,
+}
+
+impl<C: Component> std::fmt::Debug for RequiresComponent<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequiresComponent")
+            .field("scorer", &self.scorer)
+            .field("component", &std::any::type_name::<C>())
+            .finish()
+    }
+}
+
+impl<C: Component> RequiresComponent<C> {
+    pub fn build(scorer: impl ScorerBuilder + 'static) -> RequiresComponentBuilder<C> {
+        RequiresComponentBuilder {
+            scorer: Arc::new(scorer),
+            component: PhantomData,
+            label: None,
+        }
+    }
+}
+
+/// System that updates any [`RequiresComponent`]s for the component `C`.
+pub fn requires_component_system<C: Component>(
+    query: Query<(Entity, &Actor, &RequiresComponent<C>, &ScorerSpan), Without<SkipScoring>>,
+    actors: Query<(), With<C>>,
+    mut scores: Query<&mut Score>,
+) {
+    for (requires_ent, Actor(actor), requires, _span) in query.iter() {
+        let inner_score = scores
+            .get(requires.scorer.0)
+            .expect("where did it go?")
+            .get();
+        let has_component = actors.contains(*actor);
+        let mut score = scores.get_mut(requires_ent).expect("where did it go?");
+        score.set(if has_component {
+            crate::evaluators::clamp(inner_score, 0.0, 1.0)
+        } else {
+            0.0
+        });
+        #[cfg(feature = "trace")]
+        {
+            let _guard = _span.span().enter();
+            trace!(
+                "RequiresComponent score: {}, from score: {}, with component: {}",
+                score.get(),
+                inner_score,
+                has_component
+            );
+        }
+    }
+}
+
+pub struct RequiresComponentBuilder<C: Component> {
+    scorer: Arc<dyn ScorerBuilder>,
+    component: PhantomData<fn() -> C>,
+    label: Option<String>,
+}
+
+impl<C: Component> std::fmt::Debug for RequiresComponentBuilder<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequiresComponentBuilder")
+            .field("scorer", &self.scorer)
+            .field("component", &std::any::type_name::<C>())
+            .field("label", &self.label)
+            .finish()
+    }
+}
+
+impl<C: Component> RequiresComponentBuilder<C> {
+    /// Set a label for this ScorerBuilder.
+    pub fn label(mut self, label: impl AsRef<str>) -> Self {
+        self.label = Some(label.as_ref().into());
+        self
+    }
+}
+
+impl<C: Component> ScorerBuilder for RequiresComponentBuilder<C> {
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref().or(Some("RequiresComponent"))
+    }
+
+    fn build(&self, cmd: &mut Commands, scorer: Entity, actor: Entity) {
+        let inner_scorer = spawn_scorer(&*self.scorer, cmd, actor);
+        cmd.entity(scorer)
+            .add_children(&[inner_scorer])
+            .insert(RequiresComponent::<C> {
+                scorer: Scorer(inner_scorer),
+                component: PhantomData,
+            });
+    }
+}
+
+/// How [`OverTargets`] boils the scores for all of its targets down into one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum TargetReducer {
+    /// The highest score of any target. "How dangerous is the most
+    /// dangerous threat?"
+    #[default]
+    Max,
+    /// All targets' scores added up, clamped to `1.0`. "How much danger am I
+    /// in overall?"
+    Sum,
+    /// The average score over all targets.
+    Average,
+}
+
+type TargetScoreFn<D> = dyn for<'a> Fn(ROQueryItem<'a, D>, ROQueryItem<'a, D>) -> f32 + Send + Sync;
+
+/// Scorer that scores every entity with a `T` component (except the actor
+/// itself) with a closure, and reduces all of those scores into one with a
+/// [`TargetReducer`]. This covers the common "over all nearby threats, how
+/// dangerous is the most dangerous one?" kind of decision without writing
+/// the loop yourself.
+///
+/// The closure gets the actor's and the target's `D`, which can be any
+/// read-only query data, like `&Transform` or `(&Transform, &Health)`, in
+/// that order. Actors or targets without a `D` are skipped. Its results are
+/// clamped to `0.0..=1.0`. With no targets, the Score is `0.0`.
+///
+/// Just like [`ResourceScore`], you have to register [`over_targets_system`]
+/// for each combination of `T` and `D` you use.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # use big_brain::scorers::over_targets_system;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct Flee;
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// fn setup(mut cmd: Commands) {
+///     cmd.spawn((
+///         Transform::default(),
+///         Thinker::build().picker(FirstToScore::new(0.5)).when(
+///             // The closer the closest enemy, the scarier.
+///             OverTargets::<Enemy, &Transform>::build(|me: &Transform, them: &Transform| {
+///                 1.0 - me.translation.distance(them.translation) / 10.0
+///             }),
+///             Flee,
+///         ),
+///     ));
+/// }
+///
+/// # fn main() {
+/// App::new()
+///     .add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+///     .add_systems(Startup, setup)
+///     .add_systems(
+///         PreUpdate,
+///         over_targets_system::<Enemy, &Transform>.in_set(BigBrainSet::Scorers),
+///     )
+/// # ;
+/// # }
+/// ```
+#[derive(Component)]
+pub struct OverTargets<T: Component, D: ReadOnlyQueryData + 'static> {
+    score_fn: Arc<TargetScoreFn<D>>,
+    reducer: TargetReducer,
+    targets: PhantomData<fn() -> T>,
+}
+
+impl<T: Component, D: ReadOnlyQueryData + 'static> std::fmt::Debug for OverTargets<T, D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OverTargets")
+            .field("targets", &std::any::type_name::<T>())
+            .field("data", &std::any::type_name::<D>())
+            .field("reducer", &self.reducer)
+            .finish()
+    }
+}
+
+impl<T: Component, D: ReadOnlyQueryData + 'static> OverTargets<T, D> {
+    pub fn build(
+        score_fn: impl for<'a> Fn(ROQueryItem<'a, D>, ROQueryItem<'a, D>) -> f32 + Send + Sync + 'static,
+    ) -> OverTargetsBuilder<T, D> {
+        OverTargetsBuilder {
+            score_fn: Arc::new(score_fn),
+            reducer: TargetReducer::default(),
+            targets: PhantomData,
+            label: None,
+        }
+    }
+}
+
+/// System that updates any [`OverTargets`] for targets with a `T`, scored
+/// using their `D`.
+#[allow(clippy::type_complexity)]
+pub fn over_targets_system<T: Component, D: ReadOnlyQueryData + 'static>(
+    mut query: Query<(&Actor, &OverTargets<T, D>, &mut Score, &ScorerSpan), Without<SkipScoring>>,
+    actors: Query<D>,
+    targets: Query<(Entity, D), With<T>>,
+) {
+    for (Actor(actor), over_targets, mut score, _span) in query.iter_mut() {
+        if !actors.contains(*actor) {
+            score.set(0.0);
+            continue;
+        }
+        let mut count = 0;
+        let mut reduced = 0.0f32;
+        for (target, them) in targets.iter() {
+            if target == *actor {
+                continue;
+            }
+            let Ok(me) = actors.get(*actor) else {
+                continue;
+            };
+            let value = closure_score((over_targets.score_fn)(me, them));
+            count += 1;
+            reduced = match over_targets.reducer {
+                TargetReducer::Max => reduced.max(value),
+                TargetReducer::Sum | TargetReducer::Average => reduced + value,
+            };
+        }
+        let value = match over_targets.reducer {
+            TargetReducer::Average if count > 0 => reduced / count as f32,
+            _ => reduced,
+        };
+        score.set(crate::evaluators::clamp(value, 0.0, 1.0));
+        #[cfg(feature = "trace")]
+        {
+            let _guard = _span.span().enter();
+            trace!("OverTargets score: {}, over {} targets", score.get(), count);
+        }
+    }
+}
+
+pub struct OverTargetsBuilder<T: Component, D: ReadOnlyQueryData + 'static> {
+    score_fn: Arc<TargetScoreFn<D>>,
+    reducer: TargetReducer,
+    targets: PhantomData<fn() -> T>,
+    label: Option<String>,
+}
+
+impl<T: Component, D: ReadOnlyQueryData + 'static> std::fmt::Debug for OverTargetsBuilder<T, D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OverTargetsBuilder")
+            .field("targets", &std::any::type_name::<T>())
+            .field("data", &std::any::type_name::<D>())
+            .field("reducer", &self.reducer)
+            .field("label", &self.label)
+            .finish()
+    }
+}
+
+impl<T: Component, D: ReadOnlyQueryData + 'static> OverTargetsBuilder<T, D> {
+    /// How to combine the targets' scores. Defaults to
+    /// [`TargetReducer::Max`].
+    pub fn reducer(mut self, reducer: TargetReducer) -> Self {
+        self.reducer = reducer;
+        self
+    }
+
+    /// Set a label for this ScorerBuilder.
+    pub fn label(mut self, label: impl AsRef<str>) -> Self {
+        self.label = Some(label.as_ref().into());
+        self
+    }
+}
+
+impl<T: Component, D: ReadOnlyQueryData + 'static> ScorerBuilder for OverTargetsBuilder<T, D> {
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref().or(Some("OverTargets"))
+    }
+
+    fn build(&self, cmd: &mut Commands, scorer: Entity, _actor: Entity) {
+        cmd.entity(scorer).insert(OverTargets::<T, D> {
+            score_fn: self.score_fn.clone(),
+            reducer: self.reducer,
+            targets: PhantomData,
+        });
+    }
+}
+
+/// Composite Scorer that allows more fine-grained control of how the scores
+/// are combined. The default is to apply a weighting
+///
+/// [Unset](Score::unset) child Scores are left out before measuring, along
+/// with their weights. If every child is unset, this Scorer is unset too.
+///
+/// ### Example
+///
+/// Using the default measure ([`WeightedMeasure`]):
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct MyScorer;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct MyOtherScorer;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct MyAction;
+/// # fn main() {
+/// Thinker::build()
+///     .when(
+///         MeasuredScorer::build(0.5)
+///           .push(MyScorer, 0.9)
+///           .push(MyOtherScorer, 0.4),
+///         MyAction)
+/// # ;
+/// # }
+/// ```
+///
+/// Customising the measure:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct MyScorer;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct MyOtherScorer;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct MyAction;
+/// # fn main() {
+/// Thinker::build()
+///     .when(
+///         MeasuredScorer::build(0.5)
+///           .measure(ChebyshevDistance)
+///           .push(MyScorer, 0.8)
+///           .push(MyOtherScorer, 0.2),
+///         MyAction)
+/// # ;
+/// # }
+/// ```
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(from_reflect = false)]
+pub struct MeasuredScorer {
+    threshold: f32,
+    #[reflect(ignore)]
+    measure: Arc<dyn Measure>,
+    measure_string: String,
+    normalize_weights: bool,
+    scorers: Vec<(Scorer, f32)>,
+    #[reflect(ignore)]
+    evaluators: Vec<Option<Arc<dyn Evaluator>>>,
+}
+
+impl MeasuredScorer {
+    pub fn build(threshold: f32) -> MeasuredScorerBuilder {
+        MeasuredScorerBuilder {
+            threshold,
+            measure: Arc::new(WeightedMeasure),
+            measure_string: format!("{WeightedMeasure:#?}"),
+            normalize_weights: false,
+            scorers: Vec::new(),
+            evaluators: Vec::new(),
+            scorer_labels: Vec::new(),
+            label: None,
+        }
+    }
+}
+
+pub fn measured_scorers_system(
+    query: Query<(Entity, &MeasuredScorer, &ScorerSpan), Without<SkipScoring>>,
+    scores: Query<&mut Score>,
+) {
+    measured_scorers_system_filtered(query, scores);
+}
+
+/// Does the work of [`measured_scorers_system`] for the Scorers matching `F`.
+fn measured_scorers_system_filtered<F: QueryFilter + 'static>(
+    query: Query<(Entity, &MeasuredScorer, &ScorerSpan), F>,
+    mut scores: Query<&mut Score>,
+) {
+    for (
+        sos_ent,
+        MeasuredScorer {
+            threshold,
+            measure,
+            normalize_weights,
+            scorers: children,
+            evaluators,
+            ..
+        },
+        _span,
+    ) in query.iter()
+    {
+        let (evaluated, weights): (Vec<_>, Vec<_>) = children
+            .iter()
+            .zip(evaluators.iter())
+            .filter_map(|((scorer, weight), evaluator)| {
+                let score = scores.get(scorer.0).expect("where is it?");
+                if score.is_unset() {
+                    return None;
+                }
+                let score = match evaluator {
+                    Some(evaluator) => Score::new(crate::evaluators::clamp(
+                        evaluator.evaluate(score.get()),
+                        0.0,
+                        1.0,
+                    )),
+                    None => Score::new(score.get()),
+                };
+                Some((score, *weight))
+            })
+            .unzip();
+        let mut score = scores.get_mut(sos_ent).expect("where did it go?");
+        if evaluated.is_empty() && !children.is_empty() {
+            score.unset();
+            continue;
+        }
+        let weight_scale = if *normalize_weights {
+            let total: f32 = weights.iter().sum();
+            if total == 0.0 {
+                1.0
+            } else {
+                total.recip()
+            }
+        } else {
+            1.0
+        };
+        let measured_score = measure.calculate(
+            evaluated
+                .iter()
+                .zip(weights.iter())
+                .map(|(score, weight)| (score, *weight * weight_scale))
+                .collect::<Vec<_>>(),
+        );
+
+        if measured_score < *threshold {
+            score.set(0.0);
+        } else {
+            score.set(measured_score.clamp(0.0, 1.0));
+        }
+        #[cfg(feature = "trace")]
+        {
+            let _guard = _span.span().enter();
+            trace!(
+                "MeasuredScorer score: {}, from {} scores",
+                score.get(),
+                children.len()
+            );
+        }
+    }
+}
+
+#[derive(Debug, Reflect)]
+#[reflect(from_reflect = false)]
+pub struct MeasuredScorerBuilder {
+    threshold: f32,
+    #[reflect(ignore)]
+    measure: Arc<dyn Measure>,
+    measure_string: String,
+    normalize_weights: bool,
+    #[reflect(ignore)]
+    scorers: Vec<(Arc<dyn ScorerBuilder>, f32)>,
+    #[reflect(ignore)]
+    evaluators: Vec<Option<Arc<dyn Evaluator>>>,
+    scorer_labels: Vec<String>,
+    label: Option<String>,
+}
+
+impl MeasuredScorerBuilder {
+    /// Sets the measure to be used to combine the child scorers
+    pub fn measure(mut self, measure: impl Measure + 'static) -> Self {
+        self.measure_string = format!("{measure:#?}");
+        self.measure = Arc::new(measure);
+        self
+    }
+
+    /// Divide each weight by the total of all weights before combining the
+    /// child scores, so that weights describe relative importance no matter
+    /// their absolute magnitude. Note that this changes the results of
+    /// measures that depend on the absolute weights, like
+    /// [`WeightedSum`](crate::measures::WeightedSum).
+    pub fn normalize_weights(mut self, normalize_weights: bool) -> Self {
+        self.normalize_weights = normalize_weights;
+        self
+    }
+
+    pub fn push(mut self, scorer: impl ScorerBuilder + 'static, weight: f32) -> Self {
+        if let Some(label) = scorer.label() {
+            self.scorer_labels.push(label.into());
+        } else {
+            self.scorer_labels.push("Unnamed Scorer".into());
+        }
+        self.scorers.push((Arc::new(scorer), weight));
+        self.evaluators.push(None);
+        self
+    }
+
+    /// Like [`MeasuredScorerBuilder::push`], but runs the child's score
+    /// through `evaluator` before the measure combines it with the others.
+    /// This saves you from wrapping each child in its own
+    /// [`EvaluatingScorer`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+    /// # struct Hungry;
+    /// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+    /// # struct NearFood;
+    /// # #[derive(Debug, Clone, Component, ActionBuilder)]
+    /// # struct Eat;
+    /// # fn main() {
+    /// Thinker::build()
+    ///     .when(
+    ///         MeasuredScorer::build(0.5)
+    ///             .push_evaluated(Hungry, 0.7, PowerEvaluator::new(2.0))
+    ///             .push(NearFood, 0.3),
+    ///         Eat)
+    /// # ;
+    /// # }
+    /// ```
+    pub fn push_evaluated(
+        self,
+        scorer: impl ScorerBuilder + 'static,
+        weight: f32,
+        evaluator: impl Evaluator + 'static,
+    ) -> Self {
+        let mut this = self.push(scorer, weight);
+        if let Some(last) = this.evaluators.last_mut() {
+            *last = Some(Arc::new(evaluator));
+        }
+        this
+    }
+
+    /// Set a label for this ScorerBuilder.
+    pub fn label(mut self, label: impl AsRef<str>) -> Self {
+        self.label = Some(label.as_ref().into());
+        self
+    }
+}
+
+impl ScorerBuilder for MeasuredScorerBuilder {
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref().or(Some("MeasuredScorer"))
+    }
+
+    #[allow(clippy::needless_collect)]
+    fn build(&self, cmd: &mut Commands, scorer: Entity, actor: Entity) {
+        warn_if_childless(self, self.scorers.len());
+        let scorers: Vec<_> = self
+            .scorers
             .iter()
             .map(|(scorer, _)| spawn_scorer(&**scorer, cmd, actor))
             .collect();
@@ -982,12 +3181,319 @@ impl ScorerBuilder for MeasuredScorerBuilder {
             .insert(MeasuredScorer {
                 threshold: self.threshold,
                 measure: self.measure.clone(),
+                normalize_weights: self.normalize_weights,
                 scorers: scorers
                     .into_iter()
                     .map(Scorer)
                     .zip(self.scorers.iter().map(|(_, weight)| *weight))
                     .collect(),
+                evaluators: self.evaluators.clone(),
                 measure_string: self.measure_string.clone(),
             });
     }
 }
+
+type CombineFn = dyn Fn(&[f32]) -> f32 + Send + Sync;
+
+/// Composite Scorer that combines its children's [`Score`]s with a closure,
+/// for one-off combinations that don't deserve their own [`Measure`]. The
+/// closure gets the children's values in the order they were pushed, every
+/// frame, and its result is clamped to `0.0..=1.0`. [Unset](Score::unset)
+/// children are passed in as `0.0`, so their positions stay put.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct Hungry;
+/// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+/// # struct Full;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct Eat;
+/// # fn main() {
+/// Thinker::build()
+///     .picker(FirstToScore::new(0.5))
+///     .when(
+///         CombineScorer::build(|scores: &[f32]| scores[0] - scores[1])
+///             .push(Hungry)
+///             .push(Full),
+///         Eat,
+///     )
+/// # ;
+/// # }
+/// ```
+#[derive(Component)]
+pub struct CombineScorer {
+    combine: Arc<CombineFn>,
+    scorers: Vec<Scorer>,
+    scorer_labels: Vec<String>,
+}
+
+impl std::fmt::Debug for CombineScorer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CombineScorer")
+            .field("scorers", &self.scorers)
+            .field("scorer_labels", &self.scorer_labels)
+            .finish()
+    }
+}
+
+impl CombineScorer {
+    pub fn build(combine: impl Fn(&[f32]) -> f32 + Send + Sync + 'static) -> CombineScorerBuilder {
+        CombineScorerBuilder {
+            combine: Arc::new(combine),
+            scorers: Vec::new(),
+            scorer_labels: Vec::new(),
+            label: None,
+        }
+    }
+}
+
+pub fn combine_scorer_system(
+    query: Query<(Entity, &CombineScorer, &ScorerSpan), Without<SkipScoring>>,
+    scores: Query<&mut Score>,
+    values: Local<Vec<f32>>,
+) {
+    combine_scorer_system_filtered(query, scores, values);
+}
+
+/// Does the work of [`combine_scorer_system`] for the Scorers matching `F`.
+fn combine_scorer_system_filtered<F: QueryFilter + 'static>(
+    query: Query<(Entity, &CombineScorer, &ScorerSpan), F>,
+    mut scores: Query<&mut Score>,
+    mut values: Local<Vec<f32>>,
+) {
+    for (
+        combine_ent,
+        CombineScorer {
+            combine, scorers, ..
+        },
+        _span,
+    ) in query.iter()
+    {
+        values.clear();
+        values.extend(
+            scorers
+                .iter()
+                .map(|Scorer(child)| scores.get(*child).expect("where is it?").get()),
+        );
+        let value = combine(&values);
+        let mut score = scores.get_mut(combine_ent).expect("where did it go?");
+        score.set(closure_score(value));
+        #[cfg(feature = "trace")]
+        {
+            let _guard = _span.span().enter();
+            trace!(
+                "CombineScorer score: {}, from scores: {:?}",
+                score.get(),
+                values
+            );
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct CombineScorerBuilder {
+    combine: Arc<CombineFn>,
+    scorers: Vec<Arc<dyn ScorerBuilder>>,
+    scorer_labels: Vec<String>,
+    label: Option<String>,
+}
+
+impl std::fmt::Debug for CombineScorerBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CombineScorerBuilder")
+            .field("scorer_labels", &self.scorer_labels)
+            .field("label", &self.label)
+            .finish()
+    }
+}
+
+impl CombineScorerBuilder {
+    /// Add another Scorer to this [`ScorerBuilder`]. Its value is passed to
+    /// the closure after those of the Scorers pushed before it.
+    pub fn push(mut self, scorer: impl ScorerBuilder + 'static) -> Self {
+        if let Some(label) = scorer.label() {
+            self.scorer_labels.push(label.into());
+        } else {
+            self.scorer_labels.push("Unnamed Scorer".into());
+        }
+        self.scorers.push(Arc::new(scorer));
+        self
+    }
+
+    /// Set a label for this ScorerBuilder.
+    pub fn label(mut self, label: impl AsRef<str>) -> Self {
+        self.label = Some(label.as_ref().into());
+        self
+    }
+}
+
+impl ScorerBuilder for CombineScorerBuilder {
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref().or(Some("CombineScorer"))
+    }
+
+    #[allow(clippy::needless_collect)]
+    fn build(&self, cmd: &mut Commands, scorer: Entity, actor: Entity) {
+        warn_if_childless(self, self.scorers.len());
+        let scorers: Vec<_> = self
+            .scorers
+            .iter()
+            .map(|scorer| spawn_scorer(&**scorer, cmd, actor))
+            .collect();
+        cmd.entity(scorer)
+            .add_children(&scorers[..])
+            .insert(CombineScorer {
+                combine: self.combine.clone(),
+                scorers: scorers.into_iter().map(Scorer).collect(),
+                scorer_labels: self.scorer_labels.clone(),
+            });
+    }
+}
+
+type MakeCondition = dyn Fn() -> BoxedCondition + Send + Sync;
+
+/// Scorer that reports whether a Bevy run [`Condition`] holds: `1.0` when it
+/// returns `true`, `0.0` when it returns `false`. This is for choices gated
+/// on game-wide state, like whether it's raining or the boss is still alive,
+/// and lets you reuse the same conditions you already use with `run_if`,
+/// including the ones in
+/// [`common_conditions`](bevy::ecs::schedule::common_conditions).
+///
+/// Each ConditionScorer runs its own copy of the condition as a system,
+/// through `condition_scorer_system`. That needs exclusive [`World`] access,
+/// so these Scorers don't run in parallel with anything else. The condition
+/// can't see which actor it's scoring for; use a regular Scorer for that.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # #[derive(Clone, Component, Debug, ActionBuilder)]
+/// # struct TakeShelter;
+/// #[derive(Resource)]
+/// struct Raining;
+///
+/// # fn main() {
+/// Thinker::build()
+///     .picker(FirstToScore::new(0.5))
+///     .when(
+///         ConditionScorer::build(resource_exists::<Raining>),
+///         TakeShelter,
+///     )
+/// # ;
+/// # }
+/// ```
+#[derive(Component)]
+pub struct ConditionScorer {
+    condition: Option<BoxedCondition>,
+    initialized: bool,
+}
+
+impl std::fmt::Debug for ConditionScorer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConditionScorer")
+            .field(
+                "condition",
+                &self.condition.as_ref().map(|condition| condition.name()),
+            )
+            .finish()
+    }
+}
+
+impl ConditionScorer {
+    pub fn build<M>(
+        condition: impl Condition<M> + Clone + Send + Sync + 'static,
+    ) -> ConditionScorerBuilder {
+        let name = IntoSystem::into_system(condition.clone()).name();
+        ConditionScorerBuilder {
+            make: Arc::new(move || Box::new(IntoSystem::into_system(condition.clone()))),
+            name,
+            label: None,
+        }
+    }
+}
+
+/// Runs every [`ConditionScorer`]'s condition and sets its [`Score`].
+pub fn condition_scorer_system(
+    world: &mut World,
+    scorers: &mut QueryState<(Entity, &mut ConditionScorer), Without<SkipScoring>>,
+) {
+    condition_scorer_system_filtered(world, scorers);
+}
+
+/// Does the work of [`condition_scorer_system`] for the Scorers matching `F`.
+fn condition_scorer_system_filtered<F: QueryFilter + 'static>(
+    world: &mut World,
+    scorers: &mut QueryState<(Entity, &mut ConditionScorer), F>,
+) {
+    let mut conditions: Vec<_> = scorers
+        .iter_mut(world)
+        .filter_map(|(entity, mut scorer)| {
+            let initialized = scorer.initialized;
+            scorer
+                .condition
+                .take()
+                .map(|condition| (entity, condition, initialized))
+        })
+        .collect();
+    for (entity, condition, initialized) in conditions.iter_mut() {
+        if !*initialized {
+            condition.initialize(world);
+            *initialized = true;
+        }
+        let holds = condition.run((), world);
+        #[cfg(feature = "trace")]
+        trace!("ConditionScorer {}: {holds}", condition.name());
+        if let Some(mut score) = world.get_mut::<Score>(*entity) {
+            score.set(if holds { 1.0 } else { 0.0 });
+        }
+    }
+    for (entity, condition, initialized) in conditions {
+        if let Some(mut scorer) = world.get_mut::<ConditionScorer>(entity) {
+            scorer.condition = Some(condition);
+            scorer.initialized = initialized;
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ConditionScorerBuilder {
+    make: Arc<MakeCondition>,
+    name: std::borrow::Cow<'static, str>,
+    label: Option<String>,
+}
+
+impl std::fmt::Debug for ConditionScorerBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConditionScorerBuilder")
+            .field("condition", &self.name)
+            .field("label", &self.label)
+            .finish()
+    }
+}
+
+impl ConditionScorerBuilder {
+    /// Set a label for this ScorerBuilder.
+    pub fn label(mut self, label: impl AsRef<str>) -> Self {
+        self.label = Some(label.as_ref().into());
+        self
+    }
+}
+
+impl ScorerBuilder for ConditionScorerBuilder {
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref().or(Some("ConditionScorer"))
+    }
+
+    fn build(&self, cmd: &mut Commands, scorer: Entity, _actor: Entity) {
+        cmd.entity(scorer).insert(ConditionScorer {
+            condition: Some((self.make)()),
+            initialized: false,
+        });
+    }
+}
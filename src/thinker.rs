@@ -1,9 +1,18 @@
 //! Thinkers are the "brain" of an entity. You attach Scorers to it, and the
 //! Thinker picks the right Action to run based on the resulting Scores.
 
-use std::{collections::VecDeque, sync::Arc};
+use std::{
+    collections::{HashSet, VecDeque},
+    sync::Arc,
+};
 
 use bevy::{
+    ecs::{
+        entity::{Entities, EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+        system::{SystemId, SystemParam, SystemState},
+        world::CommandQueue,
+    },
     prelude::*,
     utils::{
         tracing::{debug, field, span, Level, Span},
@@ -18,7 +27,8 @@ use crate::{
     actions::{self, ActionBuilder, ActionBuilderWrapper, ActionState},
     choices::{Choice, ChoiceBuilder},
     pickers::Picker,
-    scorers::{Score, ScorerBuilder},
+    scorers::{self, Score, ScorerBuilder},
+    MaxBuildDepth,
 };
 
 /// Wrapper for Actor entities. In terms of Scorers, Thinkers, and Actions,
@@ -26,8 +36,45 @@ use crate::{
 /// entity a Scorer/Thinker/Action is attached to. Generally, you will use
 /// this entity when writing Queries for Action and Scorer systems.
 #[derive(Debug, Clone, Component, Copy, Reflect)]
+#[reflect(Component, MapEntities)]
 pub struct Actor(pub Entity);
 
+impl MapEntities for Actor {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.0 = entity_mapper.map_entity(self.0);
+    }
+}
+
+impl Actor {
+    /// The actor entity. This is the "way back" from a Thinker, Scorer, or
+    /// Action entity to the actor it belongs to. [`HasThinker::entity`] goes
+    /// the other way, from the actor to its Thinker.
+    pub fn entity(&self) -> Entity {
+        self.0
+    }
+}
+
+/// Looks up the actor that a Thinker, Scorer, or Action `entity` belongs to.
+/// This works for entities at any depth, including the children of composite
+/// Scorers and Actions, since they all get an [`Actor`] component when
+/// they're spawned.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # use big_brain::thinker::actor_of;
+/// fn log_thinkers(thinkers: Query<Entity, With<Thinker>>, actors: Query<&Actor>) {
+///     for thinker in thinkers.iter() {
+///         info!("{:?} belongs to {:?}", thinker, actor_of(thinker, &actors));
+///     }
+/// }
+/// ```
+pub fn actor_of(entity: Entity, actors: &Query<&Actor>) -> Option<Entity> {
+    actors.get(entity).ok().map(Actor::entity)
+}
+
 #[derive(Debug, Clone, Copy, Reflect)]
 pub struct Action(pub Entity);
 
@@ -40,6 +87,7 @@ impl Action {
 #[derive(Debug, Clone, Component)]
 pub struct ActionSpan {
     pub(crate) span: Span,
+    pub(crate) label: Option<String>,
 }
 
 impl ActionSpan {
@@ -53,20 +101,35 @@ impl ActionSpan {
         if let Some(label) = label {
             span.record("label", label);
         }
-        Self { span }
+        Self {
+            span,
+            label: label.map(|s| s.into()),
+        }
     }
 
     pub fn span(&self) -> &Span {
         &self.span
     }
+
+    /// The label of the [`ActionBuilder`] this Action was built from, if any.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
 }
 
 #[derive(Debug, Clone, Copy, Reflect)]
 pub struct Scorer(pub Entity);
 
+impl MapEntities for Scorer {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.0 = entity_mapper.map_entity(self.0);
+    }
+}
+
 #[derive(Debug, Clone, Component)]
 pub struct ScorerSpan {
     pub(crate) span: Span,
+    pub(crate) label: Option<String>,
 }
 
 impl ScorerSpan {
@@ -81,12 +144,20 @@ impl ScorerSpan {
         if let Some(label) = label {
             span.record("label", label);
         }
-        Self { span }
+        Self {
+            span,
+            label: label.map(|s| s.into()),
+        }
     }
 
     pub fn span(&self) -> &Span {
         &self.span
     }
+
+    /// The label of the [`ScorerBuilder`] this Scorer was built from, if any.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
 }
 
 /// The "brains" behind this whole operation. A `Thinker` is what glues
@@ -127,22 +198,60 @@ impl ScorerSpan {
 ///     ));
 /// }
 /// ```
+///
+/// ### Saving and loading
+///
+/// Thinkers hold on to trait objects like their [`Picker`] and builders, so
+/// they can't be serialized themselves. Instead, save whatever you build the
+/// [`ThinkerBuilder`] from, along with a [`ThinkerSnapshot`] of what the
+/// Thinker is in the middle of (which is serializable with the `serde`
+/// feature). To load:
+///
+/// 1. Spawn the actor with the rebuilt `ThinkerBuilder`, like you normally
+///    would.
+/// 2. If it should pick up exactly where it left off, rather than picking
+///    whatever scores best right now, call [`restore_thinker`] with the
+///    snapshot.
+/// 3. Any progress an Action made should live in components on the actor,
+///    which you can save and load like any other component.
 #[derive(Component, Debug, Reflect)]
 #[reflect(from_reflect = false)]
 pub struct Thinker {
     #[reflect(ignore)]
     picker: Arc<dyn Picker>,
     #[reflect(ignore)]
-    otherwise: Option<ActionBuilderWrapper>,
+    pub(crate) otherwise: Option<ActionBuilderWrapper>,
+    #[reflect(ignore)]
+    idle: Option<(Duration, ActionBuilderWrapper)>,
     #[reflect(ignore)]
-    choices: Vec<Choice>,
+    idle_since: Option<Duration>,
     #[reflect(ignore)]
-    current_action: Option<(Action, ActionBuilderWrapper)>,
+    pub(crate) choices: Vec<Choice>,
+    #[reflect(ignore)]
+    pub(crate) current_action: Option<(Action, ActionBuilderWrapper)>,
     current_action_label: Option<Option<String>>,
+    multi: bool,
+    #[reflect(ignore)]
+    pub(crate) concurrent_actions: Vec<(Action, ActionBuilderWrapper)>,
     #[reflect(ignore)]
     span: Span,
     #[reflect(ignore)]
     scheduled_actions: VecDeque<ActionBuilderWrapper>,
+    #[reflect(ignore)]
+    pooled_actions: Vec<(Action, ActionBuilderWrapper)>,
+    #[reflect(ignore)]
+    switches: SwitchTracker,
+    #[reflect(ignore)]
+    on_complete: Option<SystemId<In<ActionCompleted>>>,
+    completed: Option<Entity>,
+    #[reflect(ignore)]
+    on_finish: Option<SystemId<In<ThinkerFinished>>>,
+    finished: bool,
+    finish_when_exhausted: bool,
+    #[reflect(ignore)]
+    interrupt_grace: Option<Duration>,
+    #[reflect(ignore)]
+    interrupted: Vec<(Entity, Duration)>,
 }
 
 impl Thinker {
@@ -156,16 +265,119 @@ impl Thinker {
         self.scheduled_actions
             .push_back(ActionBuilderWrapper::new(Arc::new(action)));
     }
+
+    /// How many choices this Thinker has. They're numbered in the order they
+    /// were added with [`ThinkerBuilder::when`] (and friends), which is also
+    /// the order its [`Picker`] sees them in. This goes down as one-shot
+    /// choices from [`ThinkerBuilder::when_once`] are used up.
+    pub fn choice_count(&self) -> usize {
+        self.choices.len()
+    }
+
+    /// The choice whose Action this Thinker is currently running, if any.
+    pub(crate) fn current_choice(&self) -> Option<&Choice> {
+        let (_, ActionBuilderWrapper(current_id, _)) = self.current_action.as_ref()?;
+        self.choices
+            .iter()
+            .find(|choice| choice.action.0 == *current_id)
+    }
+
+    /// The label of the Action this Thinker is currently running, if it's
+    /// running one and it has a label.
+    pub fn current_action_label(&self) -> Option<&str> {
+        self.current_action.as_ref()?;
+        self.current_action_label.as_ref()?.as_deref()
+    }
+
+    /// Finds the builder of this Thinker's choice, `otherwise` or idle Action
+    /// with the given label, in that order. Passing it to
+    /// [`PendingAction::set`] runs it as that very choice, rather than as a
+    /// one-off override.
+    pub fn action_by_label(&self, label: &str) -> Option<Arc<dyn ActionBuilder>> {
+        self.wrapper_by_label(label)
+            .map(|wrapper| wrapper.1.clone())
+    }
+
+    fn wrapper_by_label(&self, label: &str) -> Option<&ActionBuilderWrapper> {
+        self.choices
+            .iter()
+            .map(|choice| &choice.action)
+            .chain(self.otherwise.iter())
+            .chain(self.idle.iter().map(|(_, action)| action))
+            .find(|wrapper| wrapper.1.label() == Some(label))
+    }
+
+    /// How many times per second this Thinker has switched from one Action to
+    /// a different one, averaged over the window set with
+    /// [`ThinkerBuilder::switch_window`]. Restarting the same Action doesn't
+    /// count. A high rate means the Thinker is thrashing between choices
+    /// whose scores are too close together; see
+    /// [`StableHighest`](crate::pickers::StableHighest) for one way to calm
+    /// it down.
+    ///
+    /// Only the main Action is tracked, not the ones started in MultiThinker
+    /// mode.
+    pub fn switches_per_second(&self) -> f32 {
+        self.switches.rate()
+    }
+
+    /// The Action entities currently running for picked choices, when in
+    /// MultiThinker mode (see [`ThinkerBuilder::multi`]). This includes
+    /// Actions that are still wrapping up after being interrupted.
+    pub fn concurrent_actions(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.concurrent_actions
+            .iter()
+            .map(|(action, _)| action.entity())
+    }
+
+    /// The first of [`Thinker::concurrent_actions`] that belongs to a choice
+    /// tagged with `tag` (see [`ThinkerBuilder::when_tagged`]). With
+    /// [`HighestPerGroup`](crate::pickers::HighestPerGroup), this is the one
+    /// Action running for that group, if any. Actions still wrapping up after
+    /// being interrupted are included, so there can briefly be more than one
+    /// per group while they finish.
+    pub fn concurrent_action_for_tag(&self, tag: &str) -> Option<Entity> {
+        self.concurrent_actions
+            .iter()
+            .find_map(|(action, wrapper)| {
+                self.choices
+                    .iter()
+                    .any(|choice| {
+                        choice.tag.as_deref() == Some(tag) && choice.action.0 == wrapper.0
+                    })
+                    .then(|| action.entity())
+            })
+    }
 }
 
 /// This is what you actually use to configure Thinker behavior. It's a plain
 /// old [`ActionBuilder`], as well.
-#[derive(Component, Clone, Debug, Default)]
+///
+/// Cloning a `ThinkerBuilder` is cheap: its [`Picker`] and all of its Scorer
+/// and Action builders live behind [`Arc`]s, so clones share them. To spawn
+/// lots of actors that think alike, see [`ThinkerBuilder::spawn_batch`].
+#[derive(Component, Clone, Debug)]
 pub struct ThinkerBuilder {
     picker: Option<Arc<dyn Picker>>,
     otherwise: Option<ActionBuilderWrapper>,
+    otherwise_choice: Option<ChoiceBuilder>,
+    idle: Option<(Duration, ActionBuilderWrapper)>,
     choices: Vec<ChoiceBuilder>,
     label: Option<String>,
+    multi: bool,
+    scratch: Vec<fn(&mut Commands, Entity)>,
+    switch_window: Duration,
+    warn_above_switches: Option<f32>,
+    on_complete: Option<SystemId<In<ActionCompleted>>>,
+    on_finish: Option<SystemId<In<ThinkerFinished>>>,
+    finish_when_exhausted: bool,
+    interrupt_grace: Option<Duration>,
+}
+
+impl Default for ThinkerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl ThinkerBuilder {
@@ -173,8 +385,18 @@ impl ThinkerBuilder {
         Self {
             picker: None,
             otherwise: None,
+            otherwise_choice: None,
+            idle: None,
             choices: Vec::new(),
             label: None,
+            multi: false,
+            scratch: Vec::new(),
+            switch_window: Duration::from_secs(5),
+            warn_above_switches: None,
+            on_complete: None,
+            on_finish: None,
+            finish_when_exhausted: false,
+            interrupt_grace: Some(Duration::from_secs(1)),
         }
     }
 
@@ -184,8 +406,77 @@ impl ThinkerBuilder {
         self
     }
 
+    /// Puts this Thinker in MultiThinker mode, where it runs the Actions of
+    /// every choice [`Picker::pick_many`] returns at the same time, instead
+    /// of just the one [`Picker::pick`] returns. Each picked choice gets its
+    /// own Action, which keeps running for as long as the choice keeps being
+    /// picked, and is interrupted once it isn't. This is for actors that do
+    /// several independent things at once, like walking while scanning for
+    /// enemies, without tying those things together in one
+    /// [`Concurrently`](crate::actions::Concurrently).
+    ///
+    /// The `otherwise` Action and scheduled Actions only run while no choice
+    /// is picked.
+    ///
+    /// Instead of a single current Action, a MultiThinker keeps track of one
+    /// Action per picked choice (see [`Thinker::concurrent_actions`]). To keep
+    /// it to at most one Action per kind of thing, tag choices with
+    /// [`ThinkerBuilder::when_tagged`] and use
+    /// [`HighestPerGroup`](crate::pickers::HighestPerGroup), which treats each
+    /// tag as a group. Each group's Action can then be found with
+    /// [`Thinker::concurrent_action_for_tag`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// # #[derive(Clone, Component, Debug, ScorerBuilder)]
+    /// # struct HasDestination;
+    /// # #[derive(Clone, Component, Debug, ScorerBuilder)]
+    /// # struct Alert;
+    /// # #[derive(Clone, Component, Debug, ActionBuilder)]
+    /// # struct Walk;
+    /// # #[derive(Clone, Component, Debug, ActionBuilder)]
+    /// # struct Scan;
+    /// # fn main() {
+    /// Thinker::build()
+    ///     .multi(true)
+    ///     .picker(AllToScore::new(0.5))
+    ///     .when(HasDestination, Walk)
+    ///     .when(Alert, Scan)
+    /// # ;
+    /// # }
+    /// ```
+    pub fn multi(mut self, multi: bool) -> Self {
+        self.multi = multi;
+        self
+    }
+
+    /// How far back [`Thinker::switches_per_second`] looks, in in-game time.
+    /// Defaults to 5 seconds.
+    pub fn switch_window(mut self, window: Duration) -> Self {
+        self.switch_window = window;
+        self
+    }
+
+    /// Logs a warning when this Thinker starts switching Actions more than
+    /// `switches_per_second` times per second (see
+    /// [`Thinker::switches_per_second`]). It warns again only after the rate
+    /// has dropped back down and then climbed over the limit again.
+    pub fn warn_above_switches(mut self, switches_per_second: f32) -> Self {
+        self.warn_above_switches = Some(switches_per_second);
+        self
+    }
+
     /// Define an [`ActionBuilder`](crate::actions::ActionBuilder) and
     /// [`ScorerBuilder`](crate::scorers::ScorerBuilder) pair.
+    ///
+    /// Choices are handed to the [`Picker`] in the order they were added,
+    /// across `when` and all its variants. This order is part of the API, so
+    /// order-sensitive Pickers like [`FirstToScore`](crate::pickers::FirstToScore)
+    /// can be relied on to prefer earlier choices. One-shot choices removed
+    /// by [`ThinkerBuilder::when_once`] don't shuffle the rest.
     pub fn when(
         mut self,
         scorer: impl ScorerBuilder + 'static,
@@ -196,10 +487,435 @@ impl ThinkerBuilder {
         self
     }
 
+    /// Like [`ThinkerBuilder::when`], but the choice is only ever picked
+    /// until its action succeeds. After that, it's removed from the Thinker
+    /// for good. This is handy for things that should only happen once, like
+    /// greeting the player the first time you see them.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// # #[derive(Clone, Component, Debug, ScorerBuilder)]
+    /// # struct SeesPlayer;
+    /// # #[derive(Clone, Component, Debug, ActionBuilder)]
+    /// # struct Greet;
+    /// # #[derive(Clone, Component, Debug, ActionBuilder)]
+    /// # struct Idle;
+    /// # fn main() {
+    /// Thinker::build()
+    ///     .picker(FirstToScore::new(0.5))
+    ///     .when_once(SeesPlayer, Greet)
+    ///     .otherwise(Idle)
+    /// # ;
+    /// # }
+    /// ```
+    pub fn when_once(
+        mut self,
+        scorer: impl ScorerBuilder + 'static,
+        action: impl ActionBuilder + 'static,
+    ) -> Self {
+        self.choices
+            .push(ChoiceBuilder::new(Arc::new(scorer), Arc::new(action)).once());
+        self
+    }
+
+    /// Like [`ThinkerBuilder::when`], but tags the resulting [`Choice`] so a
+    /// custom [`Picker`] can read it from [`Choice::tag`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// use big_brain::choices::Choice;
+    /// # #[derive(Clone, Component, Debug, ScorerBuilder)]
+    /// # struct Hungry;
+    /// # #[derive(Clone, Component, Debug, ScorerBuilder)]
+    /// # struct Threatened;
+    /// # #[derive(Clone, Component, Debug, ActionBuilder)]
+    /// # struct Eat;
+    /// # #[derive(Clone, Component, Debug, ActionBuilder)]
+    /// # struct Flee;
+    /// /// Picks the best "urgent" choice if there is one, and the best of
+    /// /// the rest otherwise.
+    /// #[derive(Debug, Clone)]
+    /// struct UrgentFirst;
+    ///
+    /// impl Picker for UrgentFirst {
+    ///     fn pick<'a>(&self, choices: &'a [Choice], scores: &Query<&Score>) -> Option<&'a Choice> {
+    ///         let best = |urgent: bool| {
+    ///             choices
+    ///                 .iter()
+    ///                 .filter(|c| (c.tag.as_deref() == Some("urgent")) == urgent)
+    ///                 .filter(|c| c.calculate(scores) > 0.5)
+    ///                 .max_by(|a, b| a.calculate(scores).total_cmp(&b.calculate(scores)))
+    ///         };
+    ///         best(true).or_else(|| best(false))
+    ///     }
+    /// }
+    ///
+    /// # fn main() {
+    /// Thinker::build()
+    ///     .picker(UrgentFirst)
+    ///     .when_tagged(Threatened, Flee, "urgent")
+    ///     .when(Hungry, Eat)
+    /// # ;
+    /// # }
+    /// ```
+    pub fn when_tagged(
+        mut self,
+        scorer: impl ScorerBuilder + 'static,
+        action: impl ActionBuilder + 'static,
+        tag: impl AsRef<str>,
+    ) -> Self {
+        self.choices
+            .push(ChoiceBuilder::new(Arc::new(scorer), Arc::new(action)).tag(tag));
+        self
+    }
+
     /// Default `Action` to execute if the `Picker` did not pick any of the
     /// given choices.
     pub fn otherwise(mut self, otherwise: impl ActionBuilder + 'static) -> Self {
         self.otherwise = Some(ActionBuilderWrapper::new(Arc::new(otherwise)));
+        self.otherwise_choice = None;
+        self
+    }
+
+    /// Like [`ThinkerBuilder::otherwise`], but the default `Action` competes
+    /// with the other choices as a regular choice with a fixed `baseline`
+    /// score, instead of only running when nothing else is picked. A real
+    /// choice that scores below the baseline can then lose to it, and it
+    /// interrupts and gets interrupted like any other choice.
+    ///
+    /// It always comes after every other choice, no matter when this is
+    /// called, so order-sensitive Pickers like
+    /// [`FirstToScore`](crate::pickers::FirstToScore) only fall back on it
+    /// once nothing before it scores well enough. Its Scorer is a
+    /// [`FixedScore`](crate::scorers::FixedScore) labeled `"Otherwise"`.
+    /// This replaces any plain `otherwise`, and vice versa.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// # #[derive(Clone, Component, Debug, ScorerBuilder)]
+    /// # struct Curious;
+    /// # #[derive(Clone, Component, Debug, ActionBuilder)]
+    /// # struct Explore;
+    /// # #[derive(Clone, Component, Debug, ActionBuilder)]
+    /// # struct Loiter;
+    /// # fn main() {
+    /// // Only explore when curiosity beats 0.3. Otherwise, loiter.
+    /// Thinker::build()
+    ///     .picker(Highest)
+    ///     .when(Curious, Explore)
+    ///     .otherwise_with_score(Loiter, 0.3)
+    /// # ;
+    /// # }
+    /// ```
+    pub fn otherwise_with_score(
+        mut self,
+        otherwise: impl ActionBuilder + 'static,
+        baseline: f32,
+    ) -> Self {
+        let scorer = scorers::FixedScore::build(baseline).label("Otherwise");
+        self.otherwise_choice = Some(ChoiceBuilder::new(Arc::new(scorer), Arc::new(otherwise)));
+        self.otherwise = None;
+        self
+    }
+
+    /// Ambient `Action` to execute once the `Picker` hasn't picked any of the
+    /// given choices for `after`. Unlike [`ThinkerBuilder::otherwise`], which
+    /// kicks in as soon as nothing is picked, short gaps between real Actions
+    /// don't start it. As soon as a choice is picked again, the idle Action
+    /// is interrupted and the timer starts over.
+    ///
+    /// Once the timer is up, the idle Action takes precedence over
+    /// `otherwise`. Like `otherwise`, it waits for whatever Action is already
+    /// running to finish before starting.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// # use std::time::Duration;
+    /// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+    /// # struct Thirsty;
+    /// # #[derive(Debug, Clone, Component, ActionBuilder)]
+    /// # struct Drink;
+    /// # #[derive(Debug, Clone, Component, ActionBuilder)]
+    /// # struct Whistle;
+    /// # fn main() {
+    /// Thinker::build()
+    ///     .picker(FirstToScore::new(0.5))
+    ///     .when(Thirsty, Drink)
+    ///     .idle_after(Duration::from_secs(3), Whistle)
+    /// # ;
+    /// # }
+    /// ```
+    pub fn idle_after(mut self, after: Duration, action: impl ActionBuilder + 'static) -> Self {
+        self.idle = Some((after, ActionBuilderWrapper::new(Arc::new(action))));
+        self
+    }
+
+    /// Sets how long Actions get to wrap up after this Thinker switches
+    /// away from them. Switching sets them to [`ActionState::Interrupted`]
+    /// rather than [`ActionState::Cancelled`], so they can finish what
+    /// they're doing first. Actions that are still `Interrupted` after
+    /// `grace` get `Cancelled`, so ones that only look for `Cancelled` still
+    /// stop.
+    ///
+    /// Defaults to one second. Stopping the Thinker altogether always
+    /// cancels right away. See [`ThinkerBuilder::cancel_on_switch`] to skip
+    /// the interruption entirely.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// # use std::time::Duration;
+    /// # #[derive(Debug, Clone, Component, ScorerBuilder)]
+    /// # struct Thirsty;
+    /// # #[derive(Debug, Clone, Component, ActionBuilder)]
+    /// # struct Drink;
+    /// # #[derive(Debug, Clone, Component, ActionBuilder)]
+    /// # struct Chop;
+    /// # fn main() {
+    /// // Give the current swing half a second to land.
+    /// Thinker::build()
+    ///     .picker(FirstToScore::new(0.5))
+    ///     .when(Thirsty, Drink)
+    ///     .otherwise(Chop)
+    ///     .interrupt_on_switch(Duration::from_millis(500))
+    /// # ;
+    /// # }
+    /// ```
+    pub fn interrupt_on_switch(mut self, grace: Duration) -> Self {
+        self.interrupt_grace = Some(grace);
+        self
+    }
+
+    /// Cancels Actions right away when this Thinker switches away from them,
+    /// instead of [interrupting](ThinkerBuilder::interrupt_on_switch) them
+    /// first. This is how Thinkers used to behave before
+    /// [`ActionState::Interrupted`] existed.
+    pub fn cancel_on_switch(mut self) -> Self {
+        self.interrupt_grace = None;
+        self
+    }
+
+    /// Like [`ThinkerBuilder::when`], but takes builders that are already
+    /// shared. This is useful when assembling Thinkers from data, where you
+    /// usually have trait objects on hand rather than concrete types.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// # #[derive(Clone, Component, Debug, ScorerBuilder)]
+    /// # struct Thirsty;
+    /// # #[derive(Clone, Component, Debug, ActionBuilder)]
+    /// # struct Drink;
+    /// # fn main() {
+    /// let choices: Vec<(Arc<dyn ScorerBuilder>, Arc<dyn ActionBuilder>)> =
+    ///     vec![(Arc::new(Thirsty), Arc::new(Drink))];
+    /// let thinker = choices
+    ///     .into_iter()
+    ///     .fold(Thinker::build(), |thinker, (scorer, action)| {
+    ///         thinker.when_boxed(scorer, action)
+    ///     });
+    /// # }
+    /// ```
+    pub fn when_boxed(
+        mut self,
+        scorer: Arc<dyn ScorerBuilder>,
+        action: Arc<dyn ActionBuilder>,
+    ) -> Self {
+        self.choices.push(ChoiceBuilder::new(scorer, action));
+        self
+    }
+
+    /// Like [`ThinkerBuilder::otherwise`], but takes a builder that's already
+    /// shared.
+    pub fn otherwise_boxed(mut self, otherwise: Arc<dyn ActionBuilder>) -> Self {
+        self.otherwise = Some(ActionBuilderWrapper::new(otherwise));
+        self.otherwise_choice = None;
+        self
+    }
+
+    /// Gives this Thinker a scratch slot of type `T`, for its Actions to pass
+    /// things along to each other. The slot is just a `T` component on the
+    /// actor, inserted with its [`Default`] value when the Thinker is spawned
+    /// unless the actor already has one. Actions can read and write it
+    /// through [`Scratch`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// use big_brain::{actions::ActionQuery, thinker::Scratch};
+    /// # #[derive(Clone, Component, Debug, ScorerBuilder)]
+    /// # struct Thirsty;
+    /// #[derive(Component, Debug, Default)]
+    /// struct WaterSource(Option<Entity>);
+    ///
+    /// #[derive(Clone, Component, Debug, ActionBuilder)]
+    /// struct MoveToWaterSource;
+    ///
+    /// #[derive(Clone, Component, Debug, ActionBuilder)]
+    /// struct Drink;
+    ///
+    /// fn move_to_water_source(
+    ///     wells: Query<Entity, With<Name>>,
+    ///     mut actions: ActionQuery<MoveToWaterSource>,
+    ///     mut scratch: Scratch<WaterSource>,
+    /// ) {
+    ///     for (Actor(actor), mut state, _, _) in actions.iter_mut() {
+    ///         if *state == ActionState::Requested {
+    ///             scratch.set(*actor, WaterSource(wells.iter().next()));
+    ///             *state = ActionState::Success;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// fn drink(mut actions: ActionQuery<Drink>, scratch: Scratch<WaterSource>) {
+    ///     for (Actor(actor), mut state, _, _) in actions.iter_mut() {
+    ///         if *state == ActionState::Requested {
+    ///             let source = scratch.get(*actor).and_then(|source| source.0);
+    ///             info!("Drinking from {source:?}");
+    ///             *state = ActionState::Success;
+    ///         }
+    ///     }
+    /// }
+    /// # bevy::ecs::system::assert_is_system(move_to_water_source);
+    /// # bevy::ecs::system::assert_is_system(drink);
+    ///
+    /// # fn main() {
+    /// Thinker::build()
+    ///     .picker(FirstToScore::new(0.5))
+    ///     .with_scratch::<WaterSource>()
+    ///     .when(Thirsty, Steps::build().step(MoveToWaterSource).step(Drink))
+    /// # ;
+    /// # }
+    /// ```
+    pub fn with_scratch<T: Component + Default>(mut self) -> Self {
+        self.scratch.push(|cmd, actor| {
+            cmd.entity(actor).insert_if_new(T::default());
+        });
+        self
+    }
+
+    /// Runs the one-shot `system` every time the Thinker's current Action
+    /// finishes, with an [`ActionCompleted`] saying which actor it was for
+    /// and how it went. Register the system with
+    /// [`World::register_system`] (or [`Commands::register_system`]) first.
+    ///
+    /// The system runs from [`thinker_system`]'s commands, the first time the
+    /// Thinker sees the Action as [`ActionState::Success`] or
+    /// [`ActionState::Failure`], which includes Actions that fail after being
+    /// interrupted. Only the Thinker's current Action counts, not the
+    /// concurrent Actions of a [MultiThinker](ThinkerBuilder::multi) or the
+    /// children of Composite Actions.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// # #[derive(Clone, Component, Debug, ScorerBuilder)]
+    /// # struct Hungry;
+    /// # #[derive(Clone, Component, Debug, ActionBuilder)]
+    /// # struct Hunt;
+    /// #[derive(Component)]
+    /// struct Xp(u32);
+    ///
+    /// fn grant_xp(In(completed): In<ActionCompleted>, mut xp: Query<&mut Xp>) {
+    ///     if completed.state == ActionState::Success {
+    ///         if let Ok(mut xp) = xp.get_mut(completed.actor) {
+    ///             xp.0 += 10;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// fn spawn_hunter(world: &mut World) {
+    ///     let grant_xp = world.register_system(grant_xp);
+    ///     world.spawn((
+    ///         Xp(0),
+    ///         Thinker::build()
+    ///             .picker(FirstToScore::new(0.5))
+    ///             .when(Hungry, Hunt)
+    ///             .on_complete(grant_xp),
+    ///     ));
+    /// }
+    /// # spawn_hunter(&mut World::new());
+    /// ```
+    pub fn on_complete(mut self, system: SystemId<In<ActionCompleted>>) -> Self {
+        self.on_complete = Some(system);
+        self
+    }
+
+    /// Runs the one-shot `system` when the Thinker itself is done, with a
+    /// [`ThinkerFinished`] saying which actor it was for and how it went.
+    /// This is for finite routines, like a scripted sequence, where
+    /// something else needs to happen once the routine is over.
+    ///
+    /// A Thinker is done once its own [`ActionState`] is
+    /// [`ActionState::Success`] or [`ActionState::Failure`]. That happens
+    /// when it's used as an Action and its parent stops it, when you set it
+    /// yourself, or, with [`ThinkerBuilder::finish_when_exhausted`], when it
+    /// runs out of things to do. The system runs once per finish, from
+    /// [`thinker_system`]'s commands.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// # #[derive(Clone, Component, Debug, ActionBuilder)]
+    /// # struct OpenGate;
+    /// # #[derive(Clone, Component, Debug, ActionBuilder)]
+    /// # struct Announce;
+    /// #[derive(Resource)]
+    /// struct BossPhase(u32);
+    ///
+    /// fn next_phase(In(_): In<ThinkerFinished>, mut phase: ResMut<BossPhase>) {
+    ///     phase.0 += 1;
+    /// }
+    ///
+    /// fn spawn_intro(world: &mut World) {
+    ///     let next_phase = world.register_system(next_phase);
+    ///     world.spawn(
+    ///         Thinker::build()
+    ///             .picker(FirstToScore::new(0.5))
+    ///             .when_once(FixedScore::build(1.0), OpenGate)
+    ///             .when_once(FixedScore::build(0.9), Announce)
+    ///             .finish_when_exhausted(true)
+    ///             .on_finish(next_phase),
+    ///     );
+    /// }
+    /// # spawn_intro(&mut World::new());
+    /// ```
+    pub fn on_finish(mut self, system: SystemId<In<ThinkerFinished>>) -> Self {
+        self.on_finish = Some(system);
+        self
+    }
+
+    /// Wraps the Thinker up as [`ActionState::Success`] once every choice
+    /// added with [`ThinkerBuilder::when_once`] has been used up and there's
+    /// nothing else (no other choices, [`ThinkerBuilder::otherwise`],
+    /// [`ThinkerBuilder::idle_after`], or scheduled Actions) left for it to
+    /// do. Off by default, in which case such a Thinker just sits idle until
+    /// something stops it.
+    pub fn finish_when_exhausted(mut self, finish: bool) -> Self {
+        self.finish_when_exhausted = finish;
         self
     }
 
@@ -208,6 +924,71 @@ impl ThinkerBuilder {
         self.label = Some(label.as_ref().to_string());
         self
     }
+
+    /// Spawns the Thinker for `actor` right away, instead of leaving that to
+    /// the attach system, and returns the Thinker's [`Entity`]. The builder
+    /// itself is inserted on `actor` too, the same as if you'd added it as a
+    /// component, so removing it later still tears the Thinker down.
+    ///
+    /// `actor` shouldn't already have a Thinker. If it does, remove its
+    /// `ThinkerBuilder` first and give it a frame to clean up.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// # #[derive(Clone, Component, Debug, ActionBuilder)]
+    /// # struct Patrol;
+    /// #[derive(Component)]
+    /// struct Guard;
+    ///
+    /// fn hire_guards(mut cmd: Commands, new_guards: Query<Entity, Added<Guard>>) {
+    ///     for guard in new_guards.iter() {
+    ///         let thinker = Thinker::build()
+    ///             .picker(FirstToScore::new(0.5))
+    ///             .otherwise(Patrol)
+    ///             .attach_to(guard, &mut cmd);
+    ///         info!("Guard {guard:?} is thinking with {thinker:?}");
+    ///     }
+    /// }
+    /// ```
+    pub fn attach_to(self, actor: Entity, cmd: &mut Commands) -> Entity {
+        let thinker = actions::spawn_action(&self, cmd, actor);
+        cmd.entity(actor).insert((HasThinker(thinker), self));
+        thinker
+    }
+
+    /// Spawns `count` actors at once, each with the bundle returned by
+    /// `bundle` and a clone of this builder. This goes through
+    /// [`Commands::spawn_batch`], which is quite a bit faster than spawning
+    /// big crowds one at a time.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use big_brain::prelude::*;
+    /// # #[derive(Clone, Component, Debug, ActionBuilder)]
+    /// # struct Wander;
+    /// #[derive(Component)]
+    /// struct Villager;
+    ///
+    /// fn spawn_village(mut cmd: Commands) {
+    ///     Thinker::build()
+    ///         .picker(FirstToScore::new(0.5))
+    ///         .otherwise(Wander)
+    ///         .spawn_batch(&mut cmd, 5000, || (Villager, Transform::default()));
+    /// }
+    /// ```
+    pub fn spawn_batch<B: Bundle>(
+        self,
+        cmd: &mut Commands,
+        count: usize,
+        mut bundle: impl FnMut() -> B + Send + Sync + 'static,
+    ) {
+        cmd.spawn_batch((0..count).map(move |_| (bundle(), self.clone())));
+    }
 }
 
 impl ActionBuilder for ThinkerBuilder {
@@ -219,11 +1000,17 @@ impl ActionBuilder for ThinkerBuilder {
         );
         let _guard = span.enter();
         debug!("Spawning Thinker.");
+        for insert_scratch in self.scratch.iter() {
+            insert_scratch(cmd, actor);
+        }
+        let shared_scorers = scorers::SharedScorersScope::enter();
         let choices = self
             .choices
             .iter()
+            .chain(self.otherwise_choice.iter())
             .map(|choice| choice.build(cmd, actor, action_ent))
             .collect();
+        std::mem::drop(shared_scorers);
         std::mem::drop(_guard);
         cmd.entity(action_ent)
             .insert(Thinker {
@@ -233,13 +1020,27 @@ impl ActionBuilder for ThinkerBuilder {
                     .clone()
                     .expect("ThinkerBuilder must have a Picker"),
                 otherwise: self.otherwise.clone(),
+                idle: self.idle.clone(),
+                idle_since: None,
                 choices,
                 current_action: None,
                 current_action_label: None,
+                multi: self.multi,
+                concurrent_actions: Vec::new(),
                 span,
                 scheduled_actions: VecDeque::new(),
+                pooled_actions: Vec::new(),
+                switches: SwitchTracker::new(self.switch_window, self.warn_above_switches),
+                on_complete: self.on_complete,
+                completed: None,
+                on_finish: self.on_finish,
+                finished: false,
+                finish_when_exhausted: self.finish_when_exhausted,
+                interrupt_grace: self.interrupt_grace,
+                interrupted: Vec::new(),
             })
             .insert(Name::new("Thinker"))
+            .insert(PendingAction::default())
             .insert(ActionState::Requested);
     }
 
@@ -251,7 +1052,9 @@ impl ActionBuilder for ThinkerBuilder {
 pub fn thinker_component_attach_system(
     mut cmd: Commands,
     q: Query<(Entity, &ThinkerBuilder), Without<HasThinker>>,
+    max_depth: Option<Res<MaxBuildDepth>>,
 ) {
+    let _max_depth = MaxBuildDepth::enter(max_depth.as_deref());
     for (entity, thinker_builder) in q.iter() {
         let thinker = actions::spawn_action(thinker_builder, &mut cmd, entity);
         cmd.entity(entity).insert(HasThinker(thinker));
@@ -272,11 +1075,24 @@ pub fn thinker_component_detach_system(
 
 pub fn actor_gone_cleanup(
     mut cmd: Commands,
+    entities: &Entities,
     actors: Query<&ThinkerBuilder>,
-    q: Query<(Entity, &Actor)>,
+    unwinding: Query<(&Actor, &ActionState), With<Unwinding>>,
+    q: Query<(Entity, &Actor, Has<scorers::StandaloneScorer>)>,
 ) {
-    for (child, Actor(actor)) in q.iter() {
-        if actors.get(*actor).is_err() {
+    // Thinkers stopped by `cancel_actor` get to wrap up their Actions first.
+    let waiting: HashSet<Entity> = unwinding
+        .iter()
+        .filter(|(_, state)| !matches!(state, ActionState::Success | ActionState::Failure))
+        .map(|(Actor(actor), _)| *actor)
+        .collect();
+    for (child, Actor(actor), standalone) in q.iter() {
+        let gone = if standalone {
+            !entities.contains(*actor)
+        } else {
+            actors.get(*actor).is_err() && !waiting.contains(actor)
+        };
+        if gone {
             // Actor is gone. Let's clean up.
             if let Some(ent) = cmd.get_entity(child) {
                 ent.despawn_recursive();
@@ -285,15 +1101,277 @@ pub fn actor_gone_cleanup(
     }
 }
 
+/// Marks a Thinker that's being torn down by [`cancel_actor`]. It sticks
+/// around, along with its Scorers and Actions, until it's done unwinding.
+#[derive(Debug, Clone, Copy, Component, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct Unwinding;
+
+/// Gracefully stops and tears down everything Big Brain is doing for
+/// `actor`. Its Thinker is set to [`ActionState::Cancelled`] and unwinds the
+/// usual way, so the current Action (and any Composite Actions' children) get
+/// a chance to handle [`ActionState::Cancelled`] before they're despawned.
+/// Once the Thinker has wrapped up, it's despawned along with all of its
+/// Scorers and Actions during [`BigBrainSet::Cleanup`](crate::BigBrainSet::Cleanup).
+///
+/// The actor's [`ThinkerBuilder`] and [`HasThinker`] are removed right away,
+/// so it won't get a new Thinker. It's fine to despawn the actor itself right
+/// after calling this, but then the Actions wrapping up won't find it
+/// anymore: looking up its components from an Action's
+/// [`ActionState::Cancelled`] handling fails, so handle that instead of
+/// unwrapping. Keep the actor around until its Thinker is gone if its
+/// Actions need it to wrap up. Returns whether the actor had a Thinker to
+/// stop.
+///
+/// Actions that never acknowledge [`ActionState::Cancelled`] keep their
+/// Thinker around forever, so make sure yours do.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// #[derive(Component)]
+/// struct Health(f32);
+///
+/// #[derive(Component)]
+/// struct Carrying;
+///
+/// #[derive(Clone, Component, Debug, ActionBuilder)]
+/// struct Carry;
+///
+/// fn carry(mut cmd: Commands, mut carrying: Query<(&Actor, &mut ActionState), With<Carry>>) {
+///     for (Actor(actor), mut state) in carrying.iter_mut() {
+///         if *state == ActionState::Cancelled {
+///             // Drop what it's carrying, unless the actor is gone already.
+///             if let Some(mut actor) = cmd.get_entity(*actor) {
+///                 actor.remove::<Carrying>();
+///             }
+///             *state = ActionState::Failure;
+///         }
+///     }
+/// }
+///
+/// fn die(mut cmd: Commands, dead: Query<(Entity, &Health), Changed<Health>>) {
+///     for (actor, health) in dead.iter() {
+///         if health.0 <= 0.0 {
+///             cmd.queue(move |world: &mut World| {
+///                 big_brain::cancel_actor(world, actor);
+///                 world.despawn(actor);
+///             });
+///         }
+///     }
+/// }
+/// ```
+pub fn cancel_actor(world: &mut World, actor: Entity) -> bool {
+    let Some(thinker) = world.get::<HasThinker>(actor).map(HasThinker::entity) else {
+        return false;
+    };
+    world
+        .entity_mut(actor)
+        .remove::<(ThinkerBuilder, HasThinker)>();
+    let Ok(mut thinker_ent) = world.get_entity_mut(thinker) else {
+        return false;
+    };
+    thinker_ent.insert(Unwinding);
+    if let Some(mut state) = thinker_ent.get_mut::<ActionState>() {
+        if !matches!(*state, ActionState::Success | ActionState::Failure) {
+            debug!("Cancelling actor's Thinker.");
+            *state = ActionState::Cancelled;
+        }
+    }
+    true
+}
+
+/// Reads and writes the scratch slots Thinkers set up with
+/// [`ThinkerBuilder::with_scratch`], by actor. See there for an example.
+#[derive(SystemParam)]
+pub struct Scratch<'w, 's, T: Component> {
+    query: Query<'w, 's, &'static mut T>,
+}
+
+impl<T: Component> Scratch<'_, '_, T> {
+    /// The `actor`'s scratch slot, if it has one.
+    pub fn get(&self, actor: Entity) -> Option<&T> {
+        self.query.get(actor).ok()
+    }
+
+    /// The `actor`'s scratch slot, mutably, if it has one.
+    pub fn get_mut(&mut self, actor: Entity) -> Option<Mut<'_, T>> {
+        self.query.get_mut(actor).ok()
+    }
+
+    /// Overwrites the `actor`'s scratch slot. Does nothing if it doesn't
+    /// have one.
+    pub fn set(&mut self, actor: Entity, value: T) {
+        if let Some(mut slot) = self.get_mut(actor) {
+            *slot = value;
+        }
+    }
+}
+
 #[derive(Component, Debug, Reflect)]
 pub struct HasThinker(Entity);
 
 impl HasThinker {
+    /// The actor's Thinker entity. [`Actor::entity`] goes the other way, from
+    /// the Thinker (or any of its Scorers and Actions) back to the actor.
     pub fn entity(&self) -> Entity {
         self.0
     }
 }
 
+/// What a [`ThinkerBuilder::on_complete`] system is run with.
+#[derive(Debug, Clone)]
+pub struct ActionCompleted {
+    /// The actor whose Thinker was running the Action.
+    pub actor: Entity,
+    /// The Action entity that finished. It's cleaned up right after this, so
+    /// it may already be gone by the time the system runs.
+    pub action: Entity,
+    /// The label of the Action's [`ActionBuilder`], if any.
+    pub label: Option<String>,
+    /// How it went: either [`ActionState::Success`] or
+    /// [`ActionState::Failure`].
+    pub state: ActionState,
+}
+
+/// What a [`ThinkerBuilder::on_finish`] system is run with.
+#[derive(Debug, Clone)]
+pub struct ThinkerFinished {
+    /// The actor the Thinker was thinking for.
+    pub actor: Entity,
+    /// The Thinker entity. When it's used as an Action, its parent cleans it
+    /// up right after this, so it may already be gone by the time the system
+    /// runs.
+    pub thinker: Entity,
+    /// How it went: either [`ActionState::Success`] or
+    /// [`ActionState::Failure`].
+    pub state: ActionState,
+}
+
+/// What a [`Thinker`] decided to do this tick, before it's acted upon.
+///
+/// Thinkers work in two phases, both inside [`BigBrainSet::Thinkers`]:
+///
+/// 1. [`thinker_system`] looks at the current [`Score`]s and asks the
+///    [`Picker`] what to do, writing the result here.
+/// 2. [`pending_action_system`] applies the decision: interrupting the
+///    current Action if needed, and spawning the new one.
+///
+/// Systems in [`BigBrainSet::Decisions`] run between the two, and can look
+/// at or override the pending decision. This is handy for cutscenes,
+/// tutorials, and tests, where you occasionally need to tell an actor what
+/// to do regardless of what its Thinker would rather do.
+///
+/// ### Example
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # #[derive(Clone, Component, Debug, ActionBuilder)]
+/// # struct WaveAtPlayer;
+/// #[derive(Resource)]
+/// struct Cutscene {
+///     playing: bool,
+///     wave: Arc<dyn ActionBuilder>,
+/// }
+///
+/// fn cutscene_override(cutscene: Res<Cutscene>, mut pending: Query<&mut PendingAction>) {
+///     if cutscene.playing {
+///         for mut pending in pending.iter_mut() {
+///             pending.set(cutscene.wave.clone());
+///         }
+///     }
+/// }
+///
+/// # fn main() {
+/// App::new()
+///     .insert_resource(Cutscene {
+///         playing: true,
+///         wave: Arc::new(WaveAtPlayer),
+///     })
+///     .add_plugins(BigBrainPlugin::new(PreUpdate))
+///     .add_systems(PreUpdate, cutscene_override.in_set(BigBrainSet::Decisions));
+/// # }
+/// ```
+///
+/// [`BigBrainSet::Thinkers`]: crate::BigBrainSet::Thinkers
+/// [`BigBrainSet::Decisions`]: crate::BigBrainSet::Decisions
+#[derive(Component, Debug, Default)]
+pub struct PendingAction {
+    decision: Option<Decision>,
+}
+
+#[derive(Debug, Clone)]
+enum Decision {
+    /// Nothing was picked. Let the current action wrap up.
+    Nothing,
+    /// A choice was picked, and should replace the current action.
+    Picked {
+        action: ActionBuilderWrapper,
+        scorer: Scorer,
+    },
+    /// Choices were picked by a MultiThinker, and should all run at once.
+    PickedMany(Vec<(ActionBuilderWrapper, Scorer)>),
+    /// The next scheduled action should be started.
+    Scheduled(ActionBuilderWrapper),
+    /// Nothing was picked, so the `otherwise` action should run once the
+    /// current action is done.
+    Otherwise(ActionBuilderWrapper),
+    /// Set by the user. Replaces the current action.
+    Override(Arc<dyn ActionBuilder>),
+}
+
+impl PendingAction {
+    /// The Action the Thinker is about to run, if it's decided on one this
+    /// tick. For a MultiThinker, this is the first of the picked choices'
+    /// Actions.
+    pub fn action(&self) -> Option<&Arc<dyn ActionBuilder>> {
+        match self.decision.as_ref()? {
+            Decision::Nothing => None,
+            Decision::Picked { action, .. }
+            | Decision::Scheduled(action)
+            | Decision::Otherwise(action) => Some(&action.1),
+            Decision::PickedMany(picks) => picks.first().map(|(action, _)| &action.1),
+            Decision::Override(action) => Some(action),
+        }
+    }
+
+    /// The Scorer entity of the choice that was picked, if the pending Action
+    /// comes from one. For a MultiThinker, this is the first of the picked
+    /// choices' Scorers.
+    pub fn scorer(&self) -> Option<Entity> {
+        match self.decision.as_ref()? {
+            Decision::Picked { scorer, .. } => Some(scorer.0),
+            Decision::PickedMany(picks) => picks.first().map(|(_, scorer)| scorer.0),
+            _ => None,
+        }
+    }
+
+    /// Runs `action` instead of whatever the Thinker decided, interrupting
+    /// the current Action if it's a different one.
+    ///
+    /// Actions are told apart by their builder, so keep the `Arc` around and
+    /// pass the same one in every tick you want to keep the override going.
+    /// Passing a fresh one every tick would restart the Action every tick.
+    pub fn set(&mut self, action: Arc<dyn ActionBuilder>) {
+        self.decision = Some(Decision::Override(action));
+    }
+
+    /// Don't start anything new this tick. The current Action, if any, keeps
+    /// going until it's done on its own.
+    pub fn clear(&mut self) {
+        self.decision = Some(Decision::Nothing);
+    }
+}
+
+/// How long [`thinker_system`] may spend thinking each frame before leaving
+/// the remaining Thinkers for the next one. This is a CPU budget rather than
+/// an in-game timer, so unlike Action timers it's measured in real time and
+/// doesn't care whether the game is paused. Ignored while
+/// [`DeterministicThinking`] is in effect.
 pub struct ThinkerIterations {
     index: usize,
     max_duration: Duration,
@@ -312,145 +1390,1074 @@ impl Default for ThinkerIterations {
     }
 }
 
+/// When present, [`thinker_system`] evaluates every Thinker every frame, no
+/// matter how long it takes, instead of stopping once it's used up its
+/// [`ThinkerIterations`] budget. Turn it on with
+/// [`BigBrainPlugin::deterministic`](crate::BigBrainPlugin::deterministic).
+///
+/// With the budget, how many Thinkers get to think in a given frame depends
+/// on how fast the machine happens to be running, so the same inputs can
+/// lead to different decisions on different runs. That's fine for most
+/// games, but not for lockstep multiplayer, replays, or anything else that
+/// needs to be reproducible. The tradeoff is that a frame with lots of
+/// expensive Thinkers takes as long as it takes, instead of spreading the
+/// work out over the next few frames.
+#[derive(Debug, Clone, Copy, Default, Resource, Reflect)]
+pub struct DeterministicThinking;
+
+/// Rolling record of a Thinker's Action switches, for
+/// [`Thinker::switches_per_second`].
+#[derive(Debug, Default)]
+struct SwitchTracker {
+    window: Duration,
+    warn_above: Option<f32>,
+    warned: bool,
+    now: Duration,
+    switches: VecDeque<Duration>,
+    last_action: Option<ActionBuilderWrapper>,
+}
+
+impl SwitchTracker {
+    fn new(window: Duration, warn_above: Option<f32>) -> Self {
+        Self {
+            window,
+            warn_above,
+            ..Default::default()
+        }
+    }
+
+    fn rate(&self) -> f32 {
+        let recent = self
+            .switches
+            .iter()
+            .filter(|at| self.now.saturating_sub(**at) <= self.window)
+            .count();
+        if self.window.is_zero() {
+            0.0
+        } else {
+            recent as f32 / self.window.as_secs_f32()
+        }
+    }
+}
+
+/// Notes down whether the Thinker's current Action changed since the last
+/// time this was called, and warns if it's been changing too often.
+fn track_switches(thinker: &mut Mut<Thinker>, now: Duration) {
+    let tracker = &mut thinker.bypass_change_detection().switches;
+    tracker.now = now;
+    while tracker
+        .switches
+        .front()
+        .is_some_and(|at| now.saturating_sub(*at) > tracker.window)
+    {
+        tracker.switches.pop_front();
+    }
+    let Some((_, current)) = thinker.current_action.clone() else {
+        return;
+    };
+    let tracker = &mut thinker.bypass_change_detection().switches;
+    let switched = tracker
+        .last_action
+        .as_ref()
+        .is_some_and(|last| last.0 != current.0);
+    tracker.last_action = Some(current);
+    if switched {
+        tracker.switches.push_back(now);
+    }
+    let Some(limit) = tracker.warn_above else {
+        return;
+    };
+    let rate = tracker.rate();
+    if rate <= limit {
+        tracker.warned = false;
+    } else if switched && !tracker.warned {
+        tracker.warned = true;
+        let _guard = thinker.span.enter();
+        warn!(
+            "Thinker is switching Actions {:.1} times per second, above the limit of {:.1}. Its choices might be scoring too close together.",
+            rate, limit
+        );
+    }
+}
+
+/// Marks the Scorers of the choices after the one each lazy Thinker just
+/// picked with [`SkipScoring`](scorers::SkipScoring), and unmarks the ones it
+/// needs again. See [`Picker::is_lazy`]. Scorers shared with an earlier
+/// choice are never skipped.
+pub fn lazy_scoring_system(
+    mut cmd: Commands,
+    thinkers: Query<(&Thinker, &PendingAction)>,
+    children: Query<&Children>,
+    skipped: Query<(), With<scorers::SkipScoring>>,
+    mut scores: Query<&mut Score>,
+) {
+    for (thinker, pending) in thinkers.iter() {
+        if !thinker.picker.is_lazy() {
+            continue;
+        }
+        let cutoff = pending
+            .scorer()
+            .and_then(|scorer| {
+                thinker
+                    .choices
+                    .iter()
+                    .position(|choice| choice.scorer.0 == scorer)
+            })
+            .map_or(thinker.choices.len(), |idx| idx + 1);
+        let (needed, rest) = thinker.choices.split_at(cutoff);
+        let with_children = |choice: &Choice| {
+            std::iter::once(choice.scorer.0)
+                .chain(children.iter_descendants(choice.scorer.0))
+                .chain(choice.shared_scorers.clone())
+        };
+        let needed: HashSet<Entity> = needed.iter().flat_map(with_children).collect();
+        for &scorer in &needed {
+            if skipped.contains(scorer) {
+                cmd.entity(scorer).remove::<scorers::SkipScoring>();
+            }
+        }
+        for scorer in rest.iter().flat_map(with_children) {
+            if needed.contains(&scorer) || skipped.contains(scorer) {
+                continue;
+            }
+            if let Ok(mut score) = scores.get_mut(scorer) {
+                score.unset();
+            }
+            cmd.entity(scorer).insert(scorers::SkipScoring);
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments, clippy::manual_is_multiple_of)]
 pub fn thinker_system(
     mut cmd: Commands,
     mut iterations: Local<ThinkerIterations>,
-    mut thinker_q: Query<(Entity, &Actor, &mut Thinker)>,
+    deterministic: Option<Res<DeterministicThinking>>,
+    time: Res<Time>,
+    mut thinker_q: Query<(Entity, &Actor, &mut Thinker, &mut PendingAction)>,
     scores: Query<&Score>,
     mut action_states: Query<&mut actions::ActionState>,
     action_spans: Query<&ActionSpan>,
-    scorer_spans: Query<&ScorerSpan>,
 ) {
     let start = Instant::now();
-    for (thinker_ent, Actor(actor), mut thinker) in thinker_q.iter_mut().skip(iterations.index) {
+    if deterministic.is_some() {
+        iterations.index = 0;
+    }
+    for (thinker_ent, Actor(actor), thinker, pending) in thinker_q.iter_mut().skip(iterations.index)
+    {
         iterations.index += 1;
+        think(
+            &mut cmd,
+            thinker_ent,
+            *actor,
+            thinker,
+            pending,
+            &scores,
+            &mut action_states,
+            &action_spans,
+            time.elapsed(),
+        );
+        if deterministic.is_none()
+            && iterations.index % 500 == 0
+            && start.elapsed() > iterations.max_duration
+        {
+            return;
+        }
+    }
+    iterations.index = 0;
+}
 
-        let thinker_state = action_states
-            .get_mut(thinker_ent)
-            .expect("Where is it?")
-            .clone();
+/// Applies whatever [`PendingAction`] each executing [`Thinker`] ended up
+/// with. See [`PendingAction`] for how this fits in with [`thinker_system`].
+#[allow(clippy::too_many_arguments)]
+pub fn pending_action_system(
+    mut cmd: Commands,
+    mut thinker_q: Query<(Entity, &Actor, &mut Thinker, &mut PendingAction)>,
+    scores: Query<&Score>,
+    mut action_states: Query<&mut actions::ActionState>,
+    action_spans: Query<&ActionSpan>,
+    scorer_spans: Query<&ScorerSpan>,
+    children: Query<&Children>,
+    pooling: Option<Res<actions::ActionPooling>>,
+    time: Res<Time>,
+    max_depth: Option<Res<MaxBuildDepth>>,
+) {
+    let _max_depth = MaxBuildDepth::enter(max_depth.as_deref());
+    let now = time.elapsed();
+    for (thinker_ent, Actor(actor), mut thinker, pending) in thinker_q.iter_mut() {
+        escalate_interruptions(&mut thinker, &mut action_states, now);
+        apply_decision(
+            &mut cmd,
+            thinker_ent,
+            *actor,
+            thinker.reborrow(),
+            pending,
+            &scores,
+            &mut action_states,
+            &action_spans,
+            &scorer_spans,
+            &children,
+            pooling.is_some(),
+        );
+        track_switches(&mut thinker, now);
+    }
+}
 
-        let thinker_span = thinker.span.clone();
-        let _thinker_span_guard = thinker_span.enter();
+/// The state Actions get when the Thinker switches away from them, as
+/// opposed to when the Thinker itself is stopped, which always cancels. See
+/// [`ThinkerBuilder::interrupt_on_switch`].
+fn switch_away_state(thinker: &Thinker) -> ActionState {
+    if thinker.interrupt_grace.is_some() {
+        ActionState::Interrupted
+    } else {
+        ActionState::Cancelled
+    }
+}
 
-        match thinker_state {
-            ActionState::Init => {
-                let mut act_state = action_states.get_mut(thinker_ent).expect("???");
-                debug!("Initializing thinker.");
-                *act_state = ActionState::Requested;
+/// Cancels the Thinker's Actions that have been [`ActionState::Interrupted`]
+/// for longer than its grace period. See
+/// [`ThinkerBuilder::interrupt_on_switch`].
+fn escalate_interruptions(
+    thinker: &mut Mut<Thinker>,
+    states: &mut Query<&mut ActionState>,
+    now: Duration,
+) {
+    let Some(grace) = thinker.interrupt_grace else {
+        return;
+    };
+    let running: Vec<Entity> = thinker
+        .current_action
+        .iter()
+        .chain(thinker.concurrent_actions.iter())
+        .map(|(action, _)| action.0)
+        .collect();
+    let mut interrupted = std::mem::take(&mut thinker.interrupted);
+    interrupted.retain(|(action, _)| {
+        running.contains(action) && states.get(*action).ok() == Some(&ActionState::Interrupted)
+    });
+    for action in running {
+        let Ok(mut state) = states.get_mut(action) else {
+            continue;
+        };
+        if *state != ActionState::Interrupted {
+            continue;
+        }
+        match interrupted.iter().find(|(ent, _)| *ent == action) {
+            Some((_, since)) if now.saturating_sub(*since) >= grace => {
+                debug!("Action didn't wrap up after being interrupted. Cancelling it.");
+                *state = ActionState::Cancelled;
             }
-            ActionState::Requested => {
-                let mut act_state = action_states.get_mut(thinker_ent).expect("???");
-                debug!("Thinker requested. Starting execution.");
-                *act_state = ActionState::Executing;
+            Some(_) => {}
+            None => interrupted.push((action, now)),
+        }
+    }
+    thinker.interrupted = interrupted;
+}
+
+/// Runs one round of thinking for a single actor right away, instead of
+/// waiting for [`thinker_system`] and [`pending_action_system`] to get to it.
+/// If the actor doesn't have a [`Thinker`] yet, one gets attached first.
+/// Returns the Thinker's current Action, if it ended up with one.
+///
+/// This is meant for spawn-time logic, so newly-spawned actors can hit the
+/// ground running. Don't call it every frame: the regular systems already do
+/// that job, and do it for every actor at once.
+///
+/// Before deciding, this runs Big Brain's built-in Scorer systems on the
+/// actor's own Scorers, so the Thinker sees up-to-date [`Score`]s even for
+/// Scorers spawned by this very call. Other actors' Scorers are left alone,
+/// and so are the ones that keep track of time, like
+/// [`SustainedScorer`](scorers::SustainedScorer). Your own Scorer systems
+/// don't run here, so the Scorers they're in charge of keep whatever Score
+/// they already have (`0.0` for ones spawned by this call) until those
+/// systems next run. The Action it picks is only requested here, so it
+/// starts running the next time your Action systems do.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct Patrol;
+/// fn spawn_guard(world: &mut World) {
+///     let guard = world
+///         .spawn(
+///             Thinker::build()
+///                 .picker(FirstToScore::new(0.5))
+///                 .otherwise(Patrol),
+///         )
+///         .id();
+///     let patrol = big_brain::evaluate_thinker_now(world, guard);
+///     assert!(patrol.is_some());
+/// }
+/// # let mut world = World::new();
+/// # spawn_guard(&mut world);
+/// ```
+pub fn evaluate_thinker_now(world: &mut World, actor: Entity) -> Option<Entity> {
+    run_thinker_now(world, actor, None)
+}
+
+/// Does the work of [`evaluate_thinker_now`], running the Action labeled
+/// `resume` instead of whatever the Thinker picks, if it has one.
+fn run_thinker_now(world: &mut World, actor: Entity, resume: Option<&str>) -> Option<Entity> {
+    let _max_depth = MaxBuildDepth::enter(world.get_resource());
+    let thinker_ent = match world.get::<HasThinker>(actor) {
+        Some(HasThinker(thinker)) => *thinker,
+        None => {
+            let builder = world.get::<ThinkerBuilder>(actor)?.clone();
+            let mut queue = CommandQueue::default();
+            let mut cmd = Commands::new(&mut queue, world);
+            let thinker = actions::spawn_action(&builder, &mut cmd, actor);
+            cmd.entity(actor).insert(HasThinker(thinker));
+            queue.apply(world);
+            thinker
+        }
+    };
+    scorers::score_actor_now(world, actor);
+    let pooling = world.contains_resource::<actions::ActionPooling>();
+    let now = world
+        .get_resource::<Time>()
+        .map(|time| time.elapsed())
+        .unwrap_or_default();
+    let mut state = SystemState::<(
+        Commands,
+        Query<(&mut Thinker, &mut PendingAction)>,
+        Query<&Score>,
+        Query<&mut ActionState>,
+        Query<&ActionSpan>,
+        Query<&ScorerSpan>,
+        Query<&Children>,
+    )>::new(world);
+    let (mut cmd, mut thinkers, scores, mut action_states, action_spans, scorer_spans, children) =
+        state.get_mut(world);
+    let (thinker, pending) = thinkers.get_mut(thinker_ent).ok()?;
+    think(
+        &mut cmd,
+        thinker_ent,
+        actor,
+        thinker,
+        pending,
+        &scores,
+        &mut action_states,
+        &action_spans,
+        now,
+    );
+    let (mut thinker, mut pending) = thinkers.get_mut(thinker_ent).ok()?;
+    if let Some(builder) = resume.and_then(|label| thinker.action_by_label(label)) {
+        pending.set(builder);
+    }
+    apply_decision(
+        &mut cmd,
+        thinker_ent,
+        actor,
+        thinker.reborrow(),
+        pending,
+        &scores,
+        &mut action_states,
+        &action_spans,
+        &scorer_spans,
+        &children,
+        pooling,
+    );
+    track_switches(&mut thinker, now);
+    state.apply(world);
+    world
+        .get::<Thinker>(thinker_ent)?
+        .current_action
+        .as_ref()
+        .map(|(action, _)| action.entity())
+}
+
+/// What an actor's [`Thinker`] is in the middle of, for save games. Take one
+/// with [`snapshot_thinker`], and pick up where it left off with
+/// [`restore_thinker`]. With the `serde` feature, this implements
+/// `Serialize` and `Deserialize`.
+///
+/// Actions are identified by their labels, since the builders themselves
+/// can't be saved. That means:
+///
+/// * Only labeled Actions can be restored, and if several of a Thinker's
+///   choices share a label, the first one wins.
+/// * Scheduled Actions are restored the same way, so only the ones that
+///   share a label with one of the Thinker's choices, `otherwise` or idle
+///   Actions come back. The rest are skipped with a warning; rebuild them
+///   and [schedule](Thinker::schedule_action) them again yourself.
+/// * Only the top-level [`Steps`](actions::Steps) position is saved. Steps
+///   nested inside other Actions start over.
+/// * Any progress an Action made should live in components on the actor,
+///   which you can save and load like any other component.
+#[derive(Debug, Clone, Default, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThinkerSnapshot {
+    /// The label of the Action the Thinker was running, if any.
+    pub current_action: Option<String>,
+    /// The [`ActionState`] that Action was in.
+    pub current_state: Option<ActionState>,
+    /// The [active step](actions::Steps::active_step), if that Action is a
+    /// [`Steps`](actions::Steps).
+    pub active_step: Option<usize>,
+    /// The labels of the Actions [scheduled](Thinker::schedule_action) to run
+    /// next, in order.
+    pub scheduled_actions: Vec<Option<String>>,
+}
+
+/// Takes a [`ThinkerSnapshot`] of `actor`'s Thinker. Returns `None` if it
+/// doesn't have one attached.
+pub fn snapshot_thinker(world: &World, actor: Entity) -> Option<ThinkerSnapshot> {
+    let thinker = world.get::<Thinker>(world.get::<HasThinker>(actor)?.entity())?;
+    let current = thinker.current_action.as_ref().map(|(action, _)| action.0);
+    Some(ThinkerSnapshot {
+        current_action: thinker.current_action_label().map(String::from),
+        current_state: current.and_then(|action| world.get::<ActionState>(action).cloned()),
+        active_step: current
+            .and_then(|action| world.get::<actions::Steps>(action))
+            .map(actions::Steps::active_step),
+        scheduled_actions: thinker
+            .scheduled_actions
+            .iter()
+            .map(|action| action.1.label().map(String::from))
+            .collect(),
+    })
+}
+
+/// Restores a [`ThinkerSnapshot`] onto `actor`, which should have been
+/// spawned with the same [`ThinkerBuilder`] the snapshot was taken from.
+/// Like [`evaluate_thinker_now`], this attaches the Thinker if needed and
+/// decides right away, except that the saved Action is started again instead
+/// of whatever scores best, and a [`Steps`](actions::Steps) jumps back to the
+/// saved step. The Action starts over from [`ActionState::Requested`], so it
+/// gets a chance to set itself back up.
+///
+/// If the saved Action had already finished, or none of the Thinker's
+/// Actions have its label anymore, the Thinker just decides as usual. Saved
+/// scheduled Actions are queued back up afterwards, to run once the current
+/// one is done. Returns the Thinker's current Action, if it ended up with
+/// one.
+///
+/// ### Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use big_brain::prelude::*;
+/// # #[derive(Debug, Clone, Component, ActionBuilder)]
+/// # struct Patrol;
+/// fn guard() -> ThinkerBuilder {
+///     Thinker::build()
+///         .picker(FirstToScore::new(0.5))
+///         .otherwise(Steps::build().label("Patrol").step(Patrol).step(Patrol))
+/// }
+///
+/// fn load_guard(world: &mut World, snapshot: &ThinkerSnapshot) {
+///     let guard = world.spawn(guard()).id();
+///     big_brain::restore_thinker(world, guard, snapshot);
+/// }
+/// # let mut world = World::new();
+/// # load_guard(&mut world, &ThinkerSnapshot::default());
+/// ```
+pub fn restore_thinker(
+    world: &mut World,
+    actor: Entity,
+    snapshot: &ThinkerSnapshot,
+) -> Option<Entity> {
+    let resume = match snapshot.current_state {
+        Some(ActionState::Success | ActionState::Failure) => None,
+        _ => snapshot.current_action.as_deref(),
+    };
+    let action = run_thinker_now(world, actor, resume);
+    let mut thinker = world.get_mut::<Thinker>(world.get::<HasThinker>(actor)?.entity())?;
+    for label in snapshot.scheduled_actions.iter() {
+        match label
+            .as_deref()
+            .and_then(|label| thinker.wrapper_by_label(label))
+        {
+            Some(wrapper) => {
+                let wrapper = wrapper.clone();
+                thinker.scheduled_actions.push_back(wrapper);
             }
-            ActionState::Success | ActionState::Failure => {}
-            ActionState::Cancelled => {
-                debug!("Thinker cancelled. Cleaning up.");
-                if let Some(current) = &mut thinker.current_action {
-                    let action_span = action_spans.get(current.0 .0).expect("Where is it?");
-                    debug!("Cancelling current action because thinker was cancelled.");
-                    let state = action_states.get_mut(current.0.0).expect("Couldn't find a component corresponding to the current action. This is definitely a bug.").clone();
-                    match state {
-                        ActionState::Success | ActionState::Failure => {
-                            debug!("Action already wrapped up on its own. Cleaning up action in Thinker.");
-                            if let Some(ent) = cmd.get_entity(current.0 .0) {
-                                ent.despawn_recursive();
-                            }
-                            thinker.current_action = None;
-                        }
-                        ActionState::Cancelled => {
-                            debug!("Current action already cancelled.");
-                        }
-                        _ => {
-                            let mut state = action_states.get_mut(current.0.0).expect("Couldn't find a component corresponding to the current action. This is definitely a bug.");
-                            debug!( "Action is still executing. Attempting to cancel it before wrapping up Thinker cancellation.");
-                            action_span.span.in_scope(|| {
-                                debug!("Parent thinker was cancelled. Cancelling action.");
-                            });
-                            *state = ActionState::Cancelled;
-                        }
+            None => warn!(
+                "Couldn't find a scheduled Action labeled {:?} to restore. Skipping it.",
+                label
+            ),
+        }
+    }
+    let action = action?;
+    if resume.is_some() && thinker.current_action_label() == resume {
+        if let (Some(step), Some(mut steps)) = (
+            snapshot.active_step,
+            world.get_mut::<actions::Steps>(action),
+        ) {
+            steps.jump_to(step);
+        }
+    }
+    Some(action)
+}
+
+/// Runs a single Thinker's part of [`thinker_system`]: starts it up if it was
+/// just requested, and figures out its next [`Decision`].
+#[allow(clippy::too_many_arguments)]
+fn think(
+    cmd: &mut Commands,
+    thinker_ent: Entity,
+    actor: Entity,
+    mut thinker: Mut<Thinker>,
+    mut pending: Mut<PendingAction>,
+    scores: &Query<&Score>,
+    action_states: &mut Query<&mut ActionState>,
+    action_spans: &Query<&ActionSpan>,
+    now: Duration,
+) {
+    let mut thinker_state = action_states
+        .get_mut(thinker_ent)
+        .expect("Where is it?")
+        .clone();
+
+    let thinker_span = thinker.span.clone();
+    let _thinker_span_guard = thinker_span.enter();
+
+    if thinker_state == ActionState::Requested {
+        let mut act_state = action_states.get_mut(thinker_ent).expect("???");
+        debug!("Thinker requested. Starting execution.");
+        *act_state = ActionState::Executing;
+        // Think right away, so a freshly-attached Thinker makes its first
+        // decision this frame instead of the next one.
+        thinker_state = ActionState::Executing;
+    }
+
+    match thinker_state {
+        ActionState::Init => {
+            let mut act_state = action_states.get_mut(thinker_ent).expect("???");
+            debug!("Initializing thinker.");
+            *act_state = ActionState::Requested;
+        }
+        ActionState::Requested
+        | ActionState::Yielded
+        | ActionState::Success
+        | ActionState::Failure => {}
+        ActionState::Cancelled | ActionState::Interrupted => {
+            debug!("Thinker {:?}. Cleaning up.", thinker_state);
+            let idle = thinker.current_action.is_none() && thinker.concurrent_actions.is_empty();
+            if let Some(current) = &mut thinker.current_action {
+                let action_span = action_spans.get(current.0 .0).expect("Where is it?");
+                debug!("Stopping current action because thinker was stopped.");
+                let state = action_states.get_mut(current.0.0).expect("Couldn't find a component corresponding to the current action. This is definitely a bug.").clone();
+                match state {
+                    ActionState::Success | ActionState::Failure => {
+                        debug!(
+                            "Action already wrapped up on its own. Cleaning up action in Thinker."
+                        );
+                        actions::despawn_completed(cmd, current.0 .0);
+                        thinker.current_action = None;
                     }
-                } else {
-                    let mut act_state = action_states.get_mut(thinker_ent).expect("???");
-                    debug!("No current thinker action. Wrapping up Thinker as Succeeded.");
-                    *act_state = ActionState::Success;
+                    ActionState::Cancelled => {
+                        debug!("Current action already cancelled.");
+                    }
+                    ActionState::Interrupted if thinker_state == ActionState::Interrupted => {
+                        debug!("Current action already interrupted.");
+                    }
+                    _ => {
+                        let mut state = action_states.get_mut(current.0.0).expect("Couldn't find a component corresponding to the current action. This is definitely a bug.");
+                        debug!( "Action is still executing. Attempting to stop it before wrapping up Thinker.");
+                        action_span.span.in_scope(|| {
+                            debug!(
+                                "Parent thinker was {:?}. Passing that on to the action.",
+                                thinker_state
+                            );
+                        });
+                        *state = thinker_state.clone();
+                    }
+                }
+            }
+            stop_concurrent_actions(cmd, &mut thinker, action_states, &thinker_state);
+            for (Action(pooled), _) in thinker.pooled_actions.drain(..) {
+                actions::despawn_completed(cmd, pooled);
+            }
+            if idle {
+                let mut act_state = action_states.get_mut(thinker_ent).expect("???");
+                debug!("No current thinker action. Wrapping up Thinker as Succeeded.");
+                *act_state = ActionState::Success;
+            }
+        }
+        ActionState::Executing => {
+            #[cfg(feature = "trace")]
+            trace!("Thinker is executing. Thinking...");
+            report_completion(cmd, actor, &mut thinker, action_states);
+            if consume_once_choices(cmd, &mut thinker, action_states)
+                && thinker.finish_when_exhausted
+                && is_exhausted(&thinker, action_states)
+            {
+                debug!("All one-shot choices used up. Wrapping up Thinker as Succeeded.");
+                let finished = thinker
+                    .current_action
+                    .take()
+                    .into_iter()
+                    .chain(thinker.concurrent_actions.drain(..))
+                    .collect::<Vec<_>>();
+                let pooled = thinker.pooled_actions.drain(..);
+                for (Action(action), _) in finished.into_iter().chain(pooled) {
+                    actions::despawn_completed(cmd, action);
                 }
+                *action_states.get_mut(thinker_ent).expect("???") = ActionState::Success;
+                report_finish(cmd, thinker_ent, actor, &mut thinker, action_states);
+                return;
             }
-            ActionState::Executing => {
+            // Think about what action we're supposed to be taking. We do
+            // this every tick, because we might change our mind. The
+            // decision is acted upon by `pending_action_system`.
+            let (picked, picks) = if thinker.multi {
+                let picks: Vec<_> = thinker
+                    .picker
+                    .pick_many(&thinker.choices, scores)
+                    .into_iter()
+                    .map(|choice| (choice.action.clone(), choice.scorer))
+                    .collect();
+                (None, picks)
+            } else {
+                let current = thinker.current_choice();
+                let picked = thinker
+                    .picker
+                    .pick_with_current(&thinker.choices, scores, current)
+                    .map(|choice| (choice.action.clone(), choice.scorer));
+                (picked, Vec::new())
+            };
+            if picks.is_empty() && picked.is_none() {
+                if thinker.idle.is_some() && thinker.idle_since.is_none() {
+                    thinker.idle_since = Some(now);
+                }
+            } else if thinker.idle_since.is_some() {
+                thinker.idle_since = None;
+            }
+            let idle = thinker.idle.as_ref().and_then(|(after, action)| {
+                let since = thinker.idle_since?;
+                (now.saturating_sub(since) >= *after).then(|| action.clone())
+            });
+            let decision = if !picks.is_empty() {
                 #[cfg(feature = "trace")]
-                trace!("Thinker is executing. Thinking...");
-                if let Some(choice) = thinker.picker.pick(&thinker.choices, &scores) {
-                    // Think about what action we're supposed to be taking. We do this
-                    // every tick, because we might change our mind.
-                    // ...and then execute it (details below).
-                    #[cfg(feature = "trace")]
-                    trace!("Action picked. Executing picked action.");
-                    let action = choice.action.clone();
-                    let scorer = choice.scorer;
-                    let score = scores.get(choice.scorer.0).expect("Where is it?");
-                    exec_picked_action(
-                        &mut cmd,
-                        *actor,
-                        &mut thinker,
-                        &action,
-                        &mut action_states,
-                        &action_spans,
-                        Some((&scorer, score)),
-                        &scorer_spans,
-                        true,
-                    );
-                } else if should_schedule_action(&mut thinker, &mut action_states) {
-                    debug!("Spawning scheduled action.");
-                    let action = thinker
+                trace!("{} actions picked.", picks.len());
+                Decision::PickedMany(picks)
+            } else if let Some((action, scorer)) = picked {
+                #[cfg(feature = "trace")]
+                trace!("Action picked.");
+                Decision::Picked { action, scorer }
+            } else if should_schedule_action(&mut thinker, action_states) {
+                Decision::Scheduled(
+                    thinker
                         .scheduled_actions
-                        .pop_front()
-                        .expect("we literally just checked if it was there.");
-                    let new_action = actions::spawn_action(action.1.as_ref(), &mut cmd, *actor);
-                    thinker.current_action = Some((Action(new_action), action.clone()));
-                    thinker.current_action_label = Some(action.1.label().map(|s| s.into()));
-                } else if let Some(default_action_ent) = &thinker.otherwise {
-                    // Otherwise, let's just execute the default one! (if it's there)
-                    let default_action_ent = default_action_ent.clone();
-                    exec_picked_action(
-                        &mut cmd,
-                        *actor,
-                        &mut thinker,
-                        &default_action_ent,
-                        &mut action_states,
-                        &action_spans,
-                        None,
-                        &scorer_spans,
-                        false,
-                    );
-                } else if let Some((action_ent, _)) = &thinker.current_action {
-                    let action_span = action_spans.get(action_ent.0).expect("Where is it?");
-                    let _guard = action_span.span.enter();
-                    let mut curr_action_state = action_states.get_mut(action_ent.0).expect("Couldn't find a component corresponding to the current action. This is definitely a bug.");
-                    let previous_done = matches!(
-                        *curr_action_state,
-                        ActionState::Success | ActionState::Failure
-                    );
-                    if previous_done {
-                        debug!(
-                            "Action completed and nothing was picked. Despawning action entity.",
-                        );
-                        // Despawn the action itself.
-                        if let Some(ent) = cmd.get_entity(action_ent.0) {
-                            ent.despawn_recursive();
-                        }
+                        .front()
+                        .expect("we literally just checked if it was there.")
+                        .clone(),
+                )
+            } else if let Some(idle) = idle {
+                Decision::Otherwise(idle)
+            } else if let Some(otherwise) = &thinker.otherwise {
+                Decision::Otherwise(otherwise.clone())
+            } else {
+                Decision::Nothing
+            };
+            pending.decision = Some(decision);
+        }
+    }
+    report_finish(cmd, thinker_ent, actor, &mut thinker, action_states);
+}
+
+/// Runs a single Thinker's part of [`pending_action_system`].
+#[allow(clippy::too_many_arguments)]
+fn apply_decision(
+    cmd: &mut Commands,
+    thinker_ent: Entity,
+    actor: Entity,
+    mut thinker: Mut<Thinker>,
+    mut pending: Mut<PendingAction>,
+    scores: &Query<&Score>,
+    action_states: &mut Query<&mut ActionState>,
+    action_spans: &Query<&ActionSpan>,
+    scorer_spans: &Query<&ScorerSpan>,
+    children: &Query<&Children>,
+    pool: bool,
+) {
+    let Some(decision) = pending.decision.take() else {
+        return;
+    };
+    if action_states.get(thinker_ent).ok() != Some(&ActionState::Executing) {
+        return;
+    }
+    let thinker_span = thinker.span.clone();
+    let _thinker_span_guard = thinker_span.enter();
+    if thinker.multi {
+        let picks = match &decision {
+            Decision::PickedMany(picks) => picks.as_slice(),
+            _ => &[],
+        };
+        exec_picked_actions(
+            cmd,
+            actor,
+            &mut thinker,
+            picks,
+            action_states,
+            scores,
+            scorer_spans,
+            children,
+        );
+    }
+    let yielded = thinker
+        .current_action
+        .as_ref()
+        .is_some_and(|(action, _)| has_yielded(action.0, action_states, children));
+    if yielded {
+        #[cfg(feature = "trace")]
+        trace!("Current action yielded. Keeping it for another tick.");
+        return;
+    }
+    match decision {
+        Decision::PickedMany(_) => {
+            // Picked choices take over from `otherwise` and scheduled
+            // actions.
+            if let Some(action_ent) = thinker.current_action.as_ref().map(|(a, _)| a.0) {
+                let mut state = action_states.get_mut(action_ent).expect("Couldn't find a component corresponding to the current action. This is definitely a bug.");
+                match *state {
+                    ActionState::Executing | ActionState::Requested | ActionState::Yielded => {
+                        debug!("Choices were picked. Requesting current action interruption.");
+                        *state = switch_away_state(&thinker);
+                    }
+                    ActionState::Init | ActionState::Success | ActionState::Failure => {
+                        debug!("Choices were picked. Despawning current action entity.");
+                        actions::despawn_completed(cmd, action_ent);
                         thinker.current_action = None;
-                    } else if *curr_action_state == ActionState::Init {
-                        *curr_action_state = ActionState::Requested;
                     }
+                    ActionState::Cancelled | ActionState::Interrupted => {}
                 }
             }
         }
-        if iterations.index % 500 == 0 && start.elapsed() > iterations.max_duration {
-            return;
+        Decision::Picked { action, scorer } => {
+            #[cfg(feature = "trace")]
+            trace!("Executing picked action.");
+            let score = scores.get(scorer.0).ok();
+            exec_picked_action(
+                cmd,
+                actor,
+                &mut thinker,
+                &action,
+                action_states,
+                action_spans,
+                score.map(|score| (&scorer, score)),
+                scorer_spans,
+                true,
+                pool,
+            );
+        }
+        Decision::Override(builder) => {
+            debug!("Executing overridden action.");
+            let action = wrapper_for(&thinker, builder);
+            exec_picked_action(
+                cmd,
+                actor,
+                &mut thinker,
+                &action,
+                action_states,
+                action_spans,
+                None,
+                scorer_spans,
+                true,
+                pool,
+            );
+        }
+        Decision::Scheduled(action) => {
+            debug!("Spawning scheduled action.");
+            if thinker
+                .scheduled_actions
+                .front()
+                .is_some_and(|next| next.0 == action.0)
+            {
+                thinker.scheduled_actions.pop_front();
+            }
+            let new_action = actions::spawn_shared_action(&action.1, cmd, actor);
+            thinker.current_action_label = Some(action.1.label().map(|s| s.into()));
+            thinker.current_action = Some((Action(new_action), action));
+        }
+        Decision::Otherwise(action) => {
+            // Otherwise, let's just execute the default one!
+            exec_picked_action(
+                cmd,
+                actor,
+                &mut thinker,
+                &action,
+                action_states,
+                action_spans,
+                None,
+                scorer_spans,
+                false,
+                pool,
+            );
+        }
+        Decision::Nothing => {
+            if let Some((action_ent, _)) = thinker.current_action.clone() {
+                let action_span = action_spans.get(action_ent.0).expect("Where is it?");
+                let _guard = action_span.span.enter();
+                let mut curr_action_state = action_states.get_mut(action_ent.0).expect("Couldn't find a component corresponding to the current action. This is definitely a bug.");
+                let previous_done = matches!(
+                    *curr_action_state,
+                    ActionState::Success | ActionState::Failure
+                );
+                if previous_done {
+                    debug!("Action completed and nothing was picked. Retiring action entity.",);
+                    retire_current_action(cmd, &mut thinker, pool);
+                } else if *curr_action_state == ActionState::Init {
+                    *curr_action_state = ActionState::Requested;
+                }
+            }
         }
     }
-    iterations.index = 0;
+}
+
+/// Finds the wrapper the Thinker already uses for `builder`, so overriding
+/// with the same builder over and over doesn't restart its Action.
+fn wrapper_for(thinker: &Thinker, builder: Arc<dyn ActionBuilder>) -> ActionBuilderWrapper {
+    thinker
+        .current_action
+        .iter()
+        .map(|(_, wrapper)| wrapper)
+        .chain(thinker.choices.iter().map(|choice| &choice.action))
+        .chain(thinker.otherwise.iter())
+        .chain(thinker.idle.iter().map(|(_, idle)| idle))
+        .find(|wrapper| Arc::ptr_eq(&wrapper.1, &builder))
+        .cloned()
+        .unwrap_or_else(|| ActionBuilderWrapper::new(builder))
+}
+
+/// Runs the Thinker's [`ThinkerBuilder::on_complete`] system, if it has one,
+/// the first time it sees its current Action finished.
+fn report_completion(
+    cmd: &mut Commands,
+    actor: Entity,
+    thinker: &mut Mut<Thinker>,
+    states: &Query<&mut ActionState>,
+) {
+    let Some(system) = thinker.on_complete else {
+        return;
+    };
+    let Some((action, wrapper)) = &thinker.current_action else {
+        return;
+    };
+    let action = action.entity();
+    let state = states.get(action).ok().cloned();
+    let Some(state @ (ActionState::Success | ActionState::Failure)) = state else {
+        // Pooled Action entities get reused, so forget about it once it's
+        // running again.
+        if thinker.completed.is_some() {
+            thinker.completed = None;
+        }
+        return;
+    };
+    if thinker.completed == Some(action) {
+        return;
+    }
+    let label = wrapper.1.label().map(String::from);
+    thinker.completed = Some(action);
+    debug!("Current action completed. Running on_complete system.");
+    cmd.run_system_with_input(
+        system,
+        ActionCompleted {
+            actor,
+            action,
+            label,
+            state,
+        },
+    );
+}
+
+/// Runs the Thinker's [`ThinkerBuilder::on_finish`] system, if it has one,
+/// the first time it sees the Thinker itself finished.
+fn report_finish(
+    cmd: &mut Commands,
+    thinker_ent: Entity,
+    actor: Entity,
+    thinker: &mut Mut<Thinker>,
+    states: &Query<&mut ActionState>,
+) {
+    let Some(system) = thinker.on_finish else {
+        return;
+    };
+    let state = states.get(thinker_ent).ok().cloned();
+    let Some(state @ (ActionState::Success | ActionState::Failure)) = state else {
+        if thinker.finished {
+            thinker.finished = false;
+        }
+        return;
+    };
+    if thinker.finished {
+        return;
+    }
+    thinker.finished = true;
+    debug!("Thinker finished. Running on_finish system.");
+    cmd.run_system_with_input(
+        system,
+        ThinkerFinished {
+            actor,
+            thinker: thinker_ent,
+            state,
+        },
+    );
+}
+
+/// Whether a Thinker has nothing left to do: no choices, nothing to fall
+/// back on, and no Actions still running.
+fn is_exhausted(thinker: &Thinker, states: &Query<&mut ActionState>) -> bool {
+    thinker.choices.is_empty()
+        && thinker.otherwise.is_none()
+        && thinker.idle.is_none()
+        && thinker.scheduled_actions.is_empty()
+        && thinker
+            .current_action
+            .iter()
+            .chain(thinker.concurrent_actions.iter())
+            .all(|(Action(action), _)| {
+                matches!(
+                    states.get(*action),
+                    Ok(ActionState::Success | ActionState::Failure) | Err(_)
+                )
+            })
+}
+
+/// Removes any one-shot choice whose action just succeeded, along with its
+/// scorer. Returns whether any were removed.
+fn consume_once_choices(
+    cmd: &mut Commands,
+    thinker: &mut Mut<Thinker>,
+    states: &mut Query<&mut ActionState>,
+) -> bool {
+    let mut consumed = false;
+    let succeeded: Vec<_> = thinker
+        .current_action
+        .iter()
+        .chain(thinker.concurrent_actions.iter())
+        .filter(|(action_ent, _)| states.get(action_ent.0).ok() == Some(&ActionState::Success))
+        .map(|(_, ActionBuilderWrapper(id, _))| *id)
+        .collect();
+    for current_id in succeeded {
+        let Some(idx) = thinker
+            .choices
+            .iter()
+            .position(|choice| choice.once && choice.action.0 == current_id)
+        else {
+            continue;
+        };
+        debug!("One-shot choice succeeded. Removing it from the Thinker.");
+        consumed = true;
+        let choice = thinker.choices.remove(idx);
+        // Shared scorers might still be in use by other choices, directly or
+        // nested in a composite.
+        let mut scorers = choice.shared_scorers;
+        if !scorers.contains(&choice.scorer.0) {
+            scorers.push(choice.scorer.0);
+        }
+        for scorer in scorers {
+            if thinker
+                .choices
+                .iter()
+                .any(|other| other.uses_scorer(scorer))
+            {
+                continue;
+            }
+            if let Some(ent) = cmd.get_entity(scorer) {
+                ent.despawn_recursive();
+            }
+        }
+    }
+    consumed
+}
+
+/// Brings a MultiThinker's concurrent actions in line with what was picked:
+/// actions of choices that weren't picked are interrupted, and picked choices
+/// that aren't running yet get their actions spawned.
+#[allow(clippy::too_many_arguments)]
+fn exec_picked_actions(
+    cmd: &mut Commands,
+    actor: Entity,
+    thinker: &mut Mut<Thinker>,
+    picks: &[(ActionBuilderWrapper, Scorer)],
+    states: &mut Query<&mut ActionState>,
+    scores: &Query<&Score>,
+    scorer_spans: &Query<&ScorerSpan>,
+    children: &Query<&Children>,
+) {
+    let switch_away = switch_away_state(thinker);
+    let mut running = std::mem::take(&mut thinker.concurrent_actions);
+    running.retain(|(action_ent, ActionBuilderWrapper(id, _))| {
+        let picked = picks.iter().any(|(action, _)| action.0 == *id);
+        let yielded = has_yielded(action_ent.0, states, children);
+        let mut state = states.get_mut(action_ent.0).expect("Couldn't find a component corresponding to a concurrent action. This is definitely a bug.");
+        match *state {
+            ActionState::Init if picked => {
+                *state = ActionState::Requested;
+                true
+            }
+            ActionState::Executing | ActionState::Requested if !picked && !yielded => {
+                debug!("Choice is no longer picked. Requesting action interruption.");
+                *state = switch_away.clone();
+                true
+            }
+            ActionState::Init | ActionState::Success | ActionState::Failure => {
+                // Picked choices whose action is done get a fresh one below.
+                debug!("Concurrent action completed. Despawning action entity.");
+                actions::despawn_completed(cmd, action_ent.0);
+                false
+            }
+            _ => true,
+        }
+    });
+    for (action, scorer) in picks {
+        if running.iter().any(|(_, running)| running.0 == action.0) {
+            continue;
+        }
+        if let (Ok(score), Ok(scorer_span)) = (scores.get(scorer.0), scorer_spans.get(scorer.0)) {
+            let _guard = scorer_span.span.enter();
+            debug!("Scorer chosen with score {}", score.get());
+        }
+        debug!("Spawning concurrent action.");
+        let new_action = actions::spawn_shared_action(&action.1, cmd, actor);
+        running.push((Action(new_action), action.clone()));
+    }
+    thinker.concurrent_actions = running;
+}
+
+/// Whether any Action in the tree under `action` is
+/// [`ActionState::Yielded`]. They're resumed by
+/// [`resume_yielded_system`](actions::resume_yielded_system).
+fn has_yielded(
+    action: Entity,
+    states: &Query<&mut ActionState>,
+    children: &Query<&Children>,
+) -> bool {
+    states.get(action).ok() == Some(&ActionState::Yielded)
+        || children.get(action).is_ok_and(|kids| {
+            kids.iter()
+                .any(|child| has_yielded(*child, states, children))
+        })
+}
+
+/// Passes a stopped Thinker's state on to its concurrent actions, and cleans
+/// up the ones that are done.
+fn stop_concurrent_actions(
+    cmd: &mut Commands,
+    thinker: &mut Mut<Thinker>,
+    states: &mut Query<&mut ActionState>,
+    thinker_state: &ActionState,
+) {
+    thinker.concurrent_actions.retain(|(action_ent, _)| {
+        let mut state = states.get_mut(action_ent.0).expect("Couldn't find a component corresponding to a concurrent action. This is definitely a bug.");
+        match *state {
+            ActionState::Success | ActionState::Failure => {
+                actions::despawn_completed(cmd, action_ent.0);
+                false
+            }
+            ActionState::Cancelled => true,
+            ActionState::Interrupted if *thinker_state == ActionState::Interrupted => true,
+            _ => {
+                *state = thinker_state.clone();
+                true
+            }
+        }
+    });
 }
 
 fn should_schedule_action(
@@ -488,6 +2495,51 @@ fn should_schedule_action(
     }
 }
 
+/// Gets rid of the Thinker's finished current Action. With
+/// [`ActionPooling`](actions::ActionPooling), its entity is kept around for
+/// the next time the same choice gets picked, unless one is already pooled.
+fn retire_current_action(cmd: &mut Commands, thinker: &mut Mut<Thinker>, pool: bool) {
+    let Some((action, wrapper)) = thinker.current_action.take() else {
+        return;
+    };
+    let already_pooled = thinker
+        .pooled_actions
+        .iter()
+        .any(|(_, pooled)| pooled.0 == wrapper.0);
+    if pool && !already_pooled {
+        if let Some(mut ent) = cmd.get_entity(action.entity()) {
+            ent.despawn_descendants();
+        }
+        thinker.pooled_actions.push((action, wrapper));
+    } else {
+        actions::despawn_completed(cmd, action.entity());
+    }
+}
+
+/// Spawns an Action for `picked_action`, reusing a pooled entity if the
+/// Thinker has one for it.
+fn spawn_or_reuse_action(
+    cmd: &mut Commands,
+    thinker: &mut Mut<Thinker>,
+    picked_action: &ActionBuilderWrapper,
+    actor: Entity,
+) -> Entity {
+    let pooled = thinker
+        .pooled_actions
+        .iter()
+        .position(|(_, pooled)| pooled.0 == picked_action.0);
+    match pooled {
+        Some(idx) => {
+            let (Action(action_ent), _) = thinker.pooled_actions.swap_remove(idx);
+            debug!("Reusing pooled action entity.");
+            cmd.entity(action_ent).clear();
+            actions::build_shared_action(&picked_action.1, cmd, action_ent, actor);
+            action_ent
+        }
+        None => actions::spawn_shared_action(&picked_action.1, cmd, actor),
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn exec_picked_action(
     cmd: &mut Commands,
@@ -499,6 +2551,7 @@ fn exec_picked_action(
     scorer_info: Option<(&Scorer, &Score)>,
     scorer_spans: &Query<&ScorerSpan>,
     override_current: bool,
+    pool: bool,
 ) {
     // If we do find one, then we need to grab the corresponding
     // component for it. The "action" that `picker.pick()` returns
@@ -521,7 +2574,7 @@ fn exec_picked_action(
         );
         let action_span = action_spans.get(action_ent.0).expect("Where is it?");
         let _guard = action_span.span.enter();
-        if (!Arc::ptr_eq(current_id, &picked_action.0) && override_current) || previous_done {
+        if (*current_id != picked_action.0 && override_current) || previous_done {
             // So we've picked a different action than we were
             // currently executing. Just like before, we grab the
             // actual Action component (and we assume it exists).
@@ -537,16 +2590,13 @@ fn exec_picked_action(
                 }
             }
             match *curr_action_state {
-                ActionState::Executing | ActionState::Requested => {
-                    debug!("Previous action is still executing. Requesting action cancellation.",);
-                    *curr_action_state = ActionState::Cancelled;
+                ActionState::Executing | ActionState::Requested | ActionState::Yielded => {
+                    debug!("Previous action is still executing. Requesting action interruption.",);
+                    *curr_action_state = switch_away_state(thinker);
                 }
                 ActionState::Init | ActionState::Success | ActionState::Failure => {
-                    debug!("Previous action already completed. Despawning action entity.",);
-                    // Despawn the action itself.
-                    if let Some(ent) = cmd.get_entity(action_ent.0) {
-                        ent.despawn_recursive();
-                    }
+                    debug!("Previous action already completed. Retiring action entity.",);
+                    retire_current_action(cmd, thinker, pool);
                     if let Some((Scorer(ent), score)) = scorer_info {
                         let scorer_span = scorer_spans.get(*ent).expect("Where is it?");
                         let _guard = scorer_span.span.enter();
@@ -555,14 +2605,14 @@ fn exec_picked_action(
                     std::mem::drop(_guard);
                     debug!("Spawning next action");
                     let new_action =
-                        Action(actions::spawn_action(picked_action.1.as_ref(), cmd, actor));
+                        Action(spawn_or_reuse_action(cmd, thinker, picked_action, actor));
                     thinker.current_action = Some((new_action, picked_action.clone()));
                     thinker.current_action_label = Some(picked_action.1.label().map(|s| s.into()));
                 }
-                ActionState::Cancelled => {
+                ActionState::Cancelled | ActionState::Interrupted => {
                     #[cfg(feature = "trace")]
                     trace!(
-                    "Cancellation already requested. Waiting for action to be marked as completed.",
+                    "Interruption already requested. Waiting for action to be marked as completed.",
                 )
                 }
             };
@@ -592,7 +2642,7 @@ fn exec_picked_action(
             debug!("Winning scorer chosen with score {}", score.get());
         }
         debug!("No current action. Spawning new action.");
-        let new_action = actions::spawn_action(picked_action.1.as_ref(), cmd, actor);
+        let new_action = spawn_or_reuse_action(cmd, thinker, picked_action, actor);
         thinker.current_action = Some((Action(new_action), picked_action.clone()));
         thinker.current_action_label = Some(picked_action.1.label().map(|s| s.into()));
     }
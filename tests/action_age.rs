@@ -0,0 +1,86 @@
+use bevy::{prelude::*, time::TimeUpdateStrategy, utils::Duration};
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Forever;
+
+fn forever(mut query: Query<&mut ActionState, With<Forever>>) {
+    for mut state in query.iter_mut() {
+        if *state == ActionState::Requested {
+            *state = ActionState::Executing;
+        }
+    }
+}
+
+fn age(app: &mut App) -> Duration {
+    let world = app.world_mut();
+    world
+        .query_filtered::<&ActionAge, With<Forever>>()
+        .single(world)
+        .elapsed()
+}
+
+#[test]
+fn action_age_stops_while_paused() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .add_systems(PreUpdate, forever.in_set(BigBrainSet::Actions));
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(FixedScore::build(1.0), Forever),
+    );
+    for _ in 0..10 {
+        app.update();
+    }
+    let before = age(&mut app);
+    assert!(before > Duration::ZERO);
+
+    app.world_mut().resource_mut::<Time<Virtual>>().pause();
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_eq!(age(&mut app), before);
+
+    app.world_mut().resource_mut::<Time<Virtual>>().unpause();
+    for _ in 0..10 {
+        app.update();
+    }
+    assert!(age(&mut app) > before);
+}
+
+#[test]
+fn action_age_starts_once_requested() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+    let actor = app
+        .world_mut()
+        .spawn(Thinker::build().picker(FirstToScore::new(0.5)))
+        .id();
+    // Nobody requests this one, so it sits in Init until we do.
+    let action = big_brain::actions::spawn_action(&Forever, &mut app.world_mut().commands(), actor);
+    for _ in 0..5 {
+        app.update();
+    }
+    let age = app.world().get::<ActionAge>(action).unwrap();
+    assert_eq!(age.started(), None);
+    assert_eq!(age.elapsed(), Duration::ZERO);
+
+    *app.world_mut().get_mut::<ActionState>(action).unwrap() = ActionState::Requested;
+    app.update();
+    let started = app.world().get::<ActionAge>(action).unwrap().started();
+    assert!(started.is_some());
+
+    for _ in 0..5 {
+        app.update();
+    }
+    let age = app.world().get::<ActionAge>(action).unwrap();
+    assert_eq!(age.started(), started);
+    assert!(age.elapsed() > Duration::ZERO);
+}
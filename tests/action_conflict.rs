@@ -0,0 +1,80 @@
+use std::sync::{Arc, Mutex};
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::log::tracing_subscriber::{layer::Context, prelude::*, Layer, Registry};
+use bevy::prelude::*;
+use bevy::utils::tracing::{
+    self,
+    field::{Field, Visit},
+    Event, Level, Subscriber,
+};
+use big_brain::actions::action_conflict_system;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, Default, Reflect, ActionBuilder)]
+struct Drink;
+
+/// Collects the messages of every warning logged while it's active.
+#[derive(Clone, Default)]
+struct Warnings(Arc<Mutex<Vec<String>>>);
+
+impl<S: Subscriber> Layer<S> for Warnings {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        struct Message<'a>(&'a mut String);
+        impl Visit for Message<'_> {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                if field.name() == "message" {
+                    *self.0 = format!("{value:?}");
+                }
+            }
+        }
+        if *event.metadata().level() == Level::WARN {
+            let mut message = String::new();
+            event.record(&mut Message(&mut message));
+            self.0.lock().unwrap().push(message);
+        }
+    }
+}
+
+fn conflict_warnings(register: bool) -> Vec<String> {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        BigBrainPlugin::new(PreUpdate).warn_on_action_conflicts(true),
+    ));
+    if register {
+        app.register_action::<Drink>();
+    }
+    app.world_mut().spawn(
+        Thinker::build()
+            .multi(true)
+            .picker(AllToScore::new(0.5))
+            .when(FixedScore::build(1.0), Drink)
+            .when(FixedScore::build(0.9), Drink),
+    );
+    for _ in 0..5 {
+        app.update();
+    }
+    let warnings = Warnings::default();
+    let subscriber = Registry::default().with(warnings.clone());
+    tracing::subscriber::with_default(subscriber, || {
+        app.world_mut()
+            .run_system_once(action_conflict_system)
+            .unwrap();
+    });
+    let warnings = warnings.0.lock().unwrap().clone();
+    warnings
+}
+
+#[test]
+fn warns_about_registered_actions() {
+    let warnings = conflict_warnings(true);
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("several live Actions with a"));
+    assert!(warnings[0].contains("Drink"));
+}
+
+#[test]
+fn ignores_unregistered_actions() {
+    assert!(conflict_warnings(false).is_empty());
+}
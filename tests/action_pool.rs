@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Blink;
+
+fn blink(mut query: Query<&mut ActionState, With<Blink>>) {
+    for mut state in query.iter_mut() {
+        if matches!(*state, ActionState::Requested | ActionState::Executing) {
+            *state = ActionState::Success;
+        }
+    }
+}
+
+/// Runs a Thinker that keeps picking an Action that finishes right away, and
+/// returns every distinct entity that Action ran on.
+fn blink_entities(pool: bool) -> Vec<Entity> {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        BigBrainPlugin::new(PreUpdate).pool_actions(pool),
+    ))
+    .add_systems(PreUpdate, blink.in_set(BigBrainSet::Actions));
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(FixedScore::build(1.0), Blink),
+    );
+    let mut seen = Vec::new();
+    for _ in 0..10 {
+        app.update();
+        let world = app.world_mut();
+        for entity in world
+            .query_filtered::<Entity, With<Blink>>()
+            .iter(world)
+            .collect::<Vec<_>>()
+        {
+            if !seen.contains(&entity) {
+                seen.push(entity);
+            }
+        }
+    }
+    seen
+}
+
+#[test]
+fn finished_actions_are_respawned_without_pooling() {
+    assert!(blink_entities(false).len() > 2);
+}
+
+#[test]
+fn finished_actions_reuse_their_entity_with_pooling() {
+    assert_eq!(blink_entities(true).len(), 1);
+}
+
+#[derive(Component)]
+struct Blinked;
+
+#[derive(Default, Resource)]
+struct Blinks {
+    runs: usize,
+    stale: usize,
+}
+
+fn blink_once(
+    mut cmd: Commands,
+    mut query: Query<(Entity, &mut ActionState, Has<Blinked>), With<Blink>>,
+    mut blinks: ResMut<Blinks>,
+) {
+    for (entity, mut state, blinked) in query.iter_mut() {
+        if *state == ActionState::Requested {
+            blinks.runs += 1;
+            if blinked {
+                blinks.stale += 1;
+            }
+            cmd.entity(entity).insert(Blinked);
+            *state = ActionState::Success;
+        }
+    }
+}
+
+#[test]
+fn reused_actions_start_clean() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        BigBrainPlugin::new(PreUpdate).pool_actions(true),
+    ))
+    .init_resource::<Blinks>()
+    .add_systems(PreUpdate, blink_once.in_set(BigBrainSet::Actions));
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(FixedScore::build(1.0), Blink),
+    );
+    for _ in 0..10 {
+        app.update();
+    }
+    let blinks = app.world().resource::<Blinks>();
+    assert!(blinks.runs > 1);
+    assert_eq!(blinks.stale, 0);
+}
@@ -0,0 +1,81 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Drink;
+
+#[derive(Resource, Default)]
+struct Changes(Vec<ActionStateChanged>);
+
+/// Takes one frame to get going, and another to finish.
+fn drink(mut query: Query<&mut ActionState, With<Drink>>) {
+    for mut state in query.iter_mut() {
+        match *state {
+            ActionState::Requested => *state = ActionState::Executing,
+            ActionState::Executing => *state = ActionState::Success,
+            _ => {}
+        }
+    }
+}
+
+fn record(mut events: EventReader<ActionStateChanged>, mut changes: ResMut<Changes>) {
+    changes.0.extend(events.read().cloned());
+}
+
+fn drinking_app(emit: bool) -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        BigBrainPlugin::new(PreUpdate).emit_action_state_events(emit),
+    ))
+    .add_systems(PreUpdate, drink.in_set(BigBrainSet::Actions));
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when(FixedScore::build(1.0), Drink),
+        )
+        .id();
+    (app, actor)
+}
+
+#[test]
+fn reports_each_frames_state_change() {
+    let (mut app, actor) = drinking_app(true);
+    app.init_resource::<Changes>()
+        .add_systems(Last, record.after(BigBrainSet::Cleanup));
+    for _ in 0..6 {
+        app.update();
+    }
+    let changes = &app.world().resource::<Changes>().0;
+    assert!(changes.iter().all(|change| change.actor == actor));
+    let drinks: Vec<_> = changes
+        .iter()
+        .filter(|change| change.label.as_deref() == Some("Drink"))
+        .collect();
+    let transitions: Vec<_> = drinks
+        .iter()
+        .filter(|change| change.action == drinks[0].action)
+        .map(|change| (change.old.clone(), change.new.clone()))
+        .collect();
+    assert_eq!(
+        transitions,
+        [
+            (ActionState::Init, ActionState::Executing),
+            (ActionState::Executing, ActionState::Success),
+        ]
+    );
+}
+
+#[test]
+fn sends_nothing_unless_asked_to() {
+    let (mut app, _) = drinking_app(false);
+    for _ in 0..3 {
+        app.update();
+    }
+    assert!(app
+        .world()
+        .get_resource::<Events<ActionStateChanged>>()
+        .is_none());
+}
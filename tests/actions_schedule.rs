@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Default, Resource)]
+struct Greetings(u32);
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Greet;
+
+fn greet(mut query: Query<&mut ActionState, With<Greet>>, mut greetings: ResMut<Greetings>) {
+    for mut state in query.iter_mut() {
+        if *state == ActionState::Requested {
+            greetings.0 += 1;
+            *state = ActionState::Success;
+        }
+    }
+}
+
+#[test]
+fn actions_run_in_their_own_schedule() {
+    let mut app = App::new();
+    let plugin = BigBrainPlugin::new(PreUpdate).set_actions_schedule(Update);
+    app.add_systems(
+        plugin.actions_schedule(),
+        greet.in_set(BigBrainSet::Actions),
+    )
+    .add_plugins((MinimalPlugins, plugin))
+    .init_resource::<Greetings>();
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            // Steps are driven by a built-in system, so this also checks
+            // that those moved over to the actions schedule.
+            .when(FixedScore::build(1.0), Greet.then(Greet)),
+    );
+    for _ in 0..20 {
+        app.update();
+    }
+    assert!(app.world().resource::<Greetings>().0 >= 4);
+}
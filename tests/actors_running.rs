@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use big_brain::scorers::resource_score_system;
+
+mod common;
+use common::keep_going;
+
+#[derive(Resource)]
+struct Suspicion(f32);
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Investigate;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Patrol;
+
+#[test]
+fn lists_actors_executing_the_action() {
+    let mut app = common::app();
+    app.insert_resource(Suspicion(1.0)).add_systems(
+        PreUpdate,
+        (
+            resource_score_system::<Suspicion>.in_set(BigBrainSet::Scorers),
+            (keep_going::<Investigate>, keep_going::<Patrol>).in_set(BigBrainSet::Actions),
+        ),
+    );
+    let guard = || {
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(
+                ResourceScore::build(|suspicion: &Suspicion| suspicion.0),
+                Investigate,
+            )
+            .when(
+                ResourceScore::build(|suspicion: &Suspicion| 1.0 - suspicion.0),
+                Patrol,
+            )
+    };
+    let mut guards: Vec<_> = (0..3)
+        .map(|_| app.world_mut().spawn(guard()).id())
+        .collect();
+    let bored = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .otherwise(Patrol),
+        )
+        .id();
+    for _ in 0..3 {
+        app.update();
+    }
+    guards.sort();
+    assert_eq!(
+        big_brain::actors_running::<Investigate>(app.world_mut()),
+        guards
+    );
+    assert_eq!(
+        big_brain::actors_running::<Patrol>(app.world_mut()),
+        vec![bored]
+    );
+
+    app.world_mut().resource_mut::<Suspicion>().0 = 0.0;
+    for _ in 0..3 {
+        app.update();
+    }
+    assert!(big_brain::actors_running::<Investigate>(app.world_mut()).is_empty());
+    assert_eq!(
+        big_brain::actors_running::<Patrol>(app.world_mut()).len(),
+        4
+    );
+}
@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ScorerBuilder)]
+struct Always;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+fn always(mut query: Query<&mut Score, With<Always>>) {
+    for mut score in query.iter_mut() {
+        score.set(1.0);
+    }
+}
+
+fn spawn_actor(app: &mut App) -> Entity {
+    app.world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when(Always, Idle),
+        )
+        .id()
+}
+
+fn has_thinker(app: &App, actor: Entity) -> bool {
+    app.world().get::<HasThinker>(actor).is_some()
+}
+
+#[test]
+fn thinkers_attach_in_cleanup_by_default() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    let plugin = BigBrainPlugin::new(PreUpdate);
+    assert_eq!(plugin.attach_schedule(), plugin.cleanup_schedule());
+    let actor = spawn_actor(&mut app);
+    app.update();
+    assert!(has_thinker(&app, actor));
+}
+
+#[test]
+fn thinkers_decide_on_their_first_frame() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        BigBrainPlugin::new(PreUpdate).set_attach_schedule(PreUpdate),
+    ))
+    .add_systems(PreUpdate, always.in_set(BigBrainSet::Scorers));
+    let actor = spawn_actor(&mut app);
+    app.update();
+    let thinker = app.world().get::<HasThinker>(actor).unwrap().entity();
+    assert_eq!(
+        app.world().get::<ActionState>(thinker),
+        Some(&ActionState::Executing),
+    );
+    assert_eq!(
+        app.world()
+            .get::<Thinker>(thinker)
+            .unwrap()
+            .current_action_label(),
+        Some("Idle"),
+        "the thinker should pick an action the same frame it was attached"
+    );
+}
@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Patrol;
+
+#[derive(Component)]
+struct Guard;
+
+#[derive(Component)]
+struct AttachedThinker(Entity);
+
+fn hire_guards(mut cmd: Commands, new_guards: Query<Entity, Added<Guard>>) {
+    for guard in new_guards.iter() {
+        let thinker = Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .otherwise(Patrol)
+            .attach_to(guard, &mut cmd);
+        cmd.entity(guard).insert(AttachedThinker(thinker));
+    }
+}
+
+#[test]
+fn attach_to_spawns_the_thinker_right_away() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .add_systems(Update, hire_guards);
+    let guard = app.world_mut().spawn(Guard).id();
+    app.update();
+
+    let attached = app.world().get::<AttachedThinker>(guard).unwrap().0;
+    assert_eq!(
+        app.world().get::<HasThinker>(guard).map(HasThinker::entity),
+        Some(attached)
+    );
+    assert!(app.world().get::<Thinker>(attached).is_some());
+
+    for _ in 0..3 {
+        app.update();
+    }
+    let world = app.world_mut();
+    assert_eq!(world.query::<&Thinker>().iter(world).count(), 1);
+    assert_eq!(world.query::<&Patrol>().iter(world).count(), 1);
+}
+
+#[test]
+fn removing_the_builder_tears_the_thinker_down() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .add_systems(Update, hire_guards);
+    let guard = app.world_mut().spawn(Guard).id();
+    app.update();
+    let attached = app.world().get::<AttachedThinker>(guard).unwrap().0;
+
+    app.world_mut().entity_mut(guard).remove::<ThinkerBuilder>();
+    app.update();
+    assert!(app.world().get_entity(attached).is_err());
+    assert!(app.world().get::<HasThinker>(guard).is_none());
+}
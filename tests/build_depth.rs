@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+use big_brain::{actions::spawn_action, prelude::*};
+
+/// An ActionBuilder that (accidentally) includes itself.
+#[derive(Debug)]
+struct Recursive;
+
+impl ActionBuilder for Recursive {
+    fn build(&self, cmd: &mut Commands, action: Entity, actor: Entity) {
+        let child = spawn_action(self, cmd, actor);
+        cmd.entity(action).add_children(&[child]);
+    }
+}
+
+fn recursive_app(max_depth: usize) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        BigBrainPlugin::new(PreUpdate).max_build_depth(max_depth),
+    ));
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .otherwise(Recursive),
+    );
+    app
+}
+
+fn built_actions(app: &mut App) -> Vec<ActionState> {
+    for _ in 0..4 {
+        app.update();
+    }
+    let mut query = app.world_mut().query::<(&ActionState, &Actor)>();
+    query
+        .iter(app.world())
+        .map(|(state, _)| state.clone())
+        .collect()
+}
+
+#[test]
+fn self_referential_builder_is_detected() {
+    let mut app = recursive_app(8);
+    let actions = built_actions(&mut app);
+    // The thinker itself, plus 8 built `Recursive`s and the one that went
+    // past the limit.
+    assert_eq!(actions.len(), 10);
+    assert!(actions.contains(&ActionState::Failure));
+}
+
+#[test]
+fn each_app_keeps_its_own_limit() {
+    let mut deep = recursive_app(8);
+    // Building this one last used to set the limit for every App.
+    let mut shallow = recursive_app(4);
+    assert_eq!(built_actions(&mut deep).len(), 10);
+    assert_eq!(built_actions(&mut shallow).len(), 6);
+}
@@ -0,0 +1,89 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use big_brain::thinker::Actor;
+
+mod common;
+
+#[derive(Default, Resource)]
+struct Seen(Vec<ActionState>);
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Guard;
+
+fn guard(mut query: Query<&mut ActionState, With<Guard>>, mut seen: ResMut<Seen>) {
+    for mut state in query.iter_mut() {
+        match *state {
+            ActionState::Requested => *state = ActionState::Executing,
+            ActionState::Cancelled | ActionState::Interrupted => {
+                seen.0.push(state.clone());
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
+
+fn app() -> App {
+    let mut app = common::app();
+    app.init_resource::<Seen>()
+        .add_systems(PreUpdate, guard.in_set(BigBrainSet::Actions));
+    app
+}
+
+fn leftovers(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world.query::<&Actor>().iter(world).count()
+}
+
+#[test]
+fn actions_see_the_cancel_before_everything_is_despawned() {
+    let mut app = app();
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when(FixedScore::build(0.2), Guard)
+                .otherwise(Steps::build().step(Guard)),
+        )
+        .id();
+    for _ in 0..3 {
+        app.update();
+    }
+    assert!(leftovers(&mut app) > 0);
+
+    assert!(big_brain::cancel_actor(app.world_mut(), actor));
+    app.world_mut().despawn(actor);
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(
+        app.world().resource::<Seen>().0,
+        vec![ActionState::Cancelled]
+    );
+    assert_eq!(leftovers(&mut app), 0);
+}
+
+#[test]
+fn the_actor_stays_put_without_a_thinker() {
+    let mut app = app();
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .otherwise(Guard),
+        )
+        .id();
+    for _ in 0..3 {
+        app.update();
+    }
+    assert!(big_brain::cancel_actor(app.world_mut(), actor));
+    for _ in 0..5 {
+        app.update();
+    }
+    assert!(app.world().get_entity(actor).is_ok());
+    assert!(app.world().get::<HasThinker>(actor).is_none());
+    assert_eq!(leftovers(&mut app), 0);
+    assert!(!big_brain::cancel_actor(app.world_mut(), actor));
+}
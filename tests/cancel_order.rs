@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+use big_brain::actions::concurrent_system;
+use big_brain::prelude::*;
+use big_brain::scorers::resource_score_system;
+
+#[derive(Resource)]
+struct Engaged(f32);
+
+#[derive(Default, Resource)]
+struct Stopped(Vec<(&'static str, u32)>);
+
+#[derive(Default, Resource)]
+struct Frame(u32);
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Aim;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Shoot;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Strafe;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+fn keep_going<T: Component>(
+    name: &'static str,
+) -> impl FnMut(Query<&mut ActionState, With<T>>, ResMut<Stopped>, Res<Frame>) {
+    move |mut query, mut stopped, frame| {
+        for mut state in query.iter_mut() {
+            match *state {
+                ActionState::Requested => *state = ActionState::Executing,
+                ActionState::Cancelled | ActionState::Interrupted => {
+                    stopped.0.push((name, frame.0));
+                    *state = ActionState::Failure;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn app(fight: impl ActionBuilder + 'static) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .insert_resource(Engaged(1.0))
+        .init_resource::<Stopped>()
+        .init_resource::<Frame>()
+        .add_systems(
+            PreUpdate,
+            (
+                resource_score_system::<Engaged>.in_set(BigBrainSet::Scorers),
+                (
+                    keep_going::<Aim>("aim"),
+                    keep_going::<Shoot>("shoot"),
+                    keep_going::<Strafe>("strafe"),
+                    keep_going::<Idle>("idle"),
+                )
+                    .in_set(BigBrainSet::Actions)
+                    .after(concurrent_system),
+            ),
+        )
+        .add_systems(Last, |mut frame: ResMut<Frame>| frame.0 += 1);
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(ResourceScore::build(|engaged: &Engaged| engaged.0), fight)
+            .when(
+                ResourceScore::build(|engaged: &Engaged| 1.0 - engaged.0),
+                Idle,
+            ),
+    );
+    for _ in 0..5 {
+        app.update();
+    }
+    app.world_mut().resource_mut::<Engaged>().0 = 0.0;
+    for _ in 0..10 {
+        app.update();
+    }
+    app
+}
+
+#[test]
+fn stops_children_one_at_a_time_in_order() {
+    let app = app(Concurrently::build()
+        .push(Aim)
+        .push(Shoot)
+        .push(Strafe)
+        .cancel_order(vec![1, 0]));
+    let stopped = &app.world().resource::<Stopped>().0;
+    let names: Vec<_> = stopped.iter().map(|(name, _)| *name).collect();
+    assert_eq!(names, vec!["shoot", "aim", "strafe"]);
+    let frames: Vec<_> = stopped.iter().map(|(_, frame)| *frame).collect();
+    assert!(
+        frames.windows(2).all(|pair| pair[0] < pair[1]),
+        "stopped on frames {frames:?}"
+    );
+}
+
+#[test]
+fn stops_all_children_at_once_by_default() {
+    let app = app(Concurrently::build().push(Aim).push(Shoot).push(Strafe));
+    let stopped = &app.world().resource::<Stopped>().0;
+    assert_eq!(stopped.len(), 3);
+    assert!(stopped.iter().all(|(_, frame)| *frame == stopped[0].1));
+}
@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Resource, Default)]
+struct Log {
+    ran: Vec<&'static str>,
+    most_steps: usize,
+    most_concurrently: usize,
+}
+
+macro_rules! instant_action {
+    ($name:ident, $system:ident) => {
+        #[derive(Clone, Component, Debug, ActionBuilder)]
+        struct $name;
+
+        fn $system(mut query: Query<&mut ActionState, With<$name>>, mut log: ResMut<Log>) {
+            for mut state in query.iter_mut() {
+                if *state == ActionState::Requested {
+                    log.ran.push(stringify!($name));
+                    *state = ActionState::Success;
+                }
+            }
+        }
+    };
+}
+
+instant_action!(Wake, wake);
+instant_action!(Stretch, stretch);
+instant_action!(Yawn, yawn);
+
+fn count_composites(
+    steps: Query<(), With<Steps>>,
+    concurrently: Query<(), With<Concurrently>>,
+    mut log: ResMut<Log>,
+) {
+    log.most_steps = log.most_steps.max(steps.iter().count());
+    log.most_concurrently = log.most_concurrently.max(concurrently.iter().count());
+}
+
+fn run(action: impl ActionBuilder + 'static) -> Log {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<Log>()
+        .add_systems(
+            PreUpdate,
+            (wake, stretch, yawn).in_set(BigBrainSet::Actions),
+        )
+        .add_systems(Last, count_composites);
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(FixedScore::build(1.0), action),
+    );
+    for _ in 0..10 {
+        app.update();
+    }
+    app.world_mut().remove_resource::<Log>().unwrap()
+}
+
+#[test]
+fn then_runs_actions_in_order() {
+    let log = run(Wake.then(Stretch).then(Yawn));
+    assert_eq!(log.ran[..3], ["Wake", "Stretch", "Yawn"]);
+}
+
+#[test]
+fn then_flattens_unlabeled_steps() {
+    let log = run(Wake
+        .then(Stretch)
+        .then(Steps::build().step(Yawn).step(Wake)));
+    assert_eq!(log.ran[..4], ["Wake", "Stretch", "Yawn", "Wake"]);
+    assert_eq!(log.most_steps, 1);
+}
+
+#[test]
+fn then_nests_labeled_steps() {
+    let morning = Steps::build().label("Morning").step(Wake).step(Stretch);
+    let log = run(Yawn.then(morning));
+    assert_eq!(log.ran[..3], ["Yawn", "Wake", "Stretch"]);
+    assert_eq!(log.most_steps, 2);
+}
+
+#[test]
+fn and_runs_actions_together() {
+    let log = run(Wake.and(Stretch).and(Yawn));
+    let mut first = log.ran[..3].to_vec();
+    first.sort();
+    assert_eq!(first, ["Stretch", "Wake", "Yawn"]);
+    assert_eq!(log.most_concurrently, 1);
+}
+
+#[test]
+fn and_flattens_matching_concurrently() {
+    let log = run(Wake.and(Concurrently::build().push(Stretch).push(Yawn)));
+    let mut first = log.ran[..3].to_vec();
+    first.sort();
+    assert_eq!(first, ["Stretch", "Wake", "Yawn"]);
+    assert_eq!(log.most_concurrently, 1);
+}
+
+#[test]
+fn and_nests_concurrently_with_another_mode() {
+    let race = Concurrently::build()
+        .mode(ConcurrentMode::Race)
+        .push(Stretch)
+        .push(Yawn);
+    let log = run(Wake.and(race));
+    assert_eq!(log.most_concurrently, 2);
+}
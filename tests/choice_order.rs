@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct First;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Second;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Third;
+
+fn picked(builder: ThinkerBuilder) -> (Option<String>, usize) {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    let actor = app.world_mut().spawn(builder).id();
+    for _ in 0..3 {
+        app.update();
+    }
+    let thinker = app.world().get::<HasThinker>(actor).unwrap().entity();
+    let thinker = app.world().get::<Thinker>(thinker).unwrap();
+    (
+        thinker.current_action_label().map(String::from),
+        thinker.choice_count(),
+    )
+}
+
+#[test]
+fn choices_are_picked_in_declaration_order() {
+    let (label, count) = picked(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(FixedScore::build(0.2), Third)
+            .when(FixedScore::build(1.0), First)
+            .when_tagged(FixedScore::build(1.0), Second, "second"),
+    );
+    assert_eq!(label.as_deref(), Some("First"));
+    assert_eq!(count, 3);
+
+    let (label, _) = picked(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(FixedScore::build(0.2), Third)
+            .when_tagged(FixedScore::build(1.0), Second, "second")
+            .when(FixedScore::build(1.0), First),
+    );
+    assert_eq!(label.as_deref(), Some("Second"));
+}
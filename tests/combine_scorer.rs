@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use big_brain::scorers::CombineScorerBuilder;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+fn combined_score(scorer: CombineScorerBuilder) -> f32 {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(scorer, Idle),
+    );
+    for _ in 0..3 {
+        app.update();
+    }
+    let world = app.world_mut();
+    world
+        .query_filtered::<&Score, With<CombineScorer>>()
+        .single(world)
+        .get()
+}
+
+#[test]
+fn passes_child_scores_in_order() {
+    let score = combined_score(
+        CombineScorer::build(|scores: &[f32]| scores[0] - scores[1])
+            .push(FixedScore::build(0.75))
+            .push(FixedScore::build(0.25)),
+    );
+    assert_eq!(score, 0.5);
+}
+
+#[test]
+fn clamps_the_result() {
+    let score = combined_score(
+        CombineScorer::build(|scores: &[f32]| scores.iter().sum())
+            .push(FixedScore::build(0.75))
+            .push(FixedScore::build(0.75)),
+    );
+    assert_eq!(score, 1.0);
+    let score = combined_score(
+        CombineScorer::build(|scores: &[f32]| scores[0] - scores[1])
+            .push(FixedScore::build(0.25))
+            .push(FixedScore::build(0.75)),
+    );
+    assert_eq!(score, 0.0);
+}
+
+#[test]
+fn treats_nan_as_zero() {
+    let score = combined_score(
+        CombineScorer::build(|scores: &[f32]| scores[0] / scores[1])
+            .push(FixedScore::build(0.0))
+            .push(FixedScore::build(0.0)),
+    );
+    assert_eq!(score, 0.0);
+}
@@ -0,0 +1,34 @@
+//! Helpers shared by the integration tests. Each test binary only uses some
+//! of them.
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+/// An `App` with just enough to run Big Brain in `PreUpdate`.
+pub fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    app
+}
+
+/// The label of the Action `actor`'s Thinker is running, if any.
+pub fn current(app: &App, actor: Entity) -> Option<String> {
+    let thinker = app.world().get::<HasThinker>(actor)?.entity();
+    app.world()
+        .get::<Thinker>(thinker)?
+        .current_action_label()
+        .map(String::from)
+}
+
+/// Action system that keeps every `T` Action executing until it's cancelled
+/// or interrupted, and then fails it.
+pub fn keep_going<T: Component>(mut query: Query<&mut ActionState, With<T>>) {
+    for mut state in query.iter_mut() {
+        match *state {
+            ActionState::Requested => *state = ActionState::Executing,
+            ActionState::Cancelled | ActionState::Interrupted => *state = ActionState::Failure,
+            _ => {}
+        }
+    }
+}
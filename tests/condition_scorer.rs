@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+#[derive(Resource)]
+struct Raining;
+
+#[derive(Resource)]
+struct BossHealth(f32);
+
+fn score(app: &mut App) -> f32 {
+    let world = app.world_mut();
+    world
+        .query_filtered::<&Score, With<ConditionScorer>>()
+        .single(world)
+        .get()
+}
+
+#[test]
+fn follows_the_condition() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(ConditionScorer::build(resource_exists::<Raining>), Idle),
+    );
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(score(&mut app), 0.0);
+
+    app.insert_resource(Raining);
+    app.update();
+    assert_eq!(score(&mut app), 1.0);
+
+    app.world_mut().remove_resource::<Raining>();
+    app.update();
+    assert_eq!(score(&mut app), 0.0);
+}
+
+#[test]
+fn takes_closures_with_system_params() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .insert_resource(BossHealth(100.0));
+    app.world_mut()
+        .spawn(Thinker::build().picker(FirstToScore::new(0.5)).when(
+            ConditionScorer::build(|boss: Res<BossHealth>| boss.0 > 0.0),
+            Idle,
+        ));
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(score(&mut app), 1.0);
+
+    app.world_mut().resource_mut::<BossHealth>().0 = 0.0;
+    app.update();
+    assert_eq!(score(&mut app), 0.0);
+}
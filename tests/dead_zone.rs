@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use big_brain::scorers::resource_score_system;
+
+#[derive(Resource)]
+struct Tiredness(f32);
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Sleep;
+
+#[test]
+fn holds_its_state_inside_the_dead_zone() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .insert_resource(Tiredness(0.5))
+        .add_systems(
+            PreUpdate,
+            resource_score_system::<Tiredness>.in_set(BigBrainSet::Scorers),
+        );
+    app.world_mut()
+        .spawn(Thinker::build().picker(FirstToScore::new(0.5)).when(
+            DeadZoneScorer::build(
+                0.4,
+                0.6,
+                ResourceScore::build(|tiredness: &Tiredness| tiredness.0),
+            ),
+            Sleep,
+        ));
+    for (tiredness, expected, on) in [
+        (0.5, 0.0, false),
+        (0.7, 0.7, true),
+        (0.5, 0.5, true),
+        (0.4, 0.4, true),
+        (0.3, 0.0, false),
+        (0.55, 0.0, false),
+        (0.65, 0.65, true),
+    ] {
+        app.world_mut().resource_mut::<Tiredness>().0 = tiredness;
+        for _ in 0..3 {
+            app.update();
+        }
+        let world = app.world_mut();
+        let (score, dead_zone) = world.query::<(&Score, &DeadZoneScorer)>().single(world);
+        assert_eq!(score.get(), expected, "with tiredness at {tiredness}");
+        assert_eq!(dead_zone.is_on(), on, "with tiredness at {tiredness}");
+    }
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "debug")]
+
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ScorerBuilder)]
+struct Thirsty;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Drink;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+#[test]
+fn thinker_to_dot() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .label("Brain")
+                .picker(FirstToScore::new(0.5))
+                .when(SumOfScorers::build(0.0).push(Thirsty), Drink)
+                .otherwise(Idle),
+        )
+        .id();
+    for _ in 0..4 {
+        app.update();
+    }
+    let dot = big_brain::debug::thinker_to_dot(app.world(), actor);
+    assert!(dot.starts_with("digraph thinker {"));
+    assert!(dot.contains("label=\"Brain\""));
+    assert!(dot.contains("label=\"SumOfScorers\\n0.000\""));
+    assert!(dot.contains("label=\"Thirsty\\n0.000\""));
+    assert!(dot.contains("label=\"Drink\""));
+    assert!(dot.contains("otherwise: Idle\", style=filled"));
+}
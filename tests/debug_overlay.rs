@@ -0,0 +1,113 @@
+#![cfg(feature = "debug_overlay")]
+
+use bevy::gizmos::config::{DefaultGizmoConfigGroup, GizmoConfig, GizmoConfigStore};
+use bevy::gizmos::gizmos::GizmoStorage;
+use bevy::prelude::*;
+use big_brain::debug::overlay::{label_color, DebugOverlay, DebugOverlayText};
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Wander;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Sleep;
+
+fn keep_going(mut actions: Query<&mut ActionState, With<Idle>>) {
+    for mut state in actions.iter_mut() {
+        if *state == ActionState::Requested {
+            *state = ActionState::Executing;
+        }
+    }
+}
+
+/// `GizmoPlugin` wants shader assets as soon as `bevy_render` is enabled, so
+/// only set up the bits `Gizmos` itself needs.
+fn gizmo_resources(app: &mut App) {
+    let mut store = GizmoConfigStore::default();
+    store.insert(GizmoConfig::default(), DefaultGizmoConfigGroup);
+    app.insert_resource(store)
+        .init_resource::<GizmoStorage<DefaultGizmoConfigGroup, ()>>();
+}
+
+#[test]
+fn draws_without_a_renderer() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        BigBrainPlugin::new(PreUpdate).with_debug_overlay(),
+    ));
+    gizmo_resources(&mut app);
+    app.world_mut().spawn((
+        GlobalTransform::from_translation(Vec3::new(1.0, 0.0, 0.0)),
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(FixedScore::build(0.7), Idle),
+    ));
+    let drawn = |app: &App| {
+        app.world()
+            .get_resource_change_ticks::<GizmoStorage<DefaultGizmoConfigGroup, ()>>()
+            .expect("gizmo storage is there")
+            .changed
+    };
+    let before = drawn(&app);
+    for _ in 0..3 {
+        app.update();
+    }
+    assert!(app.world().contains_resource::<DebugOverlay>());
+    // The gizmos were flushed into storage, so the overlay system did run.
+    let world = app.world();
+    assert!(drawn(&app).is_newer_than(before, world.read_change_tick()));
+}
+
+#[test]
+fn labels_keep_their_colors() {
+    assert_eq!(label_color("Drink"), label_color("Drink"));
+    assert_ne!(label_color("Drink"), label_color("Eat"));
+}
+
+#[test]
+fn shows_the_current_action_and_top_scores_as_text() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        BigBrainPlugin::new(PreUpdate).with_debug_overlay(),
+    ))
+    .add_systems(PreUpdate, keep_going.in_set(BigBrainSet::Actions));
+    gizmo_resources(&mut app);
+    app.world_mut().insert_resource(DebugOverlay {
+        top_scores: 2,
+        ..default()
+    });
+    let actor = app
+        .world_mut()
+        .spawn((
+            GlobalTransform::default(),
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when(FixedScore::build(0.2), Sleep)
+                .when(FixedScore::build(0.9), Idle)
+                .when(FixedScore::build(0.4), Wander),
+        ))
+        .id();
+    for _ in 0..3 {
+        app.update();
+    }
+    let text = |app: &mut App| {
+        app.world_mut()
+            .query::<(&DebugOverlayText, &Text2d)>()
+            .iter(app.world())
+            .map(|(overlay_text, text)| (overlay_text.actor, text.0.clone()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        text(&mut app),
+        vec![(actor, "Idle\nIdle: 0.90\nWander: 0.40".to_string())]
+    );
+
+    app.world_mut().despawn(actor);
+    app.update();
+    assert!(text(&mut app).is_empty());
+}
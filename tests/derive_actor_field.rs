@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+mod common;
+
+#[derive(Debug, Clone, Component, ActionBuilder)]
+struct Chase {
+    #[actor]
+    chaser: Entity,
+    speed: f32,
+}
+
+#[derive(Debug, Clone, Component, ActionBuilder)]
+struct Flee(f32, #[actor] Entity);
+
+#[derive(Debug, Clone, Component, ScorerBuilder)]
+struct Hungry {
+    #[actor]
+    owner: Entity,
+}
+
+#[test]
+fn action_actor_field_is_filled_in() {
+    let mut app = common::app();
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .otherwise(Chase {
+                    chaser: Entity::PLACEHOLDER,
+                    speed: 2.0,
+                }),
+        )
+        .id();
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let world = app.world_mut();
+    let (chase, Actor(owner)) = world.query::<(&Chase, &Actor)>().single(world);
+    assert_eq!(chase.chaser, actor);
+    assert_eq!(*owner, actor);
+    assert_eq!(chase.speed, 2.0);
+}
+
+#[test]
+fn tuple_action_actor_field_is_filled_in() {
+    let mut app = common::app();
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .otherwise(Flee(3.0, Entity::PLACEHOLDER)),
+        )
+        .id();
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let world = app.world_mut();
+    let flee = world.query::<&Flee>().single(world);
+    assert_eq!(flee.1, actor);
+    assert_eq!(flee.0, 3.0);
+}
+
+#[test]
+fn scorer_actor_field_is_filled_in() {
+    let mut app = common::app();
+    let actor = app
+        .world_mut()
+        .spawn(Thinker::build().picker(FirstToScore::new(0.5)).when(
+            Hungry {
+                owner: Entity::PLACEHOLDER,
+            },
+            Flee(1.0, Entity::PLACEHOLDER),
+        ))
+        .id();
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let world = app.world_mut();
+    let hungry = world.query::<&Hungry>().single(world);
+    assert_eq!(hungry.owner, actor);
+}
@@ -39,3 +39,16 @@ fn check_generic_where_macro() {
     let scorer = MyGenericWhereScorer { value: 0 };
     assert_eq!(scorer.label(), Some("MyGenericWhereLabel"))
 }
+
+#[derive(Debug, Clone, Component, ScorerBuilder)]
+#[scorer_shared]
+pub struct MySharedScorer;
+
+#[test]
+fn check_shared_macro() {
+    assert_eq!(
+        MySharedScorer.shared_key(),
+        Some(std::any::TypeId::of::<MySharedScorer>())
+    );
+    assert_eq!(MyScorer.shared_key(), None);
+}
@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use big_brain::actions::Detached;
+use big_brain::prelude::*;
+
+mod common;
+use common::current;
+
+#[derive(Default, Resource)]
+struct ShowOver(bool);
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Fireworks;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+fn fireworks(mut query: Query<&mut ActionState, With<Fireworks>>, over: Res<ShowOver>) {
+    for mut state in query.iter_mut() {
+        match *state {
+            ActionState::Requested => *state = ActionState::Executing,
+            ActionState::Executing if over.0 => *state = ActionState::Success,
+            _ => {}
+        }
+    }
+}
+
+fn idle(mut query: Query<&mut ActionState, With<Idle>>) {
+    for mut state in query.iter_mut() {
+        match *state {
+            ActionState::Requested => *state = ActionState::Executing,
+            ActionState::Cancelled | ActionState::Interrupted => *state = ActionState::Failure,
+            _ => {}
+        }
+    }
+}
+
+fn shows(app: &mut App) -> Vec<ActionState> {
+    let world = app.world_mut();
+    world
+        .query_filtered::<&ActionState, (With<Fireworks>, With<Detached>)>()
+        .iter(world)
+        .cloned()
+        .collect()
+}
+
+#[test]
+fn outlives_the_decision() {
+    let mut app = common::app();
+    app.init_resource::<ShowOver>()
+        .add_systems(PreUpdate, (fireworks, idle).in_set(BigBrainSet::Actions));
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when_once(FixedScore::build(1.0), Detach::build().wrap(Fireworks))
+                .otherwise(Idle),
+        )
+        .id();
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(current(&app, actor).as_deref(), Some("Idle"));
+    assert_eq!(shows(&mut app), vec![ActionState::Executing]);
+
+    app.world_mut().resource_mut::<ShowOver>().0 = true;
+    for _ in 0..3 {
+        app.update();
+    }
+    assert!(shows(&mut app).is_empty());
+    assert_eq!(current(&app, actor).as_deref(), Some("Idle"));
+}
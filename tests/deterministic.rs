@@ -0,0 +1,51 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use std::time::Duration;
+
+use bevy::prelude::*;
+use big_brain::choices::Choice;
+use big_brain::prelude::*;
+
+/// Counts how many Thinkers think, and takes its time doing it, so a frame
+/// of them blows through the thinking budget.
+#[derive(Debug, Clone)]
+struct SlowPicker(Arc<AtomicUsize>);
+
+impl Picker for SlowPicker {
+    fn pick<'a>(&self, _choices: &'a [Choice], _scores: &Query<&Score>) -> Option<&'a Choice> {
+        self.0.fetch_add(1, Ordering::Relaxed);
+        std::thread::sleep(Duration::from_micros(50));
+        None
+    }
+}
+
+fn thinkers_per_frame(deterministic: bool) -> usize {
+    let picks = Arc::new(AtomicUsize::new(0));
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        BigBrainPlugin::new(PreUpdate).deterministic(deterministic),
+    ));
+    for _ in 0..1000 {
+        app.world_mut()
+            .spawn(Thinker::build().picker(SlowPicker(picks.clone())));
+    }
+    for _ in 0..3 {
+        app.update();
+    }
+    picks.store(0, Ordering::Relaxed);
+    app.update();
+    picks.load(Ordering::Relaxed)
+}
+
+#[test]
+fn budget_defers_some_thinkers() {
+    assert!(thinkers_per_frame(false) < 1000);
+}
+
+#[test]
+fn deterministic_thinks_for_everyone() {
+    assert_eq!(thinkers_per_frame(true), 1000);
+}
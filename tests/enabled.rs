@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+mod common;
+use common::{current, keep_going};
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Flee;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Wander;
+
+fn settle(app: &mut App) {
+    for _ in 0..3 {
+        app.update();
+    }
+}
+
+#[test]
+fn disabled_scorers_are_skipped_by_pickers() {
+    let mut app = common::app();
+    app.add_systems(
+        PreUpdate,
+        (keep_going::<Flee>, keep_going::<Wander>).in_set(BigBrainSet::Actions),
+    );
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(Highest)
+                .when(FixedScore::build(0.9).label("Scared"), Flee)
+                .when(FixedScore::build(0.3).label("Bored"), Wander),
+        )
+        .id();
+    settle(&mut app);
+    assert_eq!(current(&app, actor).as_deref(), Some("Flee"));
+
+    assert!(big_brain::set_scorer_enabled(
+        app.world_mut(),
+        actor,
+        "Scared",
+        false
+    ));
+    assert_eq!(
+        big_brain::query_score(app.world(), actor, "Scared"),
+        Some(0.0)
+    );
+    settle(&mut app);
+    assert_eq!(current(&app, actor).as_deref(), Some("Wander"));
+
+    assert!(big_brain::set_scorer_enabled(
+        app.world_mut(),
+        actor,
+        "Scared",
+        true
+    ));
+    assert_eq!(
+        big_brain::query_score(app.world(), actor, "Scared"),
+        Some(0.9)
+    );
+    settle(&mut app);
+    assert_eq!(current(&app, actor).as_deref(), Some("Flee"));
+
+    assert!(!big_brain::set_scorer_enabled(
+        app.world_mut(),
+        actor,
+        "Hungry",
+        false
+    ));
+}
+
+#[test]
+fn disabled_scorers_contribute_zero_to_composites() {
+    let mut app = common::app();
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build().picker(FirstToScore::new(0.5)).when(
+                SumOfScorers::build(0.0)
+                    .label("Total")
+                    .push(FixedScore::build(0.25).label("Small"))
+                    .push(FixedScore::build(0.5).label("Large")),
+                Wander,
+            ),
+        )
+        .id();
+    settle(&mut app);
+    assert_eq!(
+        big_brain::query_score(app.world(), actor, "Total"),
+        Some(0.75)
+    );
+
+    // Inserting the component directly works too, and composites see it the
+    // very next frame.
+    let world = app.world_mut();
+    let large = world
+        .query::<(Entity, &ScorerSpan)>()
+        .iter(world)
+        .find(|(_, span)| span.label() == Some("Large"))
+        .map(|(scorer, _)| scorer)
+        .unwrap();
+    world.entity_mut(large).insert(Enabled(false));
+    app.update();
+    assert_eq!(
+        big_brain::query_score(app.world(), actor, "Total"),
+        Some(0.25)
+    );
+
+    // Removing it re-enables the Scorer.
+    app.world_mut().entity_mut(large).remove::<Enabled>();
+    app.update();
+    assert_eq!(
+        big_brain::query_score(app.world(), actor, "Total"),
+        Some(0.75)
+    );
+}
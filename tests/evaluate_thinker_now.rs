@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Drink;
+
+#[test]
+fn picks_an_action_before_the_first_update() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when(FixedScore::build(1.0), Drink)
+                .otherwise(Idle),
+        )
+        .id();
+
+    let action = big_brain::evaluate_thinker_now(app.world_mut(), actor)
+        .expect("the thinker should have picked something");
+    let thinker = app.world().get::<HasThinker>(actor).unwrap().entity();
+    assert_eq!(
+        app.world().get::<ActionState>(thinker),
+        Some(&ActionState::Executing)
+    );
+    // Scorers are evaluated first, so this doesn't fall back to `otherwise`.
+    assert!(app.world().get::<Drink>(action).is_some());
+}
+
+#[derive(Clone, Component, Debug, ScorerBuilder)]
+struct Thirsty;
+
+fn thirsty_scorer_system(mut query: Query<&mut Score, With<Thirsty>>) {
+    for mut score in query.iter_mut() {
+        score.set(0.9);
+    }
+}
+
+#[test]
+fn leaves_custom_scorers_to_their_systems() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .add_systems(
+            PreUpdate,
+            thirsty_scorer_system.in_set(BigBrainSet::Scorers),
+        );
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when(
+                    AllOrNothing::build(0.5)
+                        .push(Thirsty)
+                        .push(FixedScore::build(1.0)),
+                    Drink,
+                )
+                .otherwise(Idle),
+        )
+        .id();
+
+    // `Thirsty` hasn't been scored yet, so it's still `0.0`.
+    let action = big_brain::evaluate_thinker_now(app.world_mut(), actor).unwrap();
+    assert!(app.world().get::<Idle>(action).is_some());
+}
+
+fn fixed_score(app: &mut App, actor: Entity) -> f32 {
+    let world = app.world_mut();
+    world
+        .query_filtered::<(&Actor, &Score), With<FixedScore>>()
+        .iter(world)
+        .find(|(Actor(owner), _)| *owner == actor)
+        .map(|(_, score)| score.get())
+        .expect("the actor should have a FixedScore")
+}
+
+#[test]
+fn only_scores_the_actor_being_evaluated() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    let spawn = |app: &mut App| {
+        app.world_mut()
+            .spawn(
+                Thinker::build()
+                    .picker(FirstToScore::new(0.5))
+                    .when(FixedScore::build(1.0), Drink),
+            )
+            .id()
+    };
+    let first = spawn(&mut app);
+    let second = spawn(&mut app);
+    big_brain::evaluate_thinker_now(app.world_mut(), first);
+    big_brain::evaluate_thinker_now(app.world_mut(), second);
+    assert_eq!(fixed_score(&mut app, second), 1.0);
+
+    let world = app.world_mut();
+    let mut scores = world.query_filtered::<(&Actor, &mut Score), With<FixedScore>>();
+    for (Actor(owner), mut score) in scores.iter_mut(world) {
+        if *owner == second {
+            score.set(0.25);
+        }
+    }
+    big_brain::evaluate_thinker_now(app.world_mut(), first);
+    assert_eq!(fixed_score(&mut app, first), 1.0);
+    assert_eq!(fixed_score(&mut app, second), 0.25);
+}
+
+#[test]
+fn ignores_entities_without_thinkers() {
+    let mut world = World::new();
+    let entity = world.spawn_empty().id();
+    assert_eq!(big_brain::evaluate_thinker_now(&mut world, entity), None);
+}
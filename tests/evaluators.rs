@@ -0,0 +1,78 @@
+use big_brain::prelude::*;
+
+#[test]
+fn gaussian_peaks_at_center() {
+    let eval = GaussianEvaluator::new(10.0, 2.0);
+    assert_eq!(eval.evaluate(10.0), 1.0);
+    assert!(eval.evaluate(9.5) < 1.0);
+    assert!(eval.evaluate(10.5) < 1.0);
+    assert!(eval.evaluate(14.0) < eval.evaluate(12.0));
+    assert!(eval.evaluate(100.0) < 0.0001);
+}
+
+#[test]
+fn gaussian_is_symmetric() {
+    let eval = GaussianEvaluator::new(10.0, 2.0);
+    for offset in [0.5, 1.0, 2.0, 3.7, 8.0] {
+        let below = eval.evaluate(10.0 - offset);
+        let above = eval.evaluate(10.0 + offset);
+        assert!((below - above).abs() < f32::EPSILON, "{below} != {above}");
+    }
+}
+
+#[test]
+fn gaussian_ranged_clamps_input() {
+    let eval = GaussianEvaluator::new_ranged(0.5, 0.1, 0.0, 1.0);
+    assert_eq!(eval.evaluate(0.5), 1.0);
+    assert_eq!(eval.evaluate(-5.0), eval.evaluate(0.0));
+    assert_eq!(eval.evaluate(5.0), eval.evaluate(1.0));
+}
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-5,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn sigmoid_ranged_known_values() {
+    let eval = SigmoidEvaluator::new_ranged(0.0, 2.0, 4.0);
+    assert_close(eval.evaluate(2.0), 0.0);
+    assert_close(eval.evaluate(2.5), 0.25);
+    assert_close(eval.evaluate(3.0), 0.5);
+    assert_close(eval.evaluate(4.0), 1.0);
+
+    let eval = SigmoidEvaluator::new_ranged(0.0, -10.0, 10.0);
+    assert_close(eval.evaluate(-10.0), 0.0);
+    assert_close(eval.evaluate(0.0), 0.5);
+    assert_close(eval.evaluate(5.0), 0.75);
+}
+
+#[test]
+fn sigmoid_ranged_matches_unit_range() {
+    for k in [-0.5, 0.3, 0.8] {
+        let unit = SigmoidEvaluator::new(k);
+        for (min, max) in [(2.0, 4.0), (-10.0, 10.0), (100.0, 150.0)] {
+            let ranged = SigmoidEvaluator::new_ranged(k, min, max);
+            for t in [0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0] {
+                assert_close(ranged.evaluate(min + t * (max - min)), unit.evaluate(t));
+            }
+        }
+    }
+}
+
+#[test]
+fn curve_interpolates_between_points() {
+    let eval = CurveEvaluator::new([(20.0, 0.2), (6.0, 1.0), (8.0, 0.2)]);
+    assert_close(eval.evaluate(7.0), 0.6);
+    assert_close(eval.evaluate(8.0), 0.2);
+    assert_close(eval.evaluate(12.0), 0.2);
+    assert_close(eval.evaluate(0.0), 1.0);
+    assert_close(eval.evaluate(24.0), 0.2);
+}
+
+#[test]
+fn empty_curve_is_zero() {
+    assert_eq!(CurveEvaluator::new([]).evaluate(1.0), 0.0);
+}
@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Component, Debug)]
+struct Home(Vec2);
+
+#[derive(Component, Debug)]
+struct WalkHome {
+    target: Vec2,
+}
+
+#[derive(Debug)]
+struct WalkHomeBuilder;
+
+impl ActionBuilder for WalkHomeBuilder {
+    fn build(&self, _cmd: &mut Commands, _action: Entity, _actor: Entity) {}
+
+    fn wants_exclusive_build(&self) -> bool {
+        true
+    }
+
+    fn build_exclusive(&self, world: &mut World, action: Entity, actor: Entity) {
+        let target = world.get::<Home>(actor).expect("actor has a home").0;
+        world.entity_mut(action).insert(WalkHome { target });
+    }
+}
+
+#[test]
+fn exclusive_build() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    app.world_mut().spawn((
+        Home(Vec2::new(3.0, 4.0)),
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .otherwise(Steps::build().step(WalkHomeBuilder)),
+    ));
+    for _ in 0..4 {
+        app.update();
+    }
+    let mut query = app.world_mut().query::<&WalkHome>();
+    let walk = query
+        .iter(app.world())
+        .next()
+        .expect("WalkHome should have been built against the World");
+    assert_eq!(walk.target, Vec2::new(3.0, 4.0));
+}
+
+#[test]
+fn spawn_action_skips_exclusive_builds() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    let actor = app.world_mut().spawn(Home(Vec2::new(3.0, 4.0))).id();
+    let action =
+        big_brain::actions::spawn_action(&WalkHomeBuilder, &mut app.world_mut().commands(), actor);
+    app.world_mut().flush();
+    assert!(app.world().get::<ActionState>(action).is_some());
+    assert!(app.world().get::<WalkHome>(action).is_none());
+}
@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use big_brain::scorers::resource_score_system;
+
+mod common;
+use common::{current, keep_going};
+
+#[derive(Resource)]
+struct Hunger(f32);
+
+#[derive(Resource)]
+struct Boredom(f32);
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Play;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Eat;
+
+#[test]
+fn falls_back_to_the_next_picker() {
+    let mut app = common::app();
+    app.insert_resource(Boredom(0.4))
+        .insert_resource(Hunger(0.5))
+        .add_systems(
+            PreUpdate,
+            (
+                (
+                    resource_score_system::<Boredom>,
+                    resource_score_system::<Hunger>,
+                )
+                    .in_set(BigBrainSet::Scorers),
+                (keep_going::<Play>, keep_going::<Eat>).in_set(BigBrainSet::Actions),
+            ),
+        );
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(
+                    FirstValidPicker::new()
+                        .push(HighestToScore::new(0.8))
+                        .push(FirstToScore::new(0.3)),
+                )
+                .when(ResourceScore::build(|boredom: &Boredom| boredom.0), Play)
+                .when(ResourceScore::build(|hunger: &Hunger| hunger.0), Eat),
+        )
+        .id();
+    for _ in 0..3 {
+        app.update();
+    }
+    // Nothing's urgent, so the first reasonable choice wins.
+    assert_eq!(current(&app, actor).as_deref(), Some("Play"));
+
+    app.world_mut().resource_mut::<Hunger>().0 = 0.9;
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(current(&app, actor).as_deref(), Some("Eat"));
+}
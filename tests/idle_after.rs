@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use big_brain::prelude::*;
+use big_brain::scorers::resource_score_system;
+
+mod common;
+use common::current;
+
+#[derive(Resource)]
+struct Busy(f32);
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Work;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Whistle;
+
+fn work(mut query: Query<&mut ActionState, With<Work>>) {
+    for mut state in query.iter_mut() {
+        match *state {
+            ActionState::Requested => *state = ActionState::Success,
+            ActionState::Cancelled | ActionState::Interrupted => *state = ActionState::Failure,
+            _ => {}
+        }
+    }
+}
+
+fn whistle(mut query: Query<&mut ActionState, With<Whistle>>) {
+    for mut state in query.iter_mut() {
+        match *state {
+            ActionState::Requested => *state = ActionState::Executing,
+            ActionState::Cancelled | ActionState::Interrupted => *state = ActionState::Failure,
+            _ => {}
+        }
+    }
+}
+
+fn app() -> (App, Entity) {
+    let mut app = common::app();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )))
+    .insert_resource(Busy(1.0))
+    .add_systems(
+        PreUpdate,
+        (
+            resource_score_system::<Busy>.in_set(BigBrainSet::Scorers),
+            (work, whistle).in_set(BigBrainSet::Actions),
+        ),
+    );
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when(ResourceScore::build(|busy: &Busy| busy.0), Work)
+                .idle_after(Duration::from_millis(500), Whistle),
+        )
+        .id();
+    for _ in 0..3 {
+        app.update();
+    }
+    (app, actor)
+}
+
+#[test]
+fn short_gaps_do_not_start_the_idle_action() {
+    let (mut app, actor) = app();
+    for frame in 0..20 {
+        app.world_mut().resource_mut::<Busy>().0 = if frame % 3 == 0 { 1.0 } else { 0.0 };
+        app.update();
+        assert_ne!(current(&app, actor).as_deref(), Some("Whistle"));
+    }
+}
+
+#[test]
+fn idles_after_a_while_and_gets_preempted() {
+    let (mut app, actor) = app();
+    app.world_mut().resource_mut::<Busy>().0 = 0.0;
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_ne!(current(&app, actor).as_deref(), Some("Whistle"));
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(current(&app, actor).as_deref(), Some("Whistle"));
+
+    app.world_mut().resource_mut::<Busy>().0 = 1.0;
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(current(&app, actor).as_deref(), Some("Work"));
+    let world = app.world_mut();
+    assert_eq!(world.query::<&Whistle>().iter(world).count(), 0);
+}
@@ -0,0 +1,155 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Default, Resource)]
+struct GlobalState {
+    want_other: bool,
+    seen: Vec<ActionState>,
+}
+
+#[derive(Clone, Component, Debug, ScorerBuilder)]
+struct WantOther;
+
+fn want_other_scorer(mut query: Query<&mut Score, With<WantOther>>, state: Res<GlobalState>) {
+    for mut score in query.iter_mut() {
+        score.set(if state.want_other { 1.0 } else { 0.0 });
+    }
+}
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct LongAction;
+
+fn long_action(
+    mut query: Query<&mut ActionState, With<LongAction>>,
+    mut global: ResMut<GlobalState>,
+) {
+    for mut state in query.iter_mut() {
+        match *state {
+            ActionState::Requested => {
+                *state = ActionState::Executing;
+                global.want_other = true;
+            }
+            ActionState::Cancelled | ActionState::Interrupted => {
+                global.seen.push(state.clone());
+                *state = ActionState::Success;
+            }
+            _ => {}
+        }
+    }
+}
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct OtherAction;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct StubbornAction;
+
+/// Only stops when cancelled, ignoring interruptions.
+fn stubborn_action(
+    mut query: Query<&mut ActionState, With<StubbornAction>>,
+    mut global: ResMut<GlobalState>,
+) {
+    for mut state in query.iter_mut() {
+        match *state {
+            ActionState::Requested => {
+                *state = ActionState::Executing;
+                global.want_other = true;
+            }
+            ActionState::Cancelled => {
+                global.seen.push(state.clone());
+                *state = ActionState::Success;
+            }
+            _ => {}
+        }
+    }
+}
+
+fn switching_app(thinker: ThinkerBuilder) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<GlobalState>()
+        .add_systems(PreUpdate, want_other_scorer.in_set(BigBrainSet::Scorers))
+        .add_systems(
+            PreUpdate,
+            (long_action, stubborn_action).in_set(BigBrainSet::Actions),
+        );
+    app.world_mut().spawn(thinker);
+    for _ in 0..10 {
+        app.update();
+    }
+    app
+}
+
+#[test]
+fn switching_actions_interrupts_by_default() {
+    let app = switching_app(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(WantOther, OtherAction)
+            .otherwise(LongAction),
+    );
+    assert_eq!(
+        app.world().resource::<GlobalState>().seen,
+        vec![ActionState::Interrupted]
+    );
+}
+
+#[test]
+fn cancel_on_switch_cancels() {
+    let app = switching_app(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(WantOther, OtherAction)
+            .otherwise(LongAction)
+            .cancel_on_switch(),
+    );
+    assert_eq!(
+        app.world().resource::<GlobalState>().seen,
+        vec![ActionState::Cancelled]
+    );
+}
+
+#[test]
+fn unacknowledged_interruptions_get_cancelled() {
+    let app = switching_app(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(WantOther, OtherAction)
+            .otherwise(StubbornAction)
+            .interrupt_on_switch(Duration::ZERO),
+    );
+    assert_eq!(
+        app.world().resource::<GlobalState>().seen,
+        vec![ActionState::Cancelled]
+    );
+}
+
+#[test]
+fn stopping_the_thinker_cancels() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<GlobalState>()
+        .add_systems(PreUpdate, long_action.in_set(BigBrainSet::Actions));
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .otherwise(LongAction),
+        )
+        .id();
+    for _ in 0..3 {
+        app.update();
+    }
+    let thinker = app.world().get::<HasThinker>(actor).unwrap().entity();
+    *app.world_mut().get_mut::<ActionState>(thinker).unwrap() = ActionState::Cancelled;
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(
+        app.world().resource::<GlobalState>().seen,
+        vec![ActionState::Cancelled]
+    );
+}
@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+use big_brain::{actions::spawn_action, prelude::*};
+
+#[derive(Default, Resource)]
+struct Done(Vec<&'static str>);
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Left;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Right;
+
+fn left(mut query: Query<&mut ActionState, With<Left>>, mut done: ResMut<Done>) {
+    for mut state in query.iter_mut() {
+        if *state == ActionState::Requested {
+            done.0.push("left");
+            *state = ActionState::Success;
+        }
+    }
+}
+
+fn right(mut query: Query<&mut ActionState, With<Right>>, mut done: ResMut<Done>) {
+    for mut state in query.iter_mut() {
+        if *state == ActionState::Requested {
+            done.0.push("right");
+            *state = ActionState::Success;
+        }
+    }
+}
+
+#[test]
+fn lazy_concurrently_spawns_children_when_requested() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<Done>()
+        .add_systems(PreUpdate, (left, right).in_set(BigBrainSet::Actions));
+    app.world_mut().spawn(
+        Thinker::build().picker(FirstToScore::new(0.5)).when_once(
+            FixedScore::build(1.0),
+            Steps::build()
+                .step(Ticks::build(3))
+                .step(Concurrently::build().lazy(true).push(Left).push(Right)),
+        ),
+    );
+    for _ in 0..20 {
+        app.update();
+    }
+    let mut done = app.world_mut().remove_resource::<Done>().unwrap().0;
+    done.sort();
+    assert_eq!(done, vec!["left", "right"]);
+}
+
+#[test]
+fn lazy_concurrently_does_not_spawn_children_upfront() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    let actor = app
+        .world_mut()
+        .spawn(Thinker::build().picker(FirstToScore::new(0.5)))
+        .id();
+    let mut commands = app.world_mut().commands();
+    spawn_action(&Concurrently::build().push(Left), &mut commands, actor);
+    spawn_action(
+        &Concurrently::build().lazy(true).push(Left),
+        &mut commands,
+        actor,
+    );
+    app.update();
+    let world = app.world_mut();
+    let mut spawned: Vec<_> = world
+        .query::<&Concurrently>()
+        .iter(world)
+        .map(|concurrently| concurrently.actions().len())
+        .collect();
+    spawned.sort();
+    assert_eq!(spawned, vec![0, 1]);
+    assert_eq!(world.query::<&Left>().iter(world).count(), 1);
+}
@@ -0,0 +1,158 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use big_brain::scorers::{resource_score_system, ScorerQuery, ScorerTrigger, SkipScoring};
+
+mod common;
+use common::{current, keep_going};
+
+#[derive(Resource)]
+struct Danger(f32);
+
+#[derive(Default, Resource)]
+struct Pondered(u32);
+
+#[derive(Clone, Component, Debug, ScorerBuilder)]
+struct Philosophical;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Flee;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Ponder;
+
+fn philosophical(mut scorers: ScorerQuery<Philosophical>, mut pondered: ResMut<Pondered>) {
+    for (_, mut score, _) in scorers.iter_mut() {
+        pondered.0 += 1;
+        score.set(0.7);
+    }
+}
+
+fn pondered(app: &App) -> u32 {
+    app.world().resource::<Pondered>().0
+}
+
+#[test]
+fn skips_scorers_after_the_picked_choice() {
+    let mut app = common::app();
+    app.insert_resource(Danger(0.9))
+        .init_resource::<Pondered>()
+        .add_systems(
+            PreUpdate,
+            (
+                (resource_score_system::<Danger>, philosophical).in_set(BigBrainSet::Scorers),
+                (keep_going::<Flee>, keep_going::<Ponder>).in_set(BigBrainSet::Actions),
+            ),
+        );
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(LazyFirstToScore::new(0.5))
+                .when(ResourceScore::build(|danger: &Danger| danger.0), Flee)
+                .when(Philosophical, Ponder),
+        )
+        .id();
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(current(&app, actor).as_deref(), Some("Flee"));
+    let before = pondered(&app);
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(pondered(&app), before);
+
+    app.world_mut().resource_mut::<Danger>().0 = 0.0;
+    for _ in 0..4 {
+        app.update();
+    }
+    assert!(pondered(&app) > before);
+    assert_eq!(current(&app, actor).as_deref(), Some("Ponder"));
+}
+
+#[derive(Component)]
+struct Curiosity(f32);
+
+fn curious(
+    curiosity: Query<&Curiosity>,
+    mut scorers: ScorerQuery<Philosophical>,
+    trigger: ScorerTrigger<Changed<Curiosity>>,
+    mut pondered: ResMut<Pondered>,
+) {
+    for (Actor(actor), mut score, _) in scorers.iter_mut() {
+        if !trigger.should_score(*actor, &score) {
+            continue;
+        }
+        if let Ok(curiosity) = curiosity.get(*actor) {
+            pondered.0 += 1;
+            score.set(curiosity.0);
+        }
+    }
+}
+
+#[test]
+fn triggered_scorers_come_back_after_being_skipped() {
+    let mut app = common::app();
+    app.insert_resource(Danger(0.9))
+        .init_resource::<Pondered>()
+        .add_systems(
+            PreUpdate,
+            (
+                (resource_score_system::<Danger>, curious).in_set(BigBrainSet::Scorers),
+                (keep_going::<Flee>, keep_going::<Ponder>).in_set(BigBrainSet::Actions),
+            ),
+        );
+    let actor = app
+        .world_mut()
+        .spawn((
+            Curiosity(0.7),
+            Thinker::build()
+                .picker(LazyFirstToScore::new(0.5))
+                .when(ResourceScore::build(|danger: &Danger| danger.0), Flee)
+                .when(Philosophical, Ponder),
+        ))
+        .id();
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(current(&app, actor).as_deref(), Some("Flee"));
+
+    // Curiosity never changes, but the Score was unset while it was skipped,
+    // so it still gets recomputed once it's needed again.
+    app.world_mut().resource_mut::<Danger>().0 = 0.0;
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(current(&app, actor).as_deref(), Some("Ponder"));
+}
+
+#[test]
+fn built_in_scorers_are_skipped_too() {
+    let mut app = common::app();
+    app.insert_resource(Danger(0.9)).add_systems(
+        PreUpdate,
+        (
+            resource_score_system::<Danger>.in_set(BigBrainSet::Scorers),
+            (keep_going::<Flee>, keep_going::<Ponder>).in_set(BigBrainSet::Actions),
+        ),
+    );
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(LazyFirstToScore::new(0.5))
+            .when(ResourceScore::build(|danger: &Danger| danger.0), Flee)
+            .when(
+                SumOfScorers::build(0.5).push(FixedScore::build(0.7)),
+                Ponder,
+            ),
+    );
+    for _ in 0..5 {
+        app.update();
+    }
+    let world = app.world_mut();
+    let mut sums = world.query_filtered::<&Score, With<SumOfScorers>>();
+    assert!(sums.single(world).is_unset());
+    let mut fixed = world.query_filtered::<(&Score, Has<SkipScoring>), With<FixedScore>>();
+    let (score, is_skipped) = fixed.single(world);
+    assert!(is_skipped);
+    assert!(score.is_unset());
+}
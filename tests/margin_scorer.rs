@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Eat;
+
+fn margin(threshold: f32, inner: f32) -> f32 {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    let actor = app
+        .world_mut()
+        .spawn(Thinker::build().picker(Highest).when(
+            MarginScorer::build(threshold, FixedScore::build(inner)).label("Margin"),
+            Eat,
+        ))
+        .id();
+    for _ in 0..3 {
+        app.update();
+    }
+    big_brain::query_score(app.world(), actor, "Margin").unwrap()
+}
+
+#[test]
+fn scores_distance_above_the_threshold() {
+    assert!((margin(0.6, 0.8) - 0.5).abs() < 1e-5);
+    assert_eq!(margin(0.6, 1.0), 1.0);
+}
+
+#[test]
+fn scores_zero_at_or_below_the_threshold() {
+    assert_eq!(margin(0.6, 0.6), 0.0);
+    assert_eq!(margin(0.6, 0.2), 0.0);
+}
+
+#[test]
+fn threshold_of_one_only_scores_a_perfect_child() {
+    assert_eq!(margin(1.0, 0.99), 0.0);
+    assert_eq!(margin(1.0, 1.0), 1.0);
+}
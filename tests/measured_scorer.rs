@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use big_brain::scorers::MeasuredScorerBuilder;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+fn measured_score(scorer: MeasuredScorerBuilder) -> f32 {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when(scorer.label("Measured"), Idle),
+        )
+        .id();
+    for _ in 0..3 {
+        app.update();
+    }
+    big_brain::query_score(app.world(), actor, "Measured").unwrap()
+}
+
+fn weighted(normalize: bool) -> MeasuredScorerBuilder {
+    MeasuredScorer::build(0.0)
+        .measure(WeightedSum)
+        .normalize_weights(normalize)
+        .push(FixedScore::build(0.4), 2.0)
+        .push(FixedScore::build(0.8), 6.0)
+}
+
+#[test]
+fn normalized_weights_are_relative() {
+    // 0.4 * 0.25 + 0.8 * 0.75
+    assert!((measured_score(weighted(true)) - 0.7).abs() < 1e-5);
+}
+
+#[test]
+fn raw_weights_saturate() {
+    assert_eq!(measured_score(weighted(false)), 1.0);
+}
+
+#[test]
+fn normalizing_zero_weights_scores_zero() {
+    let scorer = MeasuredScorer::build(0.0)
+        .measure(WeightedSum)
+        .normalize_weights(true)
+        .push(FixedScore::build(0.4), 0.0)
+        .push(FixedScore::build(0.8), 0.0);
+    assert_eq!(measured_score(scorer), 0.0);
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+fn score(value: f32) -> Score {
+    let mut score = Score::default();
+    score.set(value);
+    score
+}
+
+fn minkowski(p: f32) -> f32 {
+    let (a, b) = (score(0.6), score(0.4));
+    MinkowskiDistance { p }.calculate(vec![(&a, 0.5), (&b, 1.0)])
+}
+
+#[test]
+fn minkowski_p1_is_a_weighted_sum() {
+    // 0.6 * 0.5 + 0.4 * 1.0
+    assert!((minkowski(1.0) - 0.7).abs() < 1e-6);
+}
+
+#[test]
+fn minkowski_p2_is_euclidean() {
+    // sqrt(0.3^2 + 0.4^2)
+    assert!((minkowski(2.0) - 0.5).abs() < 1e-6);
+}
+
+#[test]
+fn minkowski_approaches_chebyshev() {
+    assert!((minkowski(64.0) - 0.4).abs() < 0.01);
+    assert_eq!(minkowski(f32::INFINITY), 0.4);
+}
+
+#[test]
+fn push_evaluated_transforms_each_child() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    app.world_mut().spawn(
+        Thinker::build().picker(FirstToScore::new(0.5)).when(
+            MeasuredScorer::build(0.0)
+                .measure(WeightedSum)
+                .push_evaluated(FixedScore::build(0.5), 1.0, PowerEvaluator::new(2.0))
+                .push(FixedScore::build(0.5), 1.0),
+            Idle,
+        ),
+    );
+    for _ in 0..3 {
+        app.update();
+    }
+    let world = app.world_mut();
+    let score = world
+        .query_filtered::<&Score, With<MeasuredScorer>>()
+        .single(world);
+    // 0.5^2 + 0.5
+    assert!((score.get() - 0.75).abs() < 1e-6);
+}
@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+mod common;
+use common::{current, keep_going};
+
+#[derive(Clone, Component, Debug, ScorerBuilder)]
+struct Doomed;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Risky;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Safe;
+
+#[test]
+fn despawned_scorers_are_skipped() {
+    let mut app = common::app();
+    app.add_systems(
+        PreUpdate,
+        (keep_going::<Risky>, keep_going::<Safe>).in_set(BigBrainSet::Actions),
+    );
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.0))
+                .when(Doomed, Risky)
+                .when(FixedScore::build(0.0), Safe),
+        )
+        .id();
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(current(&app, actor).as_deref(), Some("Risky"));
+
+    let world = app.world_mut();
+    let doomed = world.query_filtered::<Entity, With<Doomed>>().single(world);
+    world.despawn(doomed);
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(current(&app, actor).as_deref(), Some("Safe"));
+}
@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Default, Resource)]
+struct GlobalState {
+    alert: bool,
+    executing: Vec<&'static str>,
+    interrupted: Vec<&'static str>,
+}
+
+#[derive(Clone, Component, Debug, ScorerBuilder)]
+struct Alert;
+
+fn alert_scorer(mut query: Query<&mut Score, With<Alert>>, state: Res<GlobalState>) {
+    for mut score in query.iter_mut() {
+        score.set(if state.alert { 1.0 } else { 0.0 });
+    }
+}
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Walk;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Scan;
+
+fn run(name: &'static str, state: &mut ActionState, global: &mut GlobalState) {
+    match *state {
+        ActionState::Requested => {
+            global.executing.push(name);
+            *state = ActionState::Executing;
+        }
+        ActionState::Cancelled | ActionState::Interrupted => {
+            global.interrupted.push(name);
+            *state = ActionState::Failure;
+        }
+        _ => {}
+    }
+}
+
+fn walk(mut query: Query<&mut ActionState, With<Walk>>, mut global: ResMut<GlobalState>) {
+    for mut state in query.iter_mut() {
+        run("walk", &mut state, &mut global);
+    }
+}
+
+fn scan(mut query: Query<&mut ActionState, With<Scan>>, mut global: ResMut<GlobalState>) {
+    for mut state in query.iter_mut() {
+        run("scan", &mut state, &mut global);
+    }
+}
+
+#[test]
+fn multi_thinker_runs_picked_choices_concurrently() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .insert_resource(GlobalState {
+            alert: true,
+            ..default()
+        })
+        .add_systems(PreUpdate, alert_scorer.in_set(BigBrainSet::Scorers))
+        .add_systems(PreUpdate, (walk, scan).in_set(BigBrainSet::Actions));
+    app.world_mut().spawn(
+        Thinker::build()
+            .multi(true)
+            .picker(AllToScore::new(0.5))
+            .when(FixedScore::build(1.0), Walk)
+            .when(Alert, Scan),
+    );
+    for _ in 0..10 {
+        app.update();
+    }
+    {
+        let mut global = app.world_mut().resource_mut::<GlobalState>();
+        let mut executing = global.executing.clone();
+        executing.sort();
+        assert_eq!(executing, vec!["scan", "walk"]);
+        assert!(global.interrupted.is_empty());
+        global.alert = false;
+    }
+    for _ in 0..10 {
+        app.update();
+    }
+    let global = app.world().resource::<GlobalState>();
+    // Only scanning stops. Walking keeps going without being restarted.
+    assert_eq!(global.interrupted, vec!["scan"]);
+    assert_eq!(global.executing.len(), 2);
+}
@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+fn nth_score_after_update(threshold: f32, n: usize) -> f32 {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    app.world_mut().spawn(
+        Thinker::build().picker(FirstToScore::new(0.5)).when(
+            NthScorer::build(threshold, n)
+                .push(FixedScore::build(0.4))
+                .push(FixedScore::build(0.9))
+                .push(FixedScore::build(0.7)),
+            Idle,
+        ),
+    );
+    for _ in 0..3 {
+        app.update();
+    }
+    let world = app.world_mut();
+    world
+        .query_filtered::<&Score, With<NthScorer>>()
+        .single(world)
+        .get()
+}
+
+#[test]
+fn picks_nth_highest() {
+    assert_eq!(nth_score_after_update(0.0, 1), 0.9);
+    assert_eq!(nth_score_after_update(0.0, 2), 0.7);
+    assert_eq!(nth_score_after_update(0.0, 3), 0.4);
+}
+
+#[test]
+fn zero_below_threshold() {
+    assert_eq!(nth_score_after_update(0.5, 3), 0.0);
+}
+
+#[test]
+fn zero_when_too_few_children() {
+    assert_eq!(nth_score_after_update(0.0, 4), 0.0);
+}
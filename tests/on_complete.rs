@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Hunt;
+
+#[derive(Resource, Default)]
+struct Completed(Vec<ActionCompleted>);
+
+/// Succeeds every other hunt.
+fn hunt(mut query: Query<&mut ActionState, With<Hunt>>, mut hunts: Local<usize>) {
+    for mut state in query.iter_mut() {
+        if *state == ActionState::Requested {
+            *hunts += 1;
+            *state = if *hunts % 2 == 1 {
+                ActionState::Success
+            } else {
+                ActionState::Failure
+            };
+        }
+    }
+}
+
+fn record(In(completed): In<ActionCompleted>, mut all: ResMut<Completed>) {
+    all.0.push(completed);
+}
+
+#[test]
+fn runs_once_per_completed_action() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<Completed>()
+        .add_systems(PreUpdate, hunt.in_set(BigBrainSet::Actions));
+    let record = app.world_mut().register_system(record);
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .label("Hunter")
+                .picker(FirstToScore::new(0.5))
+                .when(FixedScore::build(1.0), Hunt)
+                .on_complete(record),
+        )
+        .id();
+    for _ in 0..10 {
+        app.update();
+    }
+    let completed = &app.world().resource::<Completed>().0;
+    assert!(completed.len() >= 3, "only {} completions", completed.len());
+    let mut actions: Vec<_> = completed.iter().map(|c| c.action).collect();
+    actions.dedup();
+    assert_eq!(
+        actions.len(),
+        completed.len(),
+        "an action was reported twice"
+    );
+    for (i, completed) in completed.iter().enumerate() {
+        assert_eq!(completed.actor, actor);
+        assert_eq!(completed.label.as_deref(), Some("Hunt"));
+        let expected = if i % 2 == 0 {
+            ActionState::Success
+        } else {
+            ActionState::Failure
+        };
+        assert_eq!(completed.state, expected);
+    }
+}
@@ -0,0 +1,141 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+mod common;
+use common::keep_going;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct OpenGate;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Announce;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Patrol;
+
+#[derive(Resource, Default)]
+struct Finished(Vec<ThinkerFinished>);
+
+fn succeed<T: Component>(mut query: Query<&mut ActionState, With<T>>) {
+    for mut state in query.iter_mut() {
+        if *state == ActionState::Requested {
+            *state = ActionState::Success;
+        }
+    }
+}
+
+fn record(In(finished): In<ThinkerFinished>, mut all: ResMut<Finished>) {
+    all.0.push(finished);
+}
+
+fn app() -> App {
+    let mut app = common::app();
+    app.init_resource::<Finished>().add_systems(
+        PreUpdate,
+        (
+            succeed::<OpenGate>,
+            succeed::<Announce>,
+            keep_going::<Patrol>,
+        )
+            .in_set(BigBrainSet::Actions),
+    );
+    app
+}
+
+#[test]
+fn runs_once_all_one_shot_choices_are_used_up() {
+    let mut app = app();
+    let record = app.world_mut().register_system(record);
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when_once(FixedScore::build(1.0), OpenGate)
+                .when_once(FixedScore::build(0.9), Announce)
+                .finish_when_exhausted(true)
+                .on_finish(record),
+        )
+        .id();
+    for _ in 0..15 {
+        app.update();
+    }
+    let thinker = app.world().get::<HasThinker>(actor).unwrap().entity();
+    assert_eq!(
+        app.world().get::<ActionState>(thinker),
+        Some(&ActionState::Success)
+    );
+    let finished = &app.world().resource::<Finished>().0;
+    assert_eq!(finished.len(), 1);
+    assert_eq!(finished[0].actor, actor);
+    assert_eq!(finished[0].thinker, thinker);
+    assert_eq!(finished[0].state, ActionState::Success);
+}
+
+#[test]
+fn only_finishes_when_exhausted_if_asked_to() {
+    let mut app = app();
+    let record = app.world_mut().register_system(record);
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when_once(FixedScore::build(1.0), OpenGate)
+                .on_finish(record),
+        )
+        .id();
+    for _ in 0..15 {
+        app.update();
+    }
+    let thinker = app.world().get::<HasThinker>(actor).unwrap().entity();
+    assert_eq!(
+        app.world().get::<ActionState>(thinker),
+        Some(&ActionState::Executing)
+    );
+    assert!(app.world().resource::<Finished>().0.is_empty());
+}
+
+#[test]
+fn keeps_going_while_there_is_something_to_fall_back_on() {
+    let mut app = app();
+    let record = app.world_mut().register_system(record);
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when_once(FixedScore::build(1.0), OpenGate)
+            .otherwise(Patrol)
+            .finish_when_exhausted(true)
+            .on_finish(record),
+    );
+    for _ in 0..15 {
+        app.update();
+    }
+    assert!(app.world().resource::<Finished>().0.is_empty());
+}
+
+#[test]
+fn runs_when_the_thinker_is_stopped() {
+    let mut app = app();
+    let record = app.world_mut().register_system(record);
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .otherwise(Patrol)
+                .on_finish(record),
+        )
+        .id();
+    for _ in 0..3 {
+        app.update();
+    }
+    let thinker = app.world().get::<HasThinker>(actor).unwrap().entity();
+    *app.world_mut().get_mut::<ActionState>(thinker).unwrap() = ActionState::Cancelled;
+    for _ in 0..5 {
+        app.update();
+    }
+    let finished = &app.world().resource::<Finished>().0;
+    assert_eq!(finished.len(), 1);
+    assert_eq!(finished[0].state, ActionState::Success);
+}
@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Component, Debug)]
+struct Tagged;
+
+#[derive(Clone, Component, Debug)]
+struct Always;
+
+impl ScorerBuilder for Always {
+    fn build(&self, cmd: &mut Commands, scorer: Entity, _actor: Entity) {
+        cmd.entity(scorer).insert(self.clone());
+    }
+
+    fn on_spawn(&self, cmd: &mut Commands, scorer: Entity) {
+        cmd.entity(scorer).insert((Name::new("Always"), Tagged));
+    }
+}
+
+#[derive(Clone, Component, Debug)]
+struct Idle;
+
+impl ActionBuilder for Idle {
+    fn build(&self, cmd: &mut Commands, action: Entity, _actor: Entity) {
+        cmd.entity(action).insert(self.clone());
+    }
+
+    fn on_spawn(&self, cmd: &mut Commands, action: Entity) {
+        cmd.entity(action).insert((Name::new("Idle"), Tagged));
+    }
+}
+
+fn always(mut query: Query<&mut Score, With<Always>>) {
+    for mut score in query.iter_mut() {
+        score.set(1.0);
+    }
+}
+
+#[test]
+fn on_spawn_tags_scorers_and_actions() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .add_systems(PreUpdate, always.in_set(BigBrainSet::Scorers));
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(Always, Idle),
+    );
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let world = app.world_mut();
+    let scorer = world
+        .query_filtered::<&Name, (With<Always>, With<Tagged>)>()
+        .single(world);
+    assert_eq!(scorer.as_str(), "Always");
+    let action = world
+        .query_filtered::<&Name, (With<Idle>, With<Tagged>)>()
+        .single(world);
+    assert_eq!(action.as_str(), "Idle");
+}
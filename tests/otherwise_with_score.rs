@@ -0,0 +1,73 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use big_brain::scorers::resource_score_system;
+
+mod common;
+use common::{current, keep_going};
+
+#[derive(Resource)]
+struct Curiosity(f32);
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Explore;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Loiter;
+
+fn app(thinker: ThinkerBuilder) -> (App, Entity) {
+    let mut app = common::app();
+    app.insert_resource(Curiosity(0.2)).add_systems(
+        PreUpdate,
+        (
+            resource_score_system::<Curiosity>.in_set(BigBrainSet::Scorers),
+            (keep_going::<Explore>, keep_going::<Loiter>).in_set(BigBrainSet::Actions),
+        ),
+    );
+    let actor = app.world_mut().spawn(thinker).id();
+    for _ in 0..3 {
+        app.update();
+    }
+    (app, actor)
+}
+
+#[test]
+fn competes_with_the_other_choices() {
+    let (mut app, actor) = app(Thinker::build()
+        .picker(Highest)
+        .when(
+            ResourceScore::build(|curiosity: &Curiosity| curiosity.0),
+            Explore,
+        )
+        .otherwise_with_score(Loiter, 0.3));
+    assert_eq!(current(&app, actor).as_deref(), Some("Loiter"));
+
+    app.world_mut().resource_mut::<Curiosity>().0 = 0.8;
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(current(&app, actor).as_deref(), Some("Explore"));
+
+    // Unlike a plain `otherwise`, it interrupts a running choice.
+    app.world_mut().resource_mut::<Curiosity>().0 = 0.2;
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(current(&app, actor).as_deref(), Some("Loiter"));
+}
+
+#[test]
+fn always_comes_after_the_other_choices() {
+    let (app, actor) = app(Thinker::build()
+        .picker(FirstToScore::new(0.1))
+        .otherwise_with_score(Loiter, 0.3)
+        .when(
+            ResourceScore::build(|curiosity: &Curiosity| curiosity.0),
+            Explore,
+        ));
+    assert_eq!(current(&app, actor).as_deref(), Some("Explore"));
+    let thinker = app.world().get::<HasThinker>(actor).unwrap().entity();
+    assert_eq!(
+        app.world().get::<Thinker>(thinker).unwrap().choice_count(),
+        2
+    );
+}
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use big_brain::scorers::over_targets_system;
+
+#[derive(Component)]
+struct Enemy;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Flee;
+
+fn distance_threat(me: &Transform, them: &Transform) -> f32 {
+    1.0 - me.translation.distance(them.translation) / 10.0
+}
+
+fn threat(reducer: TargetReducer, score_fn: fn(&Transform, &Transform) -> f32) -> f32 {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .add_systems(
+            PreUpdate,
+            over_targets_system::<Enemy, &Transform>.in_set(BigBrainSet::Scorers),
+        );
+    for x in [2.0, 4.0, 8.0] {
+        app.world_mut()
+            .spawn((Enemy, Transform::from_xyz(x, 0.0, 0.0)));
+    }
+    // The actor is one of the enemies too, but doesn't count itself.
+    app.world_mut().spawn((
+        Enemy,
+        Transform::default(),
+        Thinker::build().picker(FirstToScore::new(0.5)).when(
+            OverTargets::<Enemy, &Transform>::build(score_fn).reducer(reducer),
+            Flee,
+        ),
+    ));
+    for _ in 0..3 {
+        app.update();
+    }
+    let world = app.world_mut();
+    world
+        .query_filtered::<&Score, With<OverTargets<Enemy, &Transform>>>()
+        .single(world)
+        .get()
+}
+
+#[test]
+fn reduces_target_scores() {
+    assert!((threat(TargetReducer::Max, distance_threat) - 0.8).abs() < 1e-6);
+    assert_eq!(threat(TargetReducer::Sum, distance_threat), 1.0);
+    assert!((threat(TargetReducer::Average, distance_threat) - 1.6 / 3.0).abs() < 1e-6);
+}
+
+#[test]
+fn treats_nan_as_zero() {
+    assert_eq!(threat(TargetReducer::Sum, |_, _| f32::NAN), 0.0);
+}
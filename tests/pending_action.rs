@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Default, Resource)]
+struct Ran {
+    picked: u32,
+    scripted: u32,
+    saw_pending: bool,
+}
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Picked;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Scripted;
+
+fn run_actions(
+    mut picked: Query<&mut ActionState, (With<Picked>, Without<Scripted>)>,
+    mut scripted: Query<&mut ActionState, (With<Scripted>, Without<Picked>)>,
+    mut ran: ResMut<Ran>,
+) {
+    for mut state in picked.iter_mut() {
+        if *state == ActionState::Requested {
+            ran.picked += 1;
+            *state = ActionState::Success;
+        }
+    }
+    for mut state in scripted.iter_mut() {
+        if *state == ActionState::Requested {
+            ran.scripted += 1;
+            *state = ActionState::Success;
+        }
+    }
+}
+
+#[derive(Resource)]
+struct Script(Arc<dyn ActionBuilder>);
+
+fn override_decision(
+    script: Res<Script>,
+    mut pending: Query<&mut PendingAction>,
+    mut ran: ResMut<Ran>,
+) {
+    for mut pending in pending.iter_mut() {
+        if pending.action().is_some() && pending.scorer().is_some() {
+            ran.saw_pending = true;
+        }
+        pending.set(script.0.clone());
+    }
+}
+
+#[test]
+fn decisions_can_be_overridden() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<Ran>()
+        .insert_resource(Script(Arc::new(Scripted)))
+        .add_systems(
+            PreUpdate,
+            (
+                run_actions.in_set(BigBrainSet::Actions),
+                override_decision.in_set(BigBrainSet::Decisions),
+            ),
+        );
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(FixedScore::build(1.0), Picked),
+    );
+    for _ in 0..20 {
+        app.update();
+    }
+    let ran = app.world().resource::<Ran>();
+    assert!(ran.saw_pending);
+    assert_eq!(ran.picked, 0);
+    assert!(ran.scripted > 1);
+}
+
+#[test]
+fn decisions_run_unchanged_without_overrides() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<Ran>()
+        .add_systems(PreUpdate, run_actions.in_set(BigBrainSet::Actions));
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(FixedScore::build(1.0), Picked),
+    );
+    for _ in 0..20 {
+        app.update();
+    }
+    let ran = app.world().resource::<Ran>();
+    assert!(ran.picked > 1);
+    assert_eq!(ran.scripted, 0);
+}
@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use big_brain::scorers::resource_score_system;
+
+mod common;
+use common::keep_going;
+
+#[derive(Resource)]
+struct Urges {
+    home: f32,
+    flee: f32,
+    shoot: f32,
+}
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct GoHome;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Flee;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Shoot;
+
+fn running_in(app: &App, actor: Entity, tag: &str) -> Option<&'static str> {
+    let world = app.world();
+    let thinker = world.get::<HasThinker>(actor)?.entity();
+    let action = world
+        .get::<Thinker>(thinker)?
+        .concurrent_action_for_tag(tag)?;
+    if world.get::<GoHome>(action).is_some() {
+        Some("go home")
+    } else if world.get::<Flee>(action).is_some() {
+        Some("flee")
+    } else if world.get::<Shoot>(action).is_some() {
+        Some("shoot")
+    } else {
+        None
+    }
+}
+
+#[test]
+fn runs_the_best_choice_of_each_group() {
+    let mut app = common::app();
+    app.insert_resource(Urges {
+        home: 0.7,
+        flee: 0.6,
+        shoot: 0.8,
+    })
+    .add_systems(
+        PreUpdate,
+        (
+            resource_score_system::<Urges>.in_set(BigBrainSet::Scorers),
+            (
+                keep_going::<GoHome>,
+                keep_going::<Flee>,
+                keep_going::<Shoot>,
+            )
+                .in_set(BigBrainSet::Actions),
+        ),
+    );
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .multi(true)
+                .picker(HighestPerGroup::new(0.5))
+                .when_tagged(
+                    ResourceScore::build(|urges: &Urges| urges.home),
+                    GoHome,
+                    "movement",
+                )
+                .when_tagged(
+                    ResourceScore::build(|urges: &Urges| urges.flee),
+                    Flee,
+                    "movement",
+                )
+                .when_tagged(
+                    ResourceScore::build(|urges: &Urges| urges.shoot),
+                    Shoot,
+                    "combat",
+                ),
+        )
+        .id();
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(running_in(&app, actor, "movement"), Some("go home"));
+    assert_eq!(running_in(&app, actor, "combat"), Some("shoot"));
+    let shooting = app
+        .world()
+        .get::<Thinker>(app.world().get::<HasThinker>(actor).unwrap().entity())
+        .unwrap()
+        .concurrent_action_for_tag("combat");
+
+    app.world_mut().resource_mut::<Urges>().flee = 0.9;
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(running_in(&app, actor, "movement"), Some("flee"));
+    // The combat group carries on undisturbed.
+    let thinker = app.world().get::<HasThinker>(actor).unwrap().entity();
+    assert_eq!(
+        app.world()
+            .get::<Thinker>(thinker)
+            .unwrap()
+            .concurrent_action_for_tag("combat"),
+        shooting
+    );
+    let world = app.world_mut();
+    assert_eq!(world.query::<&GoHome>().iter(world).count(), 0);
+
+    app.world_mut().resource_mut::<Urges>().shoot = 0.0;
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(running_in(&app, actor, "movement"), Some("flee"));
+    assert_eq!(running_in(&app, actor, "combat"), None);
+}
@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Explore;
+
+#[test]
+fn query_score_finds_scorer_by_label() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when(FixedScore::build(0.7).label("Curious"), Explore),
+        )
+        .id();
+    let other = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when(FixedScore::build(0.2).label("Curious"), Explore),
+        )
+        .id();
+    app.update();
+    app.update();
+    let world = app.world();
+    assert_eq!(big_brain::query_score(world, actor, "Curious"), Some(0.7));
+    assert_eq!(big_brain::query_score(world, other, "Curious"), Some(0.2));
+    assert_eq!(big_brain::query_score(world, actor, "Bored"), None);
+}
+
+#[test]
+fn query_score_looks_through_the_actors_scorer_tree() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build().picker(FirstToScore::new(0.5)).when(
+                AllOrNothing::build(0.1)
+                    .push(FixedScore::build(0.4).label("Hungry"))
+                    .push(FixedScore::build(0.6).label("Tired")),
+                Explore,
+            ),
+        )
+        .id();
+    let stray = app.world_mut().spawn_empty().id();
+    big_brain::scorers::spawn_standalone_scorer(
+        &mut app.world_mut().commands(),
+        stray,
+        &FixedScore::build(0.9).label("Lonely"),
+    );
+    app.update();
+    app.update();
+    let world = app.world();
+    assert_eq!(big_brain::query_score(world, actor, "Hungry"), Some(0.4));
+    assert_eq!(big_brain::query_score(world, actor, "Tired"), Some(0.6));
+    assert_eq!(big_brain::query_score(world, stray, "Lonely"), None);
+}
@@ -0,0 +1,96 @@
+use bevy::prelude::*;
+use big_brain::{
+    actions::{spawn_action, ConcurrentlyBuilder},
+    prelude::*,
+};
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Left;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Right;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Slow;
+
+fn succeed_right_away<T: Component>(mut query: Query<&mut ActionState, With<T>>) {
+    for mut state in query.iter_mut() {
+        if *state == ActionState::Requested {
+            *state = ActionState::Success;
+        }
+    }
+}
+
+fn slow(mut query: Query<&mut ActionState, With<Slow>>) {
+    for mut state in query.iter_mut() {
+        match *state {
+            ActionState::Requested => *state = ActionState::Executing,
+            ActionState::Cancelled => *state = ActionState::Failure,
+            _ => {}
+        }
+    }
+}
+
+/// Runs a race between `Left`, `Right`, and `Slow`, where `Left` and `Right`
+/// succeed on the same tick, and returns the winner's label.
+fn race(builder: ConcurrentlyBuilder) -> Option<&'static str> {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .add_systems(
+            PreUpdate,
+            (
+                succeed_right_away::<Left>,
+                succeed_right_away::<Right>,
+                slow,
+            )
+                .in_set(BigBrainSet::Actions),
+        );
+    let actor = app
+        .world_mut()
+        .spawn(Thinker::build().picker(FirstToScore::new(0.5)))
+        .id();
+    let mut commands = app.world_mut().commands();
+    let race = spawn_action(&builder, &mut commands, actor);
+    app.world_mut().flush();
+    *app.world_mut().get_mut::<ActionState>(race).unwrap() = ActionState::Requested;
+    for _ in 0..5 {
+        app.update();
+    }
+    let world = app.world();
+    assert_eq!(world.get::<ActionState>(race), Some(&ActionState::Success));
+    let concurrently = world.get::<Concurrently>(race).unwrap();
+    let slow = concurrently.actions()[2].entity();
+    assert_eq!(world.get::<ActionState>(slow), Some(&ActionState::Failure));
+    let winner = concurrently.winner()?.entity();
+    if world.get::<Left>(winner).is_some() {
+        Some("left")
+    } else if world.get::<Right>(winner).is_some() {
+        Some("right")
+    } else {
+        Some("other")
+    }
+}
+
+#[test]
+fn ties_go_to_the_earliest_action() {
+    let winner = race(
+        Concurrently::build()
+            .mode(ConcurrentMode::Race)
+            .push(Left)
+            .push(Right)
+            .push(Slow),
+    );
+    assert_eq!(winner, Some("left"));
+}
+
+#[test]
+fn ties_go_to_the_highest_priority() {
+    let winner = race(
+        Concurrently::build()
+            .mode(ConcurrentMode::Race)
+            .push(Left)
+            .push_with_priority(Right, 1)
+            .push(Slow),
+    );
+    assert_eq!(winner, Some("right"));
+}
@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+use big_brain::actions::RandomActionBuilder;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Slash;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Stab;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Spin;
+
+#[derive(Resource, Default)]
+struct Attacks {
+    slash: usize,
+    stab: usize,
+    spin: usize,
+}
+
+fn attack<T: Component>(
+    count: fn(&mut Attacks) -> &mut usize,
+) -> impl FnMut(Query<&mut ActionState, With<T>>, ResMut<Attacks>) {
+    move |mut query, mut attacks| {
+        for mut state in query.iter_mut() {
+            if *state == ActionState::Requested {
+                *count(&mut attacks) += 1;
+                *state = ActionState::Success;
+            }
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+struct Outcomes(Vec<ActionState>);
+
+fn record(In(completed): In<ActionCompleted>, mut outcomes: ResMut<Outcomes>) {
+    outcomes.0.push(completed.state);
+}
+
+fn app(plugin: BigBrainPlugin, attacks: RandomActionBuilder) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, plugin))
+        .insert_resource(ActionRng::seeded(7))
+        .init_resource::<Attacks>()
+        .init_resource::<Outcomes>()
+        .add_systems(
+            PreUpdate,
+            (
+                attack::<Slash>(|a| &mut a.slash),
+                attack::<Stab>(|a| &mut a.stab),
+                attack::<Spin>(|a| &mut a.spin),
+            )
+                .in_set(BigBrainSet::Actions),
+        );
+    let record = app.world_mut().register_system(record);
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(FixedScore::build(1.0), attacks)
+            .on_complete(record),
+    );
+    app
+}
+
+#[test]
+fn picks_again_every_time() {
+    for plugin in [
+        BigBrainPlugin::new(PreUpdate),
+        BigBrainPlugin::new(PreUpdate).pool_actions(true),
+    ] {
+        let mut app = app(
+            plugin,
+            RandomAction::build()
+                .push(Slash)
+                .push_with_weight(Stab, 2.0)
+                .push_with_weight(Spin, 0.0),
+        );
+        for _ in 0..300 {
+            app.update();
+        }
+        let attacks = app.world().resource::<Attacks>();
+        assert!(attacks.slash > 10, "only {} slashes", attacks.slash);
+        assert!(
+            attacks.stab > attacks.slash,
+            "{} stabs, {} slashes",
+            attacks.stab,
+            attacks.slash
+        );
+        assert_eq!(attacks.spin, 0);
+        let outcomes = &app.world().resource::<Outcomes>().0;
+        assert!(outcomes.iter().all(|state| *state == ActionState::Success));
+    }
+}
+
+#[test]
+fn fails_with_nothing_to_pick() {
+    let mut app = app(
+        BigBrainPlugin::new(PreUpdate),
+        RandomAction::build().push_with_weight(Spin, 0.0),
+    );
+    for _ in 0..5 {
+        app.update();
+    }
+    let outcomes = &app.world().resource::<Outcomes>().0;
+    assert!(!outcomes.is_empty());
+    assert!(outcomes.iter().all(|state| *state == ActionState::Failure));
+    assert_eq!(app.world().resource::<Attacks>().spin, 0);
+}
@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use big_brain::prelude::*;
+use big_brain::scorers::resource_score_system;
+
+#[derive(Resource)]
+struct Curiosity(f32);
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Investigate;
+
+fn score(app: &mut App) -> f32 {
+    let world = app.world_mut();
+    world
+        .query_filtered::<&Score, With<RateLimitedScorer>>()
+        .single(world)
+        .get()
+}
+
+#[test]
+fn eases_toward_the_child_score_at_a_fixed_rate() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .insert_resource(Curiosity(1.0))
+        .add_systems(
+            PreUpdate,
+            resource_score_system::<Curiosity>.in_set(BigBrainSet::Scorers),
+        );
+    app.world_mut()
+        .spawn(Thinker::build().picker(FirstToScore::new(0.5)).when(
+            RateLimitedScorer::build(
+                0.5,
+                ResourceScore::build(|curiosity: &Curiosity| curiosity.0),
+            ),
+            Investigate,
+        ));
+    for _ in 0..3 {
+        app.update();
+    }
+
+    // At 0.5 per second and 0.1s per frame, it moves 0.05 per frame.
+    let mut last = score(&mut app);
+    for _ in 0..5 {
+        app.update();
+        let now = score(&mut app);
+        assert!(
+            (now - last - 0.05).abs() < 1e-4,
+            "went from {last} to {now}"
+        );
+        last = now;
+    }
+    for _ in 0..25 {
+        app.update();
+    }
+    assert_eq!(score(&mut app), 1.0);
+
+    // On the way down, too. The child's new score might only show up a frame
+    // later, since nothing orders the two Scorers' systems.
+    app.world_mut().resource_mut::<Curiosity>().0 = 0.8;
+    let mut last = 1.0;
+    for _ in 0..6 {
+        app.update();
+        let now = score(&mut app);
+        assert!(last - now < 0.05 + 1e-4, "went from {last} to {now}");
+        last = now;
+    }
+    assert!((last - 0.8).abs() < 1e-4);
+}
+
+#[test]
+#[should_panic(expected = "max_delta_per_sec must be zero or more")]
+fn rejects_negative_rates() {
+    RateLimitedScorer::build(-1.0, FixedScore::build(1.0));
+}
+
+#[test]
+#[should_panic(expected = "max_delta_per_sec must be zero or more")]
+fn rejects_nan_rates() {
+    RateLimitedScorer::build(f32::NAN, FixedScore::build(1.0));
+}
@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use bevy::ecs::entity::{EntityHashMap, EntityMapper};
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::prelude::*;
+use bevy::reflect::{FromReflect, GetPath};
+use big_brain::{choices::ChoiceBuilder, prelude::*};
+
+#[derive(Clone, Component, Debug, ScorerBuilder)]
+struct Thirsty;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Drink;
+
+#[test]
+fn choice_builder_round_trips_its_data() {
+    let builder = ChoiceBuilder::new(Arc::new(Thirsty), Arc::new(Drink))
+        .once()
+        .tag("water");
+    let rebuilt = ChoiceBuilder::from_reflect(builder.as_partial_reflect())
+        .expect("ChoiceBuilder should be FromReflect");
+    assert_eq!(rebuilt.when_label(), Some("Thirsty"));
+    assert_eq!(rebuilt.then_label(), Some("Drink"));
+    assert_eq!(rebuilt.when.label(), Some("Unresolved"));
+    assert_eq!(rebuilt.then.label(), Some("Unresolved"));
+}
+
+#[test]
+fn data_components_copy_between_worlds() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    let mut score = Score::default();
+    score.set(0.25);
+    let source = app
+        .world_mut()
+        .spawn((score, ActionState::Executing, FixedScore(0.25)))
+        .id();
+
+    let registry = app.world().resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let mut target = World::new();
+    let copy = target.spawn_empty().id();
+    for type_path in [
+        "big_brain::scorers::Score",
+        "big_brain::actions::ActionState",
+        "big_brain::scorers::FixedScore",
+    ] {
+        let registration = registry
+            .get_with_type_path(type_path)
+            .unwrap_or_else(|| panic!("{type_path} should be registered"));
+        let reflect_component = registration
+            .data::<ReflectComponent>()
+            .unwrap_or_else(|| panic!("{type_path} should reflect Component"));
+        let value = reflect_component
+            .reflect(app.world().entity(source))
+            .unwrap()
+            .clone_value();
+        reflect_component.insert(
+            &mut target.entity_mut(copy),
+            value.as_partial_reflect(),
+            &registry,
+        );
+    }
+
+    assert_eq!(target.get::<Score>(copy).unwrap().get(), 0.25);
+    assert_eq!(
+        target.get::<ActionState>(copy),
+        Some(&ActionState::Executing)
+    );
+    assert_eq!(target.get::<FixedScore>(copy).unwrap().0, 0.25);
+}
+
+struct Remap(EntityHashMap<Entity>);
+
+impl EntityMapper for Remap {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        self.0.get(&entity).copied().unwrap_or(entity)
+    }
+}
+
+#[test]
+fn composite_scorers_remap_their_children() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    app.world_mut().spawn(
+        Thinker::build().picker(FirstToScore::new(0.5)).when(
+            AllOrNothing::build(0.5)
+                .push(FixedScore::build(1.0))
+                .push(FixedScore::build(0.75)),
+            Drink,
+        ),
+    );
+    for _ in 0..3 {
+        app.update();
+    }
+    let world = app.world_mut();
+    let (source, children) = world
+        .query_filtered::<(Entity, &Children), With<AllOrNothing>>()
+        .single(world);
+    let children = children.to_vec();
+
+    let mut target = World::new();
+    let copy = target.spawn_empty().id();
+    let mut remap = Remap(EntityHashMap::default());
+    for child in children.iter() {
+        remap.0.insert(*child, target.spawn_empty().id());
+    }
+
+    let registry = app.world().resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let registration = registry
+        .get_with_type_path("big_brain::scorers::AllOrNothing")
+        .expect("AllOrNothing should be registered");
+    let reflect_component = registration.data::<ReflectComponent>().unwrap();
+    let mut value = reflect_component
+        .reflect(app.world().entity(source))
+        .unwrap()
+        .clone_value();
+    registration
+        .data::<ReflectMapEntities>()
+        .expect("AllOrNothing should reflect MapEntities")
+        .map_entities(value.as_partial_reflect_mut(), &mut remap);
+    reflect_component.insert(
+        &mut target.entity_mut(copy),
+        value.as_partial_reflect(),
+        &registry,
+    );
+
+    let copied = reflect_component.reflect(target.entity(copy)).unwrap();
+    for (i, child) in children.iter().enumerate() {
+        let mapped = copied
+            .reflect_path(format!("scorers[{i}].0").as_str())
+            .unwrap()
+            .try_downcast_ref::<Entity>()
+            .copied();
+        assert_eq!(mapped, Some(remap.0[child]));
+    }
+}
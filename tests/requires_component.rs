@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use big_brain::scorers::requires_component_system;
+
+#[derive(Component)]
+struct HasWeapon;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Shoot;
+
+#[test]
+fn scores_only_while_the_actor_has_the_component() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .add_systems(
+            PreUpdate,
+            requires_component_system::<HasWeapon>.in_set(BigBrainSet::Scorers),
+        );
+    let actor = app
+        .world_mut()
+        .spawn(Thinker::build().picker(FirstToScore::new(0.5)).when(
+            RequiresComponent::<HasWeapon>::build(FixedScore::build(0.75)),
+            Shoot,
+        ))
+        .id();
+    let score = |app: &mut App| {
+        for _ in 0..3 {
+            app.update();
+        }
+        let world = app.world_mut();
+        world
+            .query_filtered::<&Score, With<RequiresComponent<HasWeapon>>>()
+            .single(world)
+            .get()
+    };
+    assert_eq!(score(&mut app), 0.0);
+    app.world_mut().entity_mut(actor).insert(HasWeapon);
+    assert_eq!(score(&mut app), 0.75);
+    app.world_mut().entity_mut(actor).remove::<HasWeapon>();
+    assert_eq!(score(&mut app), 0.0);
+}
@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use big_brain::scorers::resource_score_system;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Hide;
+
+#[derive(Resource)]
+struct AlarmLevel(f32);
+
+fn alarmed_app() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .add_systems(
+            PreUpdate,
+            resource_score_system::<AlarmLevel>.in_set(BigBrainSet::Scorers),
+        );
+    let actor = app
+        .world_mut()
+        .spawn(Thinker::build().picker(FirstToScore::new(0.5)).when(
+            ResourceScore::build(|alarm: &AlarmLevel| alarm.0).label("Alarmed"),
+            Hide,
+        ))
+        .id();
+    (app, actor)
+}
+
+#[test]
+fn resource_score_follows_the_resource() {
+    let (mut app, actor) = alarmed_app();
+    app.insert_resource(AlarmLevel(0.2));
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(
+        big_brain::query_score(app.world(), actor, "Alarmed"),
+        Some(0.2)
+    );
+    app.world_mut().resource_mut::<AlarmLevel>().0 = 0.9;
+    app.update();
+    assert_eq!(
+        big_brain::query_score(app.world(), actor, "Alarmed"),
+        Some(0.9)
+    );
+}
+
+#[test]
+fn resource_score_is_left_alone_without_the_resource() {
+    let (mut app, actor) = alarmed_app();
+    app.insert_resource(AlarmLevel(0.7));
+    for _ in 0..3 {
+        app.update();
+    }
+    app.world_mut().remove_resource::<AlarmLevel>();
+    app.update();
+    assert_eq!(
+        big_brain::query_score(app.world(), actor, "Alarmed"),
+        Some(0.7)
+    );
+}
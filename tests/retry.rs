@@ -0,0 +1,173 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use big_brain::actions::RetryBuilder;
+use big_brain::prelude::*;
+use big_brain::scorers::resource_score_system;
+
+#[derive(Default, Resource)]
+struct Attempts {
+    succeed_on: u32,
+    made: u32,
+    result: Option<(ActionState, u32)>,
+}
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Flaky;
+
+fn flaky(mut query: Query<&mut ActionState, With<Flaky>>, mut attempts: ResMut<Attempts>) {
+    for mut state in query.iter_mut() {
+        if *state == ActionState::Requested {
+            attempts.made += 1;
+            *state = if attempts.made == attempts.succeed_on {
+                ActionState::Success
+            } else {
+                ActionState::Failure
+            };
+        }
+    }
+}
+
+fn record_result(query: Query<(&ActionState, &Retry)>, mut attempts: ResMut<Attempts>) {
+    for (state, retry) in query.iter() {
+        if attempts.result.is_none() && matches!(state, ActionState::Success | ActionState::Failure)
+        {
+            attempts.result = Some((state.clone(), retry.attempts()));
+        }
+    }
+}
+
+fn run(succeed_on: u32) -> Attempts {
+    run_retry(succeed_on, Retry::build(3).action(Flaky))
+}
+
+fn run_retry(succeed_on: u32, retry: RetryBuilder) -> Attempts {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .insert_resource(Attempts {
+            succeed_on,
+            ..default()
+        })
+        .add_systems(PreUpdate, flaky.in_set(BigBrainSet::Actions))
+        .add_systems(Last, record_result);
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(FixedScore::build(1.0), retry),
+    );
+    for _ in 0..20 {
+        app.update();
+        if app.world().resource::<Attempts>().result.is_some() {
+            break;
+        }
+    }
+    app.world_mut()
+        .remove_resource::<Attempts>()
+        .expect("resource went missing")
+}
+
+#[test]
+fn retry_succeeds_on_second_try() {
+    let attempts = run(2);
+    assert_eq!(attempts.made, 2);
+    assert_eq!(attempts.result, Some((ActionState::Success, 2)));
+}
+
+#[test]
+fn retry_fails_after_exhausting_attempts() {
+    let attempts = run(u32::MAX);
+    assert_eq!(attempts.made, 3);
+    assert_eq!(attempts.result, Some((ActionState::Failure, 3)));
+}
+
+#[test]
+fn retry_without_an_action_fails() {
+    let attempts = run_retry(1, Retry::build(3));
+    assert_eq!(attempts.made, 0);
+    assert_eq!(attempts.result, Some((ActionState::Failure, 0)));
+}
+
+#[derive(Default, Resource)]
+struct Started(Vec<u32>);
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+#[derive(Resource)]
+struct Wanted(f32);
+
+fn always_fails(
+    mut query: Query<&mut ActionState, With<Flaky>>,
+    mut started: ResMut<Started>,
+    mut frame: Local<u32>,
+) {
+    *frame += 1;
+    for mut state in query.iter_mut() {
+        if *state == ActionState::Requested {
+            started.0.push(*frame);
+            *state = ActionState::Failure;
+        }
+    }
+}
+
+fn backoff_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .init_resource::<Started>()
+        .insert_resource(Wanted(1.0))
+        .add_systems(
+            PreUpdate,
+            (
+                resource_score_system::<Wanted>.in_set(BigBrainSet::Scorers),
+                always_fails.in_set(BigBrainSet::Actions),
+            ),
+        );
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(
+                ResourceScore::build(|wanted: &Wanted| wanted.0),
+                Retry::build(3)
+                    .backoff(Duration::from_millis(500))
+                    .wrap(Flaky),
+            )
+            .when(ResourceScore::build(|wanted: &Wanted| 1.0 - wanted.0), Idle),
+    );
+    app
+}
+
+#[test]
+fn retry_waits_out_the_backoff() {
+    let mut app = backoff_app();
+    for _ in 0..20 {
+        app.update();
+    }
+    // The Thinker picks `Retry` again once it fails, so only look at the
+    // first one's attempts.
+    let started = &app.world().resource::<Started>().0[..3];
+    for pair in started.windows(2) {
+        assert!(
+            pair[1] - pair[0] >= 5,
+            "attempts started too close together: {started:?}"
+        );
+    }
+}
+
+#[test]
+fn cancelling_during_backoff_stops_retrying() {
+    let mut app = backoff_app();
+    while app.world().resource::<Started>().0.is_empty() {
+        app.update();
+    }
+    app.world_mut().resource_mut::<Wanted>().0 = 0.0;
+    for _ in 0..20 {
+        app.update();
+    }
+    assert_eq!(app.world().resource::<Started>().0.len(), 1);
+    let world = app.world_mut();
+    assert_eq!(world.query::<&Retry>().iter(world).count(), 0);
+}
@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+#[cfg(feature = "states")]
+use bevy::state::app::StatesPlugin;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Drink;
+
+#[derive(Resource, Default)]
+struct Drinks(usize);
+
+#[derive(Resource)]
+struct Awake(bool);
+
+#[cfg(feature = "states")]
+#[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+enum GameState {
+    #[default]
+    Menu,
+    Playing,
+}
+
+fn drink(mut query: Query<&mut ActionState, With<Drink>>, mut drinks: ResMut<Drinks>) {
+    for mut state in query.iter_mut() {
+        if *state == ActionState::Requested {
+            drinks.0 += 1;
+            *state = ActionState::Success;
+        }
+    }
+}
+
+fn drinking_app(plugin: BigBrainPlugin) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, plugin))
+        .init_resource::<Drinks>()
+        .add_systems(PreUpdate, drink.in_set(BigBrainSet::Actions));
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(FixedScore::build(1.0), Drink),
+    );
+    app
+}
+
+fn drinks_after_updates(app: &mut App) -> usize {
+    for _ in 0..5 {
+        app.update();
+    }
+    app.world().resource::<Drinks>().0
+}
+
+#[test]
+fn run_if_gates_everything() {
+    let mut app = drinking_app(BigBrainPlugin::new(PreUpdate).run_if(|awake: Res<Awake>| awake.0));
+    app.insert_resource(Awake(false));
+    assert_eq!(drinks_after_updates(&mut app), 0);
+
+    app.insert_resource(Awake(true));
+    let drinks = drinks_after_updates(&mut app);
+    assert!(drinks > 0);
+
+    app.insert_resource(Awake(false));
+    assert_eq!(drinks_after_updates(&mut app), drinks);
+}
+
+#[cfg(feature = "states")]
+#[test]
+fn run_in_state_only_runs_in_that_state() {
+    let mut app = drinking_app(BigBrainPlugin::new(PreUpdate).run_in_state(GameState::Playing));
+    app.add_plugins(StatesPlugin).init_state::<GameState>();
+    assert_eq!(drinks_after_updates(&mut app), 0);
+
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Playing);
+    assert!(drinks_after_updates(&mut app) > 0);
+}
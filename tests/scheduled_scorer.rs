@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use big_brain::scorers::scheduled_scorer_system;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Sleep;
+
+#[derive(Resource)]
+struct Clock {
+    hour: f32,
+}
+
+#[test]
+fn scheduled_scorer_follows_the_clock() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .insert_resource(Clock { hour: 12.0 })
+        .add_systems(
+            PreUpdate,
+            scheduled_scorer_system::<Clock>.in_set(BigBrainSet::Scorers),
+        );
+    let night = CurveEvaluator::new([(6.0, 1.0), (8.0, 0.2), (20.0, 0.2), (22.0, 1.0)]);
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build().picker(FirstToScore::new(0.5)).when(
+                ScheduledScorer::build(FixedScore::build(0.5), |clock: &Clock| clock.hour, night)
+                    .label("Sleepy"),
+                Sleep,
+            ),
+        )
+        .id();
+    let mut sleepiness_at = |hour: f32| {
+        app.world_mut().resource_mut::<Clock>().hour = hour;
+        for _ in 0..3 {
+            app.update();
+        }
+        big_brain::query_score(app.world(), actor, "Sleepy").unwrap()
+    };
+    assert!((sleepiness_at(12.0) - 0.1).abs() < 1e-5);
+    assert!((sleepiness_at(21.0) - 0.3).abs() < 1e-5);
+    assert!((sleepiness_at(2.0) - 0.5).abs() < 1e-5);
+}
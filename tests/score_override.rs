@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+mod common;
+use common::{current, keep_going};
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Flee;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Wander;
+
+fn settle(app: &mut App) {
+    for _ in 0..3 {
+        app.update();
+    }
+}
+
+#[test]
+fn overrides_win_until_cleared() {
+    let mut app = common::app();
+    app.add_systems(
+        PreUpdate,
+        (keep_going::<Flee>, keep_going::<Wander>).in_set(BigBrainSet::Actions),
+    );
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(Highest)
+                .when(FixedScore::build(0.3).label("Scared"), Flee)
+                .when(FixedScore::build(0.6).label("Bored"), Wander),
+        )
+        .id();
+    settle(&mut app);
+    assert_eq!(current(&app, actor).as_deref(), Some("Wander"));
+
+    assert!(big_brain::set_score_override(
+        app.world_mut(),
+        actor,
+        "Scared",
+        Some(1.0)
+    ));
+    settle(&mut app);
+    assert_eq!(
+        big_brain::query_score(app.world(), actor, "Scared"),
+        Some(1.0)
+    );
+    assert_eq!(current(&app, actor).as_deref(), Some("Flee"));
+
+    assert!(big_brain::set_score_override(
+        app.world_mut(),
+        actor,
+        "Scared",
+        None
+    ));
+    settle(&mut app);
+    assert_eq!(
+        big_brain::query_score(app.world(), actor, "Scared"),
+        Some(0.3)
+    );
+    assert_eq!(current(&app, actor).as_deref(), Some("Wander"));
+
+    assert!(!big_brain::set_score_override(
+        app.world_mut(),
+        actor,
+        "Hungry",
+        Some(1.0)
+    ));
+}
+
+#[test]
+fn composites_see_overridden_children() {
+    let mut app = common::app();
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build().picker(FirstToScore::new(0.5)).when(
+                SumOfScorers::build(0.0)
+                    .label("Total")
+                    .push(FixedScore::build(0.25).label("Small"))
+                    .push(FixedScore::build(0.5).label("Large")),
+                Wander,
+            ),
+        )
+        .id();
+    settle(&mut app);
+
+    let world = app.world_mut();
+    let small = world
+        .query::<(Entity, &ScorerSpan)>()
+        .iter(world)
+        .find(|(_, span)| span.label() == Some("Small"))
+        .map(|(scorer, _)| scorer)
+        .unwrap();
+    world.entity_mut(small).insert(ScoreOverride(Some(0.0)));
+    app.update();
+    assert_eq!(
+        big_brain::query_score(app.world(), actor, "Total"),
+        Some(0.5)
+    );
+
+    // The override sticks around until it's removed.
+    settle(&mut app);
+    assert_eq!(
+        big_brain::query_score(app.world(), actor, "Total"),
+        Some(0.5)
+    );
+    app.world_mut().entity_mut(small).remove::<ScoreOverride>();
+    app.update();
+    assert_eq!(
+        big_brain::query_score(app.world(), actor, "Total"),
+        Some(0.75)
+    );
+}
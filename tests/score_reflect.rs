@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+use bevy::reflect::ReflectRef;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+#[test]
+fn score_value_is_visible_through_reflection() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(FixedScore::build(0.7), Idle),
+    );
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let world = app.world_mut();
+    let scorer = world
+        .query_filtered::<Entity, With<FixedScore>>()
+        .single(world);
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let reflect_component = registry
+        .get_type_data::<ReflectComponent>(std::any::TypeId::of::<Score>())
+        .expect("Score should be registered as a reflected Component");
+    let score = reflect_component
+        .reflect(world.entity(scorer))
+        .expect("the scorer should have a Score");
+    let ReflectRef::Struct(score) = score.reflect_ref() else {
+        panic!("Score should reflect as a struct");
+    };
+    let value = score
+        .field("value")
+        .and_then(|value| value.try_downcast_ref::<f32>())
+        .copied();
+    assert_eq!(value, Some(0.7));
+}
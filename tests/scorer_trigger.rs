@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use big_brain::scorers::{ScorerQuery, ScorerTrigger};
+
+#[derive(Component)]
+struct Thirst(f32);
+
+#[derive(Default, Resource)]
+struct Computed(u32);
+
+#[derive(Clone, Component, Debug, ScorerBuilder)]
+struct Thirsty;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+fn thirsty_scorer_system(
+    thirsts: Query<&Thirst>,
+    mut scorers: ScorerQuery<Thirsty>,
+    trigger: ScorerTrigger<Changed<Thirst>>,
+    mut computed: ResMut<Computed>,
+) {
+    for (Actor(actor), mut score, _span) in scorers.iter_mut() {
+        if !trigger.should_score(*actor, &score) {
+            continue;
+        }
+        if let Ok(thirst) = thirsts.get(*actor) {
+            computed.0 += 1;
+            score.set(thirst.0);
+        }
+    }
+}
+
+fn score(app: &mut App) -> f32 {
+    let world = app.world_mut();
+    world
+        .query_filtered::<&Score, With<Thirsty>>()
+        .single(world)
+        .get()
+}
+
+#[test]
+fn only_scores_when_the_dependency_changes() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<Computed>()
+        .add_systems(
+            PreUpdate,
+            thirsty_scorer_system.in_set(BigBrainSet::Scorers),
+        );
+    let actor = app
+        .world_mut()
+        .spawn((
+            Thirst(0.3),
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when(Thirsty, Idle),
+        ))
+        .id();
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(app.world().resource::<Computed>().0, 1);
+    assert_eq!(score(&mut app), 0.3);
+
+    app.world_mut().get_mut::<Thirst>(actor).unwrap().0 = 0.6;
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(app.world().resource::<Computed>().0, 2);
+    assert_eq!(score(&mut app), 0.6);
+}
@@ -0,0 +1,70 @@
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use big_brain::scorers::resource_score_system;
+
+mod common;
+use common::keep_going;
+
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct SlowTick;
+
+#[derive(Resource)]
+struct Thirst(f32);
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Drink;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+fn current(app: &App, actor: Entity) -> Option<String> {
+    let thinker = app.world().get::<HasThinker>(actor)?.entity();
+    app.world()
+        .get::<Thinker>(thinker)?
+        .current_action_label()
+        .map(str::to_string)
+}
+
+#[test]
+fn scorers_only_update_when_their_schedule_runs() {
+    let plugin = BigBrainPlugin::new(PreUpdate).set_scorers_schedule(SlowTick);
+    let mut app = App::new();
+    app.init_schedule(SlowTick)
+        .insert_resource(Thirst(0.0))
+        .add_systems(
+            plugin.scorers_schedule(),
+            resource_score_system::<Thirst>.in_set(BigBrainSet::Scorers),
+        )
+        .add_systems(
+            PreUpdate,
+            (keep_going::<Drink>, keep_going::<Idle>).in_set(BigBrainSet::Actions),
+        )
+        .add_plugins((MinimalPlugins, plugin));
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when(ResourceScore::build(|thirst: &Thirst| thirst.0), Drink)
+                .otherwise(Idle),
+        )
+        .id();
+    app.world_mut().run_schedule(SlowTick);
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(current(&app, actor).as_deref(), Some("Idle"));
+
+    app.world_mut().resource_mut::<Thirst>().0 = 1.0;
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(current(&app, actor).as_deref(), Some("Idle"));
+
+    app.world_mut().run_schedule(SlowTick);
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(current(&app, actor).as_deref(), Some("Drink"));
+}
@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+use big_brain::{prelude::*, thinker::Scratch};
+
+#[derive(Component, Debug, Default)]
+struct Target(Option<Entity>);
+
+#[derive(Default, Resource)]
+struct Reached(Option<Entity>);
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct PickTarget;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct GoToTarget;
+
+fn pick_target(
+    mut query: Query<(&Actor, &mut ActionState), With<PickTarget>>,
+    mut scratch: Scratch<Target>,
+) {
+    for (Actor(actor), mut state) in query.iter_mut() {
+        if *state == ActionState::Requested {
+            scratch.set(*actor, Target(Some(*actor)));
+            *state = ActionState::Success;
+        }
+    }
+}
+
+fn go_to_target(
+    mut query: Query<(&Actor, &mut ActionState), With<GoToTarget>>,
+    scratch: Scratch<Target>,
+    mut reached: ResMut<Reached>,
+) {
+    for (Actor(actor), mut state) in query.iter_mut() {
+        if *state == ActionState::Requested {
+            reached.0 = scratch.get(*actor).and_then(|target| target.0);
+            *state = ActionState::Success;
+        }
+    }
+}
+
+#[test]
+fn steps_share_scratch_slot() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<Reached>()
+        .add_systems(
+            PreUpdate,
+            (pick_target, go_to_target).in_set(BigBrainSet::Actions),
+        );
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .with_scratch::<Target>()
+                .when_once(
+                    FixedScore::build(1.0),
+                    Steps::build().step(PickTarget).step(GoToTarget),
+                ),
+        )
+        .id();
+    app.update();
+    assert!(app.world().get::<Target>(actor).is_some());
+    for _ in 0..20 {
+        app.update();
+    }
+    assert_eq!(app.world().resource::<Reached>().0, Some(actor));
+}
+
+#[test]
+fn with_scratch_keeps_existing_component() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    let actor = app.world_mut().spawn_empty().id();
+    app.world_mut().entity_mut(actor).insert((
+        Target(Some(actor)),
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .with_scratch::<Target>(),
+    ));
+    app.update();
+    assert_eq!(
+        app.world().get::<Target>(actor).and_then(|t| t.0),
+        Some(actor)
+    );
+}
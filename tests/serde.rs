@@ -0,0 +1,28 @@
+#![cfg(feature = "serde")]
+
+use big_brain::prelude::*;
+
+#[test]
+fn action_state_round_trips() {
+    for state in [
+        ActionState::Init,
+        ActionState::Requested,
+        ActionState::Executing,
+        ActionState::Cancelled,
+        ActionState::Interrupted,
+        ActionState::Yielded,
+        ActionState::Success,
+        ActionState::Failure,
+    ] {
+        let saved = ron::to_string(&state).unwrap();
+        assert_eq!(ron::from_str::<ActionState>(&saved).unwrap(), state);
+    }
+}
+
+#[test]
+fn action_state_saves_as_its_variant_name() {
+    assert_eq!(
+        ron::to_string(&ActionState::Executing).unwrap(),
+        "Executing"
+    );
+}
@@ -0,0 +1,154 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Debug, Clone, Component, ScorerBuilder)]
+#[scorer_shared]
+struct EnemyDistance;
+
+#[derive(Debug, Clone, Component, ScorerBuilder)]
+struct Unshared;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Flee;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Attack;
+
+#[test]
+fn shared_scorers_are_spawned_once_per_thinker() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    for _ in 0..2 {
+        app.world_mut().spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when(EnemyDistance, Flee)
+                .when(
+                    WinningScorer::build(0.5).push(EnemyDistance).push(Unshared),
+                    Attack,
+                )
+                .when(Unshared, Attack),
+        );
+    }
+    app.update();
+    let world = app.world_mut();
+    let shared = world
+        .query_filtered::<&Actor, With<EnemyDistance>>()
+        .iter(world)
+        .count();
+    let unshared = world
+        .query_filtered::<&Actor, With<Unshared>>()
+        .iter(world)
+        .count();
+    // One per actor, but unshared scorers still get one per use.
+    assert_eq!(shared, 2);
+    assert_eq!(unshared, 4);
+}
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Quick;
+
+fn enemy_distance_system(mut query: Query<&mut Score, With<EnemyDistance>>) {
+    for mut score in query.iter_mut() {
+        score.set(1.0);
+    }
+}
+
+fn quick_system(mut query: Query<&mut ActionState, With<Quick>>) {
+    for mut state in query.iter_mut() {
+        if *state == ActionState::Requested {
+            *state = ActionState::Success;
+        }
+    }
+}
+
+fn composite_app(thinker: ThinkerBuilder) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .add_systems(
+            PreUpdate,
+            enemy_distance_system.in_set(BigBrainSet::Scorers),
+        )
+        .add_systems(PreUpdate, quick_system.in_set(BigBrainSet::Actions));
+    app.world_mut().spawn(thinker);
+    app
+}
+
+fn shared_scorer(app: &mut App) -> Entity {
+    let world = app.world_mut();
+    world
+        .query_filtered::<Entity, With<EnemyDistance>>()
+        .single(world)
+}
+
+#[test]
+fn consuming_a_shared_scorer_keeps_it_for_composites() {
+    let mut app = composite_app(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when_once(EnemyDistance, Quick)
+            .when(SumOfScorers::build(0.5).push(EnemyDistance), Attack),
+    );
+    app.update();
+    let shared = shared_scorer(&mut app);
+    for _ in 0..10 {
+        app.update();
+    }
+    let world = app.world_mut();
+    assert!(world.get_entity(shared).is_ok());
+    let sum = world.query::<(&SumOfScorers, &Score)>().single(world).1;
+    assert_eq!(sum.get(), 1.0);
+    assert_eq!(world.query::<&Attack>().iter(world).count(), 1);
+}
+
+#[test]
+fn despawning_a_composite_keeps_its_shared_scorers() {
+    let mut app = composite_app(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(SumOfScorers::build(0.5).push(EnemyDistance), Attack)
+            .when(
+                AllOrNothing::build(0.5)
+                    .push(EnemyDistance)
+                    .push(FixedScore::build(1.0)),
+                Quick,
+            ),
+    );
+    app.update();
+    let shared = shared_scorer(&mut app);
+    let world = app.world_mut();
+    let all = world
+        .query_filtered::<Entity, With<AllOrNothing>>()
+        .single(world);
+    world.entity_mut(all).despawn_recursive();
+    for _ in 0..5 {
+        app.update();
+    }
+    let world = app.world_mut();
+    assert!(world.get_entity(shared).is_ok());
+    let sum = world.query::<(&SumOfScorers, &Score)>().single(world).1;
+    assert_eq!(sum.get(), 1.0);
+}
+
+#[test]
+fn consumed_choices_despawn_unused_shared_scorers() {
+    let mut app = composite_app(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when_once(
+                AllOrNothing::build(0.5)
+                    .push(EnemyDistance)
+                    .push(FixedScore::build(1.0)),
+                Quick,
+            )
+            .when(Unshared, Attack),
+    );
+    app.update();
+    let shared = shared_scorer(&mut app);
+    for _ in 0..10 {
+        app.update();
+    }
+    let world = app.world_mut();
+    assert_eq!(world.query::<&AllOrNothing>().iter(world).count(), 0);
+    assert!(world.get_entity(shared).is_err());
+}
@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+mod common;
+use common::keep_going;
+
+#[derive(Default, Resource)]
+struct Chopped(u32);
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Chop;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Stack;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+fn chop(mut query: Query<&mut ActionState, With<Chop>>, mut chopped: ResMut<Chopped>) {
+    for mut state in query.iter_mut() {
+        if *state == ActionState::Requested {
+            chopped.0 += 1;
+            *state = ActionState::Success;
+        }
+    }
+}
+
+fn new_app() -> App {
+    let mut app = common::app();
+    app.init_resource::<Chopped>().add_systems(
+        PreUpdate,
+        (chop, keep_going::<Stack>, keep_going::<Idle>).in_set(BigBrainSet::Actions),
+    );
+    app
+}
+
+fn woodcutter() -> ThinkerBuilder {
+    Thinker::build()
+        .picker(FirstToScore::new(0.5))
+        .when(FixedScore::build(0.2), Idle)
+        .otherwise(
+            Steps::build()
+                .label("Chores")
+                .step(Chop)
+                .step(Stack)
+                .step(Chop),
+        )
+}
+
+fn active_step(app: &App, actor: Entity) -> Option<usize> {
+    big_brain::snapshot_thinker(app.world(), actor)?.active_step
+}
+
+#[test]
+fn picks_up_where_it_left_off() {
+    let mut app = new_app();
+    let actor = app.world_mut().spawn(woodcutter()).id();
+    for _ in 0..5 {
+        app.update();
+    }
+    let snapshot = big_brain::snapshot_thinker(app.world(), actor).unwrap();
+    assert_eq!(
+        snapshot,
+        ThinkerSnapshot {
+            current_action: Some("Chores".into()),
+            current_state: Some(ActionState::Executing),
+            active_step: Some(1),
+            scheduled_actions: Vec::new(),
+        }
+    );
+
+    let mut loaded = new_app();
+    let actor = loaded.world_mut().spawn(woodcutter()).id();
+    let action = big_brain::restore_thinker(loaded.world_mut(), actor, &snapshot).unwrap();
+    assert!(loaded.world().get::<Steps>(action).is_some());
+    for _ in 0..5 {
+        loaded.update();
+    }
+    assert_eq!(active_step(&loaded, actor), Some(1));
+    assert_eq!(loaded.world().resource::<Chopped>().0, 0);
+}
+
+#[test]
+fn decides_as_usual_without_a_matching_action() {
+    let mut app = new_app();
+    let actor = app.world_mut().spawn(woodcutter()).id();
+    let snapshot = ThinkerSnapshot {
+        current_action: Some("Nap".into()),
+        current_state: Some(ActionState::Executing),
+        ..default()
+    };
+    big_brain::restore_thinker(app.world_mut(), actor, &snapshot).unwrap();
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(active_step(&app, actor), Some(1));
+    assert_eq!(app.world().resource::<Chopped>().0, 1);
+}
+
+#[test]
+fn restores_scheduled_actions_by_label() {
+    let mut app = new_app();
+    let actor = app.world_mut().spawn(woodcutter()).id();
+    let snapshot = ThinkerSnapshot {
+        current_action: Some("Chores".into()),
+        current_state: Some(ActionState::Executing),
+        active_step: Some(1),
+        scheduled_actions: vec![Some("Idle".into()), None, Some("Nap".into())],
+    };
+    big_brain::restore_thinker(app.world_mut(), actor, &snapshot).unwrap();
+    assert_eq!(
+        big_brain::snapshot_thinker(app.world(), actor)
+            .unwrap()
+            .scheduled_actions,
+        vec![Some("Idle".to_string())]
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn round_trips_through_serde() {
+    let mut app = new_app();
+    let actor = app.world_mut().spawn(woodcutter()).id();
+    for _ in 0..5 {
+        app.update();
+    }
+    let snapshot = big_brain::snapshot_thinker(app.world(), actor).unwrap();
+    let saved = ron::to_string(&snapshot).unwrap();
+    let loaded_snapshot: ThinkerSnapshot = ron::from_str(&saved).unwrap();
+    assert_eq!(loaded_snapshot, snapshot);
+
+    let mut loaded = new_app();
+    let actor = loaded.world_mut().spawn(woodcutter()).id();
+    big_brain::restore_thinker(loaded.world_mut(), actor, &loaded_snapshot).unwrap();
+    for _ in 0..5 {
+        loaded.update();
+    }
+    assert_eq!(active_step(&loaded, actor), Some(1));
+}
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Wander;
+
+#[derive(Component)]
+struct Villager(usize);
+
+fn spawn_village(mut cmd: Commands) {
+    let mut next = 0;
+    Thinker::build()
+        .picker(FirstToScore::new(0.5))
+        .otherwise(Wander)
+        .spawn_batch(&mut cmd, 100, move || {
+            next += 1;
+            Villager(next)
+        });
+}
+
+#[test]
+fn spawn_batch_spawns_thinking_actors() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .add_systems(Startup, spawn_village);
+    for _ in 0..3 {
+        app.update();
+    }
+    let world = app.world_mut();
+    let mut ids: Vec<_> = world
+        .query_filtered::<&Villager, With<HasThinker>>()
+        .iter(world)
+        .map(|villager| villager.0)
+        .collect();
+    ids.sort();
+    assert_eq!(ids, (1..=100).collect::<Vec<_>>());
+    assert_eq!(world.query::<&Thinker>().iter(world).count(), 100);
+    assert_eq!(world.query::<&Wander>().iter(world).count(), 100);
+}
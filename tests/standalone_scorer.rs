@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use big_brain::scorers::spawn_standalone_scorer;
+
+#[test]
+fn scores_without_a_thinker_until_the_actor_is_gone() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    let actor = app.world_mut().spawn_empty().id();
+    let mut commands = app.world_mut().commands();
+    let scorer = spawn_standalone_scorer(
+        &mut commands,
+        actor,
+        &SumOfScorers::build(0.0)
+            .push(FixedScore::build(0.25))
+            .push(FixedScore::build(0.5)),
+    );
+    app.world_mut().flush();
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(app.world().get::<Score>(scorer).unwrap().get(), 0.75);
+
+    app.world_mut().despawn(actor);
+    app.update();
+    let world = app.world_mut();
+    assert!(world.get_entity(scorer).is_err());
+    assert_eq!(world.query::<&Score>().iter(world).count(), 0);
+}
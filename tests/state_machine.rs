@@ -0,0 +1,143 @@
+use bevy::{ecs::system::SystemParamItem, prelude::*};
+use big_brain::actions::{action_state_machine_system, ActionStateMachine};
+use big_brain::prelude::*;
+
+#[derive(Default, Resource)]
+struct Log(Vec<&'static str>);
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Countdown(u32);
+
+impl ActionStateMachine for Countdown {
+    type Param = ResMut<'static, Log>;
+
+    fn on_start(&mut self, _actor: Entity, log: &mut SystemParamItem<Self::Param>) -> ActionState {
+        log.0.push("start");
+        ActionState::Executing
+    }
+
+    fn on_execute(
+        &mut self,
+        _actor: Entity,
+        log: &mut SystemParamItem<Self::Param>,
+    ) -> ActionState {
+        log.0.push("execute");
+        self.0 -= 1;
+        if self.0 == 0 {
+            ActionState::Success
+        } else {
+            ActionState::Executing
+        }
+    }
+}
+
+#[test]
+fn state_machine_runs_to_completion() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<Log>()
+        .add_systems(
+            PreUpdate,
+            action_state_machine_system::<Countdown>.in_set(BigBrainSet::Actions),
+        );
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when_once(FixedScore::build(1.0), Countdown(3)),
+    );
+    for _ in 0..20 {
+        app.update();
+    }
+    assert_eq!(
+        app.world().resource::<Log>().0,
+        vec!["start", "execute", "execute", "execute"]
+    );
+}
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+#[action(transition, param = "ResMut<'static, Log>", on_cancel)]
+struct DerivedCountdown(u32);
+
+impl DerivedCountdown {
+    fn on_execute(&mut self, _actor: Entity, log: &mut ResMut<Log>) -> ActionState {
+        log.0.push("execute");
+        self.0 -= 1;
+        if self.0 == 0 {
+            ActionState::Success
+        } else {
+            ActionState::Executing
+        }
+    }
+
+    fn on_cancel(&mut self, _actor: Entity, log: &mut ResMut<Log>) -> ActionState {
+        log.0.push("cancel");
+        ActionState::Failure
+    }
+}
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+#[action(transition)]
+struct Noop;
+
+impl Noop {
+    fn on_execute(&mut self, _actor: Entity, _param: &mut ()) -> ActionState {
+        ActionState::Success
+    }
+}
+
+#[test]
+fn derived_state_machine_runs_to_completion() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<Log>()
+        .add_systems(
+            PreUpdate,
+            (
+                action_state_machine_system::<DerivedCountdown>,
+                action_state_machine_system::<Noop>,
+            )
+                .in_set(BigBrainSet::Actions),
+        );
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when_once(FixedScore::build(1.0), DerivedCountdown(3)),
+    );
+    for _ in 0..20 {
+        app.update();
+    }
+    assert_eq!(
+        app.world().resource::<Log>().0,
+        vec!["execute", "execute", "execute"]
+    );
+}
+
+#[test]
+fn derived_state_machine_calls_on_cancel() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<Log>()
+        .add_systems(
+            PreUpdate,
+            action_state_machine_system::<DerivedCountdown>.in_set(BigBrainSet::Actions),
+        );
+    let actor = app
+        .world_mut()
+        .spawn(Thinker::build().picker(FirstToScore::new(0.5)))
+        .id();
+    let action = big_brain::actions::spawn_action(
+        &DerivedCountdown(10),
+        &mut app.world_mut().commands(),
+        actor,
+    );
+    app.world_mut().flush();
+    *app.world_mut().get_mut::<ActionState>(action).unwrap() = ActionState::Executing;
+    app.update();
+    *app.world_mut().get_mut::<ActionState>(action).unwrap() = ActionState::Cancelled;
+    app.update();
+    assert_eq!(app.world().resource::<Log>().0, vec!["execute", "cancel"]);
+    assert_eq!(
+        app.world().get::<ActionState>(action),
+        Some(&ActionState::Failure)
+    );
+}
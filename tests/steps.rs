@@ -114,3 +114,175 @@ fn no_failure_score(
         score.set(if global_state.failure { 0.0 } else { 1.0 });
     }
 }
+
+#[derive(Default, Resource)]
+struct DynamicState {
+    ran: Vec<&'static str>,
+}
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct DynamicStep(&'static str);
+
+fn dynamic_step(
+    mut query: Query<(&Parent, &DynamicStep, &mut ActionState)>,
+    mut steps: Query<&mut Steps>,
+    mut global_state: ResMut<DynamicState>,
+) {
+    for (parent, DynamicStep(name), mut state) in query.iter_mut() {
+        if *state == ActionState::Requested {
+            global_state.ran.push(name);
+            if *name == "first" {
+                let mut steps = steps.get_mut(parent.get()).expect("step has a parent");
+                assert_eq!(steps.active_step(), 0);
+                steps.push_step(DynamicStep("third"));
+            }
+            *state = ActionState::Success;
+        }
+    }
+}
+
+#[test]
+fn push_step_while_running() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<DynamicState>()
+        .add_systems(PreUpdate, dynamic_step.in_set(BigBrainSet::Actions));
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(pickers::FirstToScore::new(0.5))
+            .otherwise(
+                Steps::build()
+                    .step(DynamicStep("first"))
+                    .step(DynamicStep("second")),
+            ),
+    );
+    for _ in 0..12 {
+        app.update();
+    }
+    assert_eq!(
+        app.world().resource::<DynamicState>().ran[..3],
+        ["first", "second", "third"]
+    );
+}
+
+#[test]
+fn start_at_skips_earlier_steps() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<DynamicState>()
+        .add_systems(PreUpdate, dynamic_step.in_set(BigBrainSet::Actions));
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(pickers::FirstToScore::new(0.5))
+            .when_once(
+                FixedScore::build(1.0),
+                Steps::build()
+                    .step(DynamicStep("first"))
+                    .step(DynamicStep("second"))
+                    .step(DynamicStep("third"))
+                    .start_at(1),
+            ),
+    );
+    for _ in 0..12 {
+        app.update();
+    }
+    assert_eq!(
+        app.world().resource::<DynamicState>().ran,
+        ["second", "third"]
+    );
+}
+
+#[test]
+fn start_at_past_the_end_fails() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<DynamicState>()
+        .add_systems(PreUpdate, dynamic_step.in_set(BigBrainSet::Actions));
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(pickers::FirstToScore::new(0.5))
+            .when(
+                FixedScore::build(1.0),
+                Steps::build()
+                    .step(DynamicStep("first"))
+                    .step(DynamicStep("second"))
+                    .start_at(2),
+            ),
+    );
+    for _ in 0..12 {
+        app.update();
+    }
+    assert!(app.world().resource::<DynamicState>().ran.is_empty());
+    let world = app.world_mut();
+    assert_eq!(world.query::<&Steps>().iter(world).count(), 0);
+}
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Lingering;
+
+fn lingering(
+    mut query: Query<&mut ActionState, With<Lingering>>,
+    mut global_state: ResMut<DynamicState>,
+) {
+    for mut state in query.iter_mut() {
+        match *state {
+            ActionState::Requested => {
+                global_state.ran.push("lingering");
+                *state = ActionState::Executing;
+            }
+            ActionState::Cancelled => {
+                global_state.ran.push("cancelled");
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn jump_to_cancels_the_active_step() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<DynamicState>()
+        .add_systems(
+            PreUpdate,
+            (dynamic_step, lingering).in_set(BigBrainSet::Actions),
+        );
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(pickers::FirstToScore::new(0.5))
+            .when_once(
+                FixedScore::build(1.0),
+                Steps::build()
+                    .step(Lingering)
+                    .step(DynamicStep("second"))
+                    .step(DynamicStep("third")),
+            ),
+    );
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(app.world().resource::<DynamicState>().ran, ["lingering"]);
+
+    let world = app.world_mut();
+    let mut steps = world.query::<&mut Steps>();
+    let mut steps = steps.single_mut(world);
+    assert_eq!(steps.active_step(), 0);
+    assert_eq!(steps.len(), 3);
+    assert_eq!(steps.active_step_label(), "Lingering");
+    assert!(!steps.jump_to(3));
+    assert!(!steps.insert_step(0, DynamicStep("too early")));
+    assert!(!steps.insert_step(4, DynamicStep("too late")));
+    assert!(!steps.remove_step(0));
+    assert!(!steps.remove_step(3));
+    assert_eq!(steps.len(), 3);
+    assert!(steps.jump_to(2));
+
+    for _ in 0..8 {
+        app.update();
+    }
+    assert_eq!(
+        app.world().resource::<DynamicState>().ran,
+        ["lingering", "cancelled", "third"]
+    );
+}
@@ -0,0 +1,170 @@
+use bevy::{prelude::*, time::TimeUpdateStrategy, utils::Duration};
+use big_brain::prelude::*;
+use big_brain::scorers::resource_score_system;
+
+mod common;
+use common::{current, keep_going};
+
+#[derive(Resource)]
+struct Busy(f32);
+
+#[derive(Default, Resource)]
+struct Ran(Vec<&'static str>);
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Chop;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Stack;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Sweep;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+fn finish<T: Component>(
+    name: &'static str,
+) -> impl FnMut(Query<&mut ActionState, With<T>>, ResMut<Ran>) {
+    move |mut query, mut ran| {
+        for mut state in query.iter_mut() {
+            match *state {
+                ActionState::Requested => {
+                    ran.0.push(name);
+                    *state = ActionState::Success;
+                }
+                ActionState::Cancelled | ActionState::Interrupted => *state = ActionState::Failure,
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Keeps going until it's cancelled outright, ignoring interruptions.
+fn linger<T: Component>(
+    name: &'static str,
+) -> impl FnMut(Query<&mut ActionState, With<T>>, ResMut<Ran>) {
+    move |mut query, mut ran| {
+        for mut state in query.iter_mut() {
+            match *state {
+                ActionState::Requested => *state = ActionState::Executing,
+                ActionState::Cancelled => {
+                    ran.0.push(name);
+                    *state = ActionState::Failure;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn run(policy: Option<StepsCancelPolicy>) -> (App, Entity) {
+    let mut app = common::app();
+    app.insert_resource(Busy(1.0))
+        .init_resource::<Ran>()
+        .add_systems(
+            PreUpdate,
+            (
+                resource_score_system::<Busy>.in_set(BigBrainSet::Scorers),
+                (
+                    keep_going::<Chop>,
+                    finish::<Stack>("stack"),
+                    finish::<Sweep>("sweep"),
+                    keep_going::<Idle>,
+                )
+                    .in_set(BigBrainSet::Actions),
+            ),
+        );
+    let mut chores = Steps::build()
+        .label("Chores")
+        .step(Chop)
+        .step(Stack)
+        .step(Sweep);
+    if let Some(policy) = policy {
+        chores = chores.cancel_policy(policy);
+    }
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when(ResourceScore::build(|busy: &Busy| busy.0), chores)
+                .when(ResourceScore::build(|busy: &Busy| 1.0 - busy.0), Idle),
+        )
+        .id();
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(current(&app, actor).as_deref(), Some("Chores"));
+    app.world_mut().resource_mut::<Busy>().0 = 0.0;
+    for _ in 0..10 {
+        app.update();
+    }
+    (app, actor)
+}
+
+#[test]
+fn skip_step_runs_the_remaining_steps() {
+    let (app, actor) = run(Some(StepsCancelPolicy::SkipStep));
+    assert_eq!(app.world().resource::<Ran>().0, vec!["stack", "sweep"]);
+    assert_eq!(current(&app, actor).as_deref(), Some("Idle"));
+}
+
+#[test]
+fn aborts_by_default() {
+    let (app, actor) = run(None);
+    assert!(app.world().resource::<Ran>().0.is_empty());
+    assert_eq!(current(&app, actor).as_deref(), Some("Idle"));
+}
+
+#[test]
+fn skip_step_cancels_the_running_step_once_escalated() {
+    let mut app = common::app();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )))
+    .insert_resource(Busy(1.0))
+    .init_resource::<Ran>()
+    .add_systems(
+        PreUpdate,
+        (
+            resource_score_system::<Busy>.in_set(BigBrainSet::Scorers),
+            (
+                keep_going::<Chop>,
+                linger::<Stack>("stack cancelled"),
+                finish::<Sweep>("sweep"),
+                keep_going::<Idle>,
+            )
+                .in_set(BigBrainSet::Actions),
+        ),
+    );
+    let chores = Steps::build()
+        .label("Chores")
+        .step(Chop)
+        .step(Stack)
+        .step(Sweep)
+        .cancel_policy(StepsCancelPolicy::SkipStep);
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when(ResourceScore::build(|busy: &Busy| busy.0), chores)
+                .when(ResourceScore::build(|busy: &Busy| 1.0 - busy.0), Idle)
+                .interrupt_on_switch(Duration::from_millis(500)),
+        )
+        .id();
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(current(&app, actor).as_deref(), Some("Chores"));
+    app.world_mut().resource_mut::<Busy>().0 = 0.0;
+    for _ in 0..20 {
+        app.update();
+    }
+    assert_eq!(
+        app.world().resource::<Ran>().0,
+        vec!["stack cancelled", "sweep"]
+    );
+    assert_eq!(current(&app, actor).as_deref(), Some("Idle"));
+}
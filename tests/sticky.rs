@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use big_brain::scorers::resource_score_system;
+
+mod common;
+use common::{current, keep_going};
+
+#[derive(Resource)]
+struct Urges {
+    eat: f32,
+    sleep: f32,
+}
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Eat;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Sleep;
+
+fn app() -> (App, Entity) {
+    let mut app = common::app();
+    app.insert_resource(Urges {
+        eat: 0.6,
+        sleep: 0.5,
+    })
+    .add_systems(
+        PreUpdate,
+        (
+            resource_score_system::<Urges>.in_set(BigBrainSet::Scorers),
+            (keep_going::<Eat>, keep_going::<Sleep>).in_set(BigBrainSet::Actions),
+        ),
+    );
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(Sticky::new(Highest, 0.2))
+            .when(ResourceScore::build(|urges: &Urges| urges.eat), Eat)
+            .when(ResourceScore::build(|urges: &Urges| urges.sleep), Sleep),
+    );
+    let actor = app
+        .world_mut()
+        .query_filtered::<Entity, With<ThinkerBuilder>>()
+        .single(app.world());
+    for _ in 0..3 {
+        app.update();
+    }
+    (app, actor)
+}
+
+#[test]
+fn does_not_switch_within_stickiness() {
+    let (mut app, actor) = app();
+    assert_eq!(current(&app, actor).as_deref(), Some("Eat"));
+    for sleep in [0.7, 0.55, 0.75, 0.65, 0.79] {
+        app.world_mut().resource_mut::<Urges>().sleep = sleep;
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(
+            current(&app, actor).as_deref(),
+            Some("Eat"),
+            "switched away with sleep at {sleep}"
+        );
+    }
+}
+
+#[test]
+fn switches_once_stickiness_is_exceeded() {
+    let (mut app, actor) = app();
+    assert_eq!(current(&app, actor).as_deref(), Some("Eat"));
+    app.world_mut().resource_mut::<Urges>().sleep = 0.9;
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(current(&app, actor).as_deref(), Some("Sleep"));
+}
+
+#[test]
+fn lets_go_of_choices_that_score_zero() {
+    let (mut app, actor) = app();
+    assert_eq!(current(&app, actor).as_deref(), Some("Eat"));
+    let mut urges = app.world_mut().resource_mut::<Urges>();
+    urges.eat = 0.0;
+    urges.sleep = 0.1;
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(current(&app, actor).as_deref(), Some("Sleep"));
+}
@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use big_brain::scorers::SumOfScorersBuilder;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+fn sum_score(scorer: SumOfScorersBuilder) -> f32 {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when(scorer.label("Sum"), Idle),
+        )
+        .id();
+    for _ in 0..3 {
+        app.update();
+    }
+    big_brain::query_score(app.world(), actor, "Sum").unwrap()
+}
+
+fn saturated(threshold: f32, normalized: bool) -> SumOfScorersBuilder {
+    SumOfScorers::build(threshold)
+        .normalized(normalized)
+        .push(FixedScore::build(0.9))
+        .push(FixedScore::build(0.6))
+        .push(FixedScore::build(0.3))
+}
+
+#[test]
+fn normalized_sum_is_the_average() {
+    assert!((sum_score(saturated(0.0, true)) - 0.6).abs() < 1e-5);
+}
+
+#[test]
+fn raw_sum_is_clamped() {
+    assert_eq!(sum_score(saturated(0.0, false)), 1.0);
+}
+
+#[test]
+fn normalized_sum_is_compared_to_the_threshold() {
+    assert_eq!(sum_score(saturated(0.7, true)), 0.0);
+    assert_eq!(sum_score(saturated(0.7, false)), 1.0);
+}
@@ -0,0 +1,81 @@
+use bevy::{prelude::*, time::TimeUpdateStrategy, utils::Duration};
+use big_brain::prelude::*;
+
+#[derive(Default, Resource)]
+struct Spike(f32);
+
+#[derive(Clone, Component, Debug, ScorerBuilder)]
+struct Spiky;
+
+fn spiky(mut query: Query<&mut Score, With<Spiky>>, spike: Res<Spike>) {
+    for mut score in query.iter_mut() {
+        score.set(spike.0);
+    }
+}
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct React;
+
+fn sustained_score(app: &mut App) -> f32 {
+    let world = app.world_mut();
+    world
+        .query_filtered::<&Score, With<SustainedScorer>>()
+        .single(world)
+        .get()
+}
+
+fn app(ramp: bool) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .insert_resource(Spike(1.0))
+        .add_systems(PreUpdate, spiky.in_set(BigBrainSet::Scorers));
+    app.world_mut()
+        .spawn(Thinker::build().picker(FirstToScore::new(0.5)).when(
+            SustainedScorer::build(0.5, Duration::from_secs(1), Spiky).ramp(ramp),
+            React,
+        ));
+    app
+}
+
+#[test]
+fn sustained_scorer_ramps_up() {
+    let mut app = app(true);
+    for _ in 0..6 {
+        app.update();
+    }
+    let partial = sustained_score(&mut app);
+    assert!(partial > 0.0 && partial < 1.0, "{partial}");
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_eq!(sustained_score(&mut app), 1.0);
+
+    app.world_mut().resource_mut::<Spike>().0 = 0.4;
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(sustained_score(&mut app), 0.0);
+
+    // Going back above the threshold starts over.
+    app.world_mut().resource_mut::<Spike>().0 = 1.0;
+    for _ in 0..3 {
+        app.update();
+    }
+    assert!(sustained_score(&mut app) < partial);
+}
+
+#[test]
+fn sustained_scorer_without_ramp_steps() {
+    let mut app = app(false);
+    for _ in 0..6 {
+        app.update();
+    }
+    assert_eq!(sustained_score(&mut app), 0.0);
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_eq!(sustained_score(&mut app), 1.0);
+}
@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use big_brain::prelude::*;
+use big_brain::scorers::resource_score_system;
+
+mod common;
+use common::keep_going;
+
+#[derive(Resource)]
+struct Urges {
+    eat: f32,
+    sleep: f32,
+}
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Eat;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Sleep;
+
+fn app(window: Duration) -> (App, Entity) {
+    let mut app = common::app();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )))
+    .insert_resource(Urges {
+        eat: 0.6,
+        sleep: 0.5,
+    })
+    .add_systems(
+        PreUpdate,
+        (
+            resource_score_system::<Urges>.in_set(BigBrainSet::Scorers),
+            (keep_going::<Eat>, keep_going::<Sleep>).in_set(BigBrainSet::Actions),
+        ),
+    );
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(Highest)
+                .switch_window(window)
+                .warn_above_switches(1.0)
+                .when(ResourceScore::build(|urges: &Urges| urges.eat), Eat)
+                .when(ResourceScore::build(|urges: &Urges| urges.sleep), Sleep),
+        )
+        .id();
+    for _ in 0..3 {
+        app.update();
+    }
+    (app, actor)
+}
+
+fn switches_per_second(app: &App, actor: Entity) -> f32 {
+    let thinker = app.world().get::<HasThinker>(actor).unwrap().entity();
+    app.world()
+        .get::<Thinker>(thinker)
+        .unwrap()
+        .switches_per_second()
+}
+
+#[test]
+fn steady_thinkers_do_not_switch() {
+    let (mut app, actor) = app(Duration::from_secs(1));
+    for _ in 0..20 {
+        app.update();
+    }
+    assert_eq!(switches_per_second(&app, actor), 0.0);
+}
+
+#[test]
+fn flip_flopping_is_counted_and_forgotten() {
+    let (mut app, actor) = app(Duration::from_secs(1));
+    // Swap which urge wins every 3 frames, or 0.3s.
+    for frame in 0..30 {
+        if frame % 3 == 0 {
+            let mut urges = app.world_mut().resource_mut::<Urges>();
+            let Urges { eat, sleep } = &mut *urges;
+            std::mem::swap(eat, sleep);
+        }
+        app.update();
+    }
+    let thrashing = switches_per_second(&app, actor);
+    assert!(
+        (2.0..=4.0).contains(&thrashing),
+        "expected about 3 switches per second, got {thrashing}"
+    );
+
+    // Once it settles down, old switches fall out of the window.
+    for _ in 0..15 {
+        app.update();
+    }
+    assert_eq!(switches_per_second(&app, actor), 0.0);
+}
@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+/// Requests a standalone `Ticks` action and counts the updates it takes to
+/// succeed.
+fn updates_until_success(ticks: u32) -> u32 {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    let actor = app
+        .world_mut()
+        .spawn(Thinker::build().picker(FirstToScore::new(0.5)))
+        .id();
+    let action = big_brain::actions::spawn_action(
+        &Ticks::build(ticks),
+        &mut app.world_mut().commands(),
+        actor,
+    );
+    app.world_mut().flush();
+    *app.world_mut().get_mut::<ActionState>(action).unwrap() = ActionState::Requested;
+    for updates in 1..=10 {
+        app.update();
+        if app.world().get::<ActionState>(action) == Some(&ActionState::Success) {
+            return updates;
+        }
+    }
+    panic!("Ticks::build({ticks}) never succeeded");
+}
+
+#[test]
+fn zero_ticks_succeed_right_away() {
+    assert_eq!(updates_until_success(0), 1);
+}
+
+#[test]
+fn ticks_succeed_after_that_many_executing_passes() {
+    assert_eq!(updates_until_success(3), 4);
+}
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use big_brain::registry::BuilderKind;
+
+#[derive(Debug, Clone, Default, Component, Reflect, ScorerBuilder)]
+struct Thirsty {
+    threshold: f32,
+    per_second: f32,
+}
+
+#[derive(Debug, Clone, Default, Component, Reflect, ScorerBuilder)]
+struct Scared(f32);
+
+#[derive(Debug, Clone, Default, Component, Reflect, ActionBuilder)]
+#[action_label = "Drink some water"]
+struct Drink;
+
+#[test]
+fn lists_registered_types_with_their_fields() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .register_scorer::<Thirsty>()
+        .register_scorer::<Scared>()
+        .register_action::<Drink>()
+        .register_scorer::<Thirsty>();
+
+    let registry = app.world().resource::<BigBrainTypeRegistry>();
+    assert_eq!(registry.iter().count(), 3);
+
+    let scorers: Vec<_> = registry.scorers().map(|s| s.short_name).collect();
+    assert_eq!(scorers, vec!["Thirsty", "Scared"]);
+
+    let thirsty = registry.get(std::any::type_name::<Thirsty>()).unwrap();
+    assert_eq!(thirsty.kind, BuilderKind::Scorer);
+    assert_eq!(thirsty.default_label.as_deref(), Some("Thirsty"));
+    let fields: Vec<_> = thirsty
+        .fields
+        .iter()
+        .map(|field| (field.name.as_str(), field.type_path))
+        .collect();
+    assert_eq!(fields, vec![("threshold", "f32"), ("per_second", "f32")]);
+
+    let scared = registry
+        .get_by_id(std::any::TypeId::of::<Scared>())
+        .unwrap();
+    assert_eq!(scared.fields[0].name, "0");
+
+    let drink = registry.actions().next().unwrap();
+    assert_eq!(drink.kind, BuilderKind::Action);
+    assert_eq!(drink.default_label.as_deref(), Some("Drink some water"));
+    assert!(drink.fields.is_empty());
+
+    let types = app.world().resource::<AppTypeRegistry>().read();
+    assert!(types.contains(std::any::TypeId::of::<Drink>()));
+}
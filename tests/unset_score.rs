@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ScorerBuilder)]
+struct NoData;
+
+fn no_data_system(mut query: Query<&mut Score, With<NoData>>) {
+    for mut score in query.iter_mut() {
+        score.unset();
+    }
+}
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+fn composite_score<T: Component>(scorer: impl ScorerBuilder + 'static) -> Score {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .add_systems(PreUpdate, no_data_system.in_set(BigBrainSet::Scorers));
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(scorer, Idle),
+    );
+    for _ in 0..3 {
+        app.update();
+    }
+    let world = app.world_mut();
+    world
+        .query_filtered::<&Score, With<T>>()
+        .single(world)
+        .clone()
+}
+
+#[test]
+fn set_clears_unset() {
+    let mut score = Score::default();
+    assert!(!score.is_unset());
+    score.unset();
+    assert!(score.is_unset());
+    assert_eq!(score.get(), 0.0);
+    score.set(0.4);
+    assert!(!score.is_unset());
+    assert_eq!(score.get(), 0.4);
+}
+
+#[test]
+fn sum_of_scorers_skips_unset_children() {
+    let score = composite_score::<SumOfScorers>(
+        SumOfScorers::build(0.0)
+            .normalized(true)
+            .push(FixedScore::build(0.6))
+            .push(NoData),
+    );
+    assert!(!score.is_unset());
+    assert_eq!(score.get(), 0.6);
+
+    let score = composite_score::<SumOfScorers>(SumOfScorers::build(0.0).push(NoData).push(NoData));
+    assert!(score.is_unset());
+    assert_eq!(score.get(), 0.0);
+}
+
+#[test]
+fn measured_scorer_skips_unset_children() {
+    let score = composite_score::<MeasuredScorer>(
+        MeasuredScorer::build(0.0)
+            .measure(WeightedSum)
+            .normalize_weights(true)
+            .push(FixedScore::build(0.8), 0.5)
+            .push(NoData, 0.5),
+    );
+    assert!(!score.is_unset());
+    assert!((score.get() - 0.8).abs() < f32::EPSILON);
+
+    let score = composite_score::<MeasuredScorer>(MeasuredScorer::build(0.0).push(NoData, 1.0));
+    assert!(score.is_unset());
+}
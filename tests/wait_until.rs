@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Default, Resource)]
+struct Crossing {
+    safe: bool,
+    crossed: u32,
+}
+
+#[derive(Clone, Component, Debug, ScorerBuilder)]
+struct Safety;
+
+fn safety_scorer(mut query: Query<&mut Score, With<Safety>>, crossing: Res<Crossing>) {
+    for mut score in query.iter_mut() {
+        score.set(if crossing.safe { 1.0 } else { 0.0 });
+    }
+}
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Cross;
+
+fn cross(mut query: Query<&mut ActionState, With<Cross>>, mut crossing: ResMut<Crossing>) {
+    for mut state in query.iter_mut() {
+        if *state == ActionState::Requested {
+            crossing.crossed += 1;
+            *state = ActionState::Success;
+        }
+    }
+}
+
+#[test]
+fn wait_until_waits_for_score() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<Crossing>()
+        .add_systems(PreUpdate, safety_scorer.in_set(BigBrainSet::Scorers))
+        .add_systems(PreUpdate, cross.in_set(BigBrainSet::Actions));
+    app.world_mut().spawn(
+        Thinker::build().picker(FirstToScore::new(0.5)).when_once(
+            FixedScore::build(1.0),
+            Steps::build()
+                .step(WaitUntil::build(Safety, 0.5))
+                .step(Cross),
+        ),
+    );
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_eq!(app.world().resource::<Crossing>().crossed, 0);
+    app.world_mut().resource_mut::<Crossing>().safe = true;
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_eq!(app.world().resource::<Crossing>().crossed, 1);
+}
+
+#[test]
+fn wait_until_builder_reflects_as_an_action_builder() {
+    use bevy::reflect::{GetTypeRegistration, TypeRegistry};
+    use big_brain::actions::{ReflectActionBuilder, WaitUntilBuilder};
+
+    let mut registry = TypeRegistry::default();
+    registry.add_registration(WaitUntilBuilder::get_type_registration());
+    let reflect_builder = registry
+        .get_type_data::<ReflectActionBuilder>(std::any::TypeId::of::<WaitUntilBuilder>())
+        .expect("WaitUntilBuilder should reflect ActionBuilder");
+    let builder = WaitUntil::build(Safety, 0.5).label("Wait");
+    let builder = reflect_builder
+        .get(&builder)
+        .expect("reflected value should be an ActionBuilder");
+    assert_eq!(builder.label(), Some("Wait"));
+}
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Default, Resource)]
+struct Greetings(u32);
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Greet;
+
+fn greet(mut query: Query<&mut ActionState, With<Greet>>, mut greetings: ResMut<Greetings>) {
+    for mut state in query.iter_mut() {
+        if *state == ActionState::Requested {
+            greetings.0 += 1;
+            *state = ActionState::Success;
+        }
+    }
+}
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+#[test]
+fn when_once_only_succeeds_once() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<Greetings>()
+        .add_systems(PreUpdate, greet.in_set(BigBrainSet::Actions));
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when_once(FixedScore::build(1.0), Greet)
+            .otherwise(Idle),
+    );
+    for _ in 0..20 {
+        app.update();
+    }
+    assert_eq!(app.world().resource::<Greetings>().0, 1);
+}
@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Idle;
+
+fn winner_after_update(tie_break: TieBreak) -> (Entity, Vec<Entity>) {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)));
+    app.world_mut().spawn(
+        Thinker::build().picker(FirstToScore::new(0.5)).when(
+            WinningScorer::build(0.5)
+                .push(FixedScore::build(0.8))
+                .push(FixedScore::build(0.8))
+                .push(FixedScore::build(0.3))
+                .tie_break(tie_break),
+            Idle,
+        ),
+    );
+    for _ in 0..3 {
+        app.update();
+    }
+    let world = app.world_mut();
+    let (winner, children) = world.query::<(&WinningScorer, &Children)>().single(world);
+    (
+        winner.winner().expect("something should have won"),
+        children.to_vec(),
+    )
+}
+
+#[test]
+fn tie_break_first_pushed() {
+    let (winner, children) = winner_after_update(TieBreak::FirstPushed);
+    assert_eq!(winner, children[0]);
+}
+
+#[test]
+fn tie_break_last_pushed() {
+    let (winner, children) = winner_after_update(TieBreak::LastPushed);
+    assert_eq!(winner, children[1]);
+}
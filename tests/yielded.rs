@@ -0,0 +1,129 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+#[derive(Default, Resource)]
+struct GlobalState {
+    want_other: bool,
+    chunks: u32,
+    seen: Vec<ActionState>,
+    other_ran: bool,
+}
+
+#[derive(Clone, Component, Debug, ScorerBuilder)]
+struct WantOther;
+
+fn want_other_scorer(mut query: Query<&mut Score, With<WantOther>>, state: Res<GlobalState>) {
+    for mut score in query.iter_mut() {
+        score.set(if state.want_other { 1.0 } else { 0.0 });
+    }
+}
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct Chunky;
+
+fn chunky(mut query: Query<&mut ActionState, With<Chunky>>, mut global: ResMut<GlobalState>) {
+    for mut state in query.iter_mut() {
+        match *state {
+            ActionState::Requested | ActionState::Executing => {
+                global.chunks += 1;
+                // Something better comes along halfway through.
+                global.want_other = global.chunks >= 2;
+                *state = if global.chunks == 5 {
+                    ActionState::Success
+                } else {
+                    ActionState::Yielded
+                };
+            }
+            ActionState::Cancelled | ActionState::Interrupted => {
+                global.seen.push(state.clone());
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
+
+#[derive(Clone, Component, Debug, ActionBuilder)]
+struct OtherAction;
+
+fn other_action(
+    mut query: Query<&mut ActionState, With<OtherAction>>,
+    mut global: ResMut<GlobalState>,
+) {
+    for mut state in query.iter_mut() {
+        if *state == ActionState::Requested {
+            global.other_ran = true;
+            *state = ActionState::Success;
+        }
+    }
+}
+
+fn run(otherwise: impl ActionBuilder + 'static) -> GlobalState {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<GlobalState>()
+        .add_systems(PreUpdate, want_other_scorer.in_set(BigBrainSet::Scorers))
+        .add_systems(
+            PreUpdate,
+            (chunky, other_action).in_set(BigBrainSet::Actions),
+        );
+    app.world_mut().spawn(
+        Thinker::build()
+            .picker(FirstToScore::new(0.5))
+            .when(WantOther, OtherAction)
+            .otherwise(otherwise),
+    );
+    for _ in 0..20 {
+        app.update();
+    }
+    app.world_mut()
+        .remove_resource::<GlobalState>()
+        .expect("resource went missing")
+}
+
+#[test]
+fn yielded_actions_are_not_preempted() {
+    let global = run(Chunky);
+    assert_eq!(global.chunks, 5);
+    assert!(global.seen.is_empty());
+    assert!(global.other_ran);
+}
+
+#[test]
+fn yielded_nested_actions_are_not_preempted() {
+    let global = run(Steps::build().step(Chunky));
+    assert_eq!(global.chunks, 5);
+    assert!(global.seen.is_empty());
+    assert!(global.other_ran);
+}
+
+#[test]
+fn yielded_actions_resume_outside_of_thinkers() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, BigBrainPlugin::new(PreUpdate)))
+        .init_resource::<GlobalState>()
+        .add_systems(
+            PreUpdate,
+            (chunky, other_action).in_set(BigBrainSet::Actions),
+        );
+    let actor = app
+        .world_mut()
+        .spawn(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .otherwise(OtherAction),
+        )
+        .id();
+    // Run Chunky on the side, without the actor's Thinker knowing about it.
+    app.world_mut()
+        .run_system_once(move |mut cmd: Commands| {
+            let action = big_brain::actions::spawn_action(&Chunky, &mut cmd, actor);
+            cmd.entity(action).insert(ActionState::Requested);
+        })
+        .unwrap();
+    for _ in 0..20 {
+        app.update();
+    }
+    assert_eq!(app.world().resource::<GlobalState>().chunks, 5);
+}